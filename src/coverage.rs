@@ -0,0 +1,301 @@
+//! Track the parts of a document which no validator checked.
+//!
+//! Most validators check the whole value they are given. Only the validators
+//! which select some of the children of a value, like [validators::object] or
+//! [validators::at], record which children they descended into; the other
+//! children of such a value are unvalidated.
+
+use crate::{validators, Error, Validator, Value};
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+#[derive(Default)]
+struct Coverage {
+    /// Values whose children are validated selectively.
+    descended: HashSet<*const Value>,
+    /// Children which were validated.
+    visited: HashSet<*const Value>,
+}
+
+thread_local! {
+    static COVERAGE: RefCell<Option<Coverage>> = const { RefCell::new(None) };
+}
+
+/// Validate `value` and list the JSON pointers of the fields which no
+/// validator checked.
+///
+/// Object validators ignore the keys they don't list, so fields appearing in a
+/// document can easily go unnoticed. The fields are listed in document order.
+///
+/// ```
+/// use assert_json::{unvalidated_fields, validators, Validator};
+///
+/// let value = serde_json::json!({"id": 5, "user": {"name": "n", "email": "e"}});
+/// let validator = validators::object_contains(vec![
+///     ("id", Box::new(validators::eq(5)) as Box<dyn Validator>),
+///     ("user", Box::new(validators::at("/name", validators::is_string()))),
+/// ]);
+///
+/// assert_eq!(
+///     Ok(vec![String::from("/user/email")]),
+///     unvalidated_fields(&value, &validator)
+/// );
+/// ```
+pub fn unvalidated_fields<'a, V>(
+    value: &'a Value,
+    validator: &V,
+) -> Result<Vec<String>, Vec<Error<'a>>>
+where
+    V: Validator + ?Sized,
+{
+    let (errors, coverage) =
+        track(|| validators::with_document(value, || validator.validate_all(value)));
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut fields = vec![];
+    collect_unvalidated(value, "", &coverage, &mut fields);
+    Ok(fields)
+}
+
+/// Record that `child`, a child of `parent`, is validated.
+pub(crate) fn visit(parent: &Value, child: &Value) {
+    COVERAGE.with(|coverage| {
+        if let Some(coverage) = coverage.borrow_mut().as_mut() {
+            coverage.descended.insert(parent);
+            coverage.visited.insert(child);
+        }
+    });
+}
+
+/// Run `f`, keeping the visits it records only if `keep` accepts its result.
+///
+/// The fields checked by alternatives which failed, or by negated validators,
+/// are not validated by the document.
+pub(crate) fn tentatively<R>(f: impl FnOnce() -> R, keep: impl FnOnce(&R) -> bool) -> R {
+    let outer = COVERAGE.with(|coverage| coverage.borrow_mut().as_mut().map(std::mem::take));
+    let Some(outer) = outer else {
+        return f();
+    };
+
+    /// Restore the outer visits, even on panic.
+    struct Guard(Option<Coverage>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            if let Some(outer) = self.0.take() {
+                COVERAGE.with(|coverage| *coverage.borrow_mut() = Some(outer));
+            }
+        }
+    }
+
+    let mut guard = Guard(Some(outer));
+    let result = f();
+    let mut outer = guard.0.take().unwrap_or_default();
+    COVERAGE.with(|coverage| {
+        let mut coverage = coverage.borrow_mut();
+        if let Some(branch) = coverage.take() {
+            if keep(&result) {
+                outer.descended.extend(branch.descended);
+                outer.visited.extend(branch.visited);
+            }
+        }
+        *coverage = Some(outer);
+    });
+    result
+}
+
+/// Record that the value at `pointer` within `value` is validated.
+pub(crate) fn visit_pointer(value: &Value, pointer: &str) {
+    let mut parent = value;
+    let mut prefix = String::new();
+    for token in pointer.split('/').skip(1) {
+        prefix.push('/');
+        prefix.push_str(token);
+        match value.pointer(&prefix) {
+            Some(child) => {
+                visit(parent, child);
+                parent = child;
+            }
+            None => break,
+        }
+    }
+}
+
+fn track<R>(f: impl FnOnce() -> R) -> (R, Coverage) {
+    /// Restore the previous tracking, even on panic.
+    struct Guard(Option<Coverage>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            COVERAGE.with(|coverage| *coverage.borrow_mut() = previous);
+        }
+    }
+
+    let _guard = Guard(COVERAGE.with(|coverage| coverage.replace(Some(Coverage::default()))));
+    let result = f();
+    let coverage = COVERAGE.with(|coverage| coverage.borrow_mut().take().unwrap_or_default());
+    (result, coverage)
+}
+
+fn collect_unvalidated(
+    value: &Value,
+    pointer: &str,
+    coverage: &Coverage,
+    fields: &mut Vec<String>,
+) {
+    let descended = coverage.descended.contains(&(value as *const Value));
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, child)| (validators::escape_token(key), child))
+            .collect(),
+        Value::Array(array) => array
+            .iter()
+            .enumerate()
+            .map(|(index, child)| (index.to_string(), child))
+            .collect(),
+        _ => vec![],
+    };
+
+    for (token, child) in children {
+        let child_pointer = format!("{}/{}", pointer, token);
+        if descended && !coverage.visited.contains(&(child as *const Value)) {
+            fields.push(child_pointer);
+        } else {
+            collect_unvalidated(child, &child_pointer, coverage, fields);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{validators, Validator};
+
+    #[test]
+    fn unvalidated_fields() {
+        let value = serde_json::json!({
+            "id": 5,
+            "items": [{"name": "a", "price": 1}, {"name": "b", "price": 2}],
+            "meta": {"page": 1},
+        });
+        let validator = validators::object_contains(vec![
+            ("id", Box::new(validators::any()) as Box<dyn Validator>),
+            (
+                "items",
+                Box::new(validators::array_for_each(validators::object_contains(
+                    vec![(
+                        "name",
+                        Box::new(validators::is_string()) as Box<dyn Validator>,
+                    )],
+                ))),
+            ),
+        ]);
+
+        assert_eq!(
+            Ok(vec![
+                String::from("/items/0/price"),
+                String::from("/items/1/price"),
+                String::from("/meta"),
+            ]),
+            super::unvalidated_fields(&value, &validator)
+        );
+    }
+
+    #[test]
+    fn whole_value_validated() {
+        let value = serde_json::json!({"a": {"b": 1}});
+
+        assert_eq!(
+            Ok(vec![]),
+            super::unvalidated_fields(&value, &validators::eq(value.clone()))
+        );
+        assert_eq!(
+            Ok(vec![]),
+            super::unvalidated_fields(&value, &validators::at("/a/b", validators::eq(1)))
+        );
+    }
+
+    #[test]
+    fn failed_alternatives() {
+        let value = serde_json::json!({"a": 1, "b": 2});
+        let validator = validators::any_of(vec![
+            Box::new(validators::at("/a", validators::is_string())),
+            Box::new(validators::at("/b", validators::eq(2))),
+        ]);
+
+        assert_eq!(
+            Ok(vec![String::from("/a")]),
+            super::unvalidated_fields(&value, &validator)
+        );
+        assert_eq!(
+            Ok(vec![String::from("/a")]),
+            super::unvalidated_fields(
+                &value,
+                &validators::not(validators::at("/a", validators::eq(2)))
+                    .and(validators::at("/b", validators::eq(2)))
+            )
+        );
+    }
+
+    #[test]
+    fn array_rest() {
+        let value = serde_json::json!([{"a": 1}, {"b": 2}, {"c": 3}]);
+
+        assert_eq!(
+            Ok(vec![String::from("/1"), String::from("/2")]),
+            super::unvalidated_fields(
+                &value,
+                &validators::array_starts_with(vec![Box::new(validators::any())])
+            )
+        );
+        assert_eq!(
+            Ok(vec![String::from("/1")]),
+            super::unvalidated_fields(
+                &value,
+                &validators::array_with_rest(
+                    vec![Box::new(validators::any())],
+                    vec![Box::new(validators::any())]
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn uncounted_elements() {
+        let value = serde_json::json!([{"a": 1, "b": 2}, {"a": "x", "b": 3}]);
+        let validator =
+            validators::count_where(validators::at("/a", validators::eq(1)), validators::eq(1));
+
+        // The element which isn't counted checks no field.
+        assert_eq!(
+            Ok(vec![String::from("/0/b")]),
+            super::unvalidated_fields(&value, &validator)
+        );
+    }
+
+    #[test]
+    fn map_of() {
+        let value = serde_json::json!({"a": {"b": 1, "c": 2}});
+        let validator = validators::at(
+            "/a",
+            validators::map_of(validators::any(), validators::is_number()),
+        );
+
+        assert_eq!(Ok(vec![]), super::unvalidated_fields(&value, &validator));
+    }
+
+    #[test]
+    fn invalid_value() {
+        let value = serde_json::json!({"a": 1});
+
+        assert_eq!(
+            1,
+            super::unvalidated_fields(&value, &validators::at("/a", validators::is_string()))
+                .unwrap_err()
+                .len()
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::macros_utils::SpanSerializer;
+use crate::{Error, Value};
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+/// Validation errors rendered as a [miette] diagnostic.
+///
+/// Each error is a labeled span into a pretty-printed rendering of the actual
+/// JSON value.
+///
+/// ```
+/// use assert_json::{validators, JsonDiagnostic, Validator};
+///
+/// let value = serde_json::json!({"id": "5"});
+/// let errors = validators::at("/id", validators::is_number()).validate_all(&value);
+///
+/// let report = miette::Report::new(JsonDiagnostic::new(&value, &errors));
+/// assert_eq!("Invalid JSON at /id", report.to_string());
+/// ```
+#[derive(Debug)]
+pub struct JsonDiagnostic {
+    message: String,
+    code: Option<String>,
+    source_code: String,
+    labels: Vec<LabeledSpan>,
+    help: Option<String>,
+}
+
+impl JsonDiagnostic {
+    /// Build the diagnostic of the `errors` which occurred while validating `root`.
+    pub fn new(root: &Value, errors: &[Error]) -> JsonDiagnostic {
+        let serializer = SpanSerializer::serialize(root);
+        let labels = errors
+            .iter()
+            .map(|error| {
+                let span = serializer.span(error.location());
+                LabeledSpan::new(Some(error.to_string()), span.start, span.len())
+            })
+            .collect();
+
+        let (message, code, help) = match errors {
+            [error] => (
+                match error.pointer(root) {
+                    Some(pointer) if !pointer.is_empty() => format!("Invalid JSON at {}", pointer),
+                    _ => String::from("Invalid JSON"),
+                },
+                Some(format!("assert_json::{:?}", error.kind())),
+                error.diff(),
+            ),
+            _ => (
+                format!("Invalid JSON ({} errors)", errors.len()),
+                None,
+                None,
+            ),
+        };
+
+        JsonDiagnostic {
+            message,
+            code,
+            source_code: String::from(serializer.serialized_json()),
+            labels,
+            help,
+        }
+    }
+}
+
+impl std::fmt::Display for JsonDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for JsonDiagnostic {}
+
+impl Diagnostic for JsonDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.code
+            .as_ref()
+            .map(|code| Box::new(code) as Box<dyn std::fmt::Display>)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn std::fmt::Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{validators, Validator};
+    use miette::Diagnostic;
+
+    #[test]
+    fn single_error() {
+        let value = serde_json::json!({"id": "5"});
+        let errors = validators::at("/id", validators::is_number()).validate_all(&value);
+
+        let diagnostic = super::JsonDiagnostic::new(&value, &errors);
+        assert_eq!("Invalid JSON at /id", diagnostic.to_string());
+        assert_eq!(
+            Some(String::from("assert_json::TypeMismatch")),
+            diagnostic.code().map(|code| code.to_string())
+        );
+
+        let labels: Vec<_> = diagnostic.labels().unwrap().collect();
+        assert_eq!(1, labels.len());
+        assert_eq!(
+            Some("Invalid type. Expected number but got string."),
+            labels[0].label()
+        );
+        assert_eq!(
+            "\"5\"",
+            &diagnostic.source_code[labels[0].offset()..labels[0].offset() + labels[0].len()]
+        );
+    }
+
+    #[test]
+    fn several_errors() {
+        let value = serde_json::json!(["a", 1, 2]);
+        let errors = validators::array_for_each(validators::is_string()).validate_all(&value);
+
+        let diagnostic = super::JsonDiagnostic::new(&value, &errors);
+        assert_eq!("Invalid JSON (2 errors)", diagnostic.to_string());
+        assert_eq!(2, diagnostic.labels().unwrap().count());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::Value;
+
+/// Number of unchanged lines shown around each change.
+const CONTEXT: usize = 3;
+
+/// Render a unified diff between the pretty-printed `expected` and `actual`
+/// values.
+pub(crate) fn unified_diff(expected: &Value, actual: &Value) -> String {
+    let expected = serde_json::to_string_pretty(expected).expect("valid JSON");
+    let actual = serde_json::to_string_pretty(actual).expect("valid JSON");
+    diff_lines(
+        &expected.lines().collect::<Vec<_>>(),
+        &actual.lines().collect::<Vec<_>>(),
+    )
+}
+
+/// Bound of the edit distance searched between two sections of lines: beyond
+/// it, the sections are shown as replaced as a whole, which keeps the diff of
+/// completely different values fast.
+const MAX_EDITS: usize = 1000;
+
+struct Line<'a> {
+    tag: char,
+    text: &'a str,
+    old_index: usize,
+    new_index: usize,
+}
+
+fn diff_lines(old: &[&str], new: &[&str]) -> String {
+    let mut lines = vec![];
+    diff_sections(old, new, 0, 0, &mut lines);
+
+    let changes: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.tag != ' ')
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut output = vec![String::from("--- expected"), String::from("+++ actual")];
+    let mut index = 0;
+    while index < changes.len() {
+        let start = changes[index].saturating_sub(CONTEXT);
+        let mut end = (changes[index] + CONTEXT + 1).min(lines.len());
+        index += 1;
+        // Merge the changes whose context overlaps.
+        while index < changes.len() && changes[index] <= end + CONTEXT {
+            end = (changes[index] + CONTEXT + 1).min(lines.len());
+            index += 1;
+        }
+
+        let hunk = &lines[start..end];
+        output.push(format!(
+            "@@ -{},{} +{},{} @@",
+            hunk[0].old_index + 1,
+            hunk.iter().filter(|line| line.tag != '+').count(),
+            hunk[0].new_index + 1,
+            hunk.iter().filter(|line| line.tag != '-').count(),
+        ));
+        output.extend(hunk.iter().map(|line| format!("{}{}", line.tag, line.text)));
+    }
+
+    output.join("\n")
+}
+
+/// Diff `old` and `new`, which start at the given line indices, with Myers'
+/// algorithm in linear space: the sections are split at a point of an
+/// optimal edit path and each half is diffed recursively.
+fn diff_sections<'a>(
+    old: &[&'a str],
+    new: &[&'a str],
+    old_start: usize,
+    new_start: usize,
+    lines: &mut Vec<Line<'a>>,
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    for (offset, text) in old[..prefix].iter().enumerate() {
+        lines.push(Line {
+            tag: ' ',
+            text,
+            old_index: old_start + offset,
+            new_index: new_start + offset,
+        });
+    }
+    let (old_rest, new_rest) = (&old[prefix..], &new[prefix..]);
+    let suffix = old_rest
+        .iter()
+        .rev()
+        .zip(new_rest.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old_rest[..old_rest.len() - suffix];
+    let new_middle = &new_rest[..new_rest.len() - suffix];
+    let (old_start, new_start) = (old_start + prefix, new_start + prefix);
+
+    match split_point(old_middle, new_middle) {
+        Some((x, y)) if (x, y) != (0, 0) && (x, y) != (old_middle.len(), new_middle.len()) => {
+            diff_sections(
+                &old_middle[..x],
+                &new_middle[..y],
+                old_start,
+                new_start,
+                lines,
+            );
+            diff_sections(
+                &old_middle[x..],
+                &new_middle[y..],
+                old_start + x,
+                new_start + y,
+                lines,
+            );
+        }
+        _ => {
+            for (offset, text) in old_middle.iter().enumerate() {
+                lines.push(Line {
+                    tag: '-',
+                    text,
+                    old_index: old_start + offset,
+                    new_index: new_start,
+                });
+            }
+            for (offset, text) in new_middle.iter().enumerate() {
+                lines.push(Line {
+                    tag: '+',
+                    text,
+                    old_index: old_start + old_middle.len(),
+                    new_index: new_start + offset,
+                });
+            }
+        }
+    }
+
+    let (old_end, new_end) = (old_start + old_middle.len(), new_start + new_middle.len());
+    for (offset, text) in old_rest[old_rest.len() - suffix..].iter().enumerate() {
+        lines.push(Line {
+            tag: ' ',
+            text,
+            old_index: old_end + offset,
+            new_index: new_end + offset,
+        });
+    }
+}
+
+/// Find where the forward and backward searches for the shortest edit path
+/// of `old` into `new` meet, or `None` if the sections have nothing in common
+/// within [MAX_EDITS].
+fn split_point(old: &[&str], new: &[&str]) -> Option<(usize, usize)> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max_d = ((n + m + 1) / 2).min(MAX_EDITS as isize);
+    let offset = max_d + 1;
+    let len = (2 * max_d + 3) as usize;
+    // Furthest x reached on each diagonal k = x - y, from the start for
+    // `forward` and from the end for `backward`.
+    let mut forward = vec![-1isize; len];
+    let mut backward = vec![-1isize; len];
+    forward[(offset + 1) as usize] = 0;
+    backward[(offset + 1) as usize] = 0;
+    let delta = n - m;
+    let front = delta % 2 != 0;
+    let (mut k1_start, mut k1_end, mut k2_start, mut k2_end) = (0, 0, 0, 0);
+
+    for d in 0..max_d {
+        let mut k1 = -d + k1_start;
+        while k1 <= d - k1_end {
+            let k1_offset = (offset + k1) as usize;
+            let mut x1 = if k1 == -d || (k1 != d && forward[k1_offset - 1] < forward[k1_offset + 1])
+            {
+                forward[k1_offset + 1]
+            } else {
+                forward[k1_offset - 1] + 1
+            };
+            let mut y1 = x1 - k1;
+            while x1 < n && y1 < m && old[x1 as usize] == new[y1 as usize] {
+                x1 += 1;
+                y1 += 1;
+            }
+            forward[k1_offset] = x1;
+            if x1 > n {
+                k1_end += 2;
+            } else if y1 > m {
+                k1_start += 2;
+            } else if front {
+                let k2_offset = offset + delta - k1;
+                if (0..len as isize).contains(&k2_offset) && backward[k2_offset as usize] != -1 {
+                    let x2 = n - backward[k2_offset as usize];
+                    if x1 >= x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+            k1 += 2;
+        }
+
+        let mut k2 = -d + k2_start;
+        while k2 <= d - k2_end {
+            let k2_offset = (offset + k2) as usize;
+            let mut x2 =
+                if k2 == -d || (k2 != d && backward[k2_offset - 1] < backward[k2_offset + 1]) {
+                    backward[k2_offset + 1]
+                } else {
+                    backward[k2_offset - 1] + 1
+                };
+            let mut y2 = x2 - k2;
+            while x2 < n && y2 < m && old[(n - x2 - 1) as usize] == new[(m - y2 - 1) as usize] {
+                x2 += 1;
+                y2 += 1;
+            }
+            backward[k2_offset] = x2;
+            if x2 > n {
+                k2_end += 2;
+            } else if y2 > m {
+                k2_start += 2;
+            } else if !front {
+                let k1_offset = offset + delta - k2;
+                if (0..len as isize).contains(&k1_offset) && forward[k1_offset as usize] != -1 {
+                    let x1 = forward[k1_offset as usize];
+                    let y1 = offset + x1 - k1_offset;
+                    if x1 >= n - x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+            k2 += 2;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    #[test]
+    fn unified_diff() {
+        let expected = serde_json::json!({"id": 1, "tags": ["a", "b"], "name": "n"});
+        let actual = serde_json::json!({"id": 2, "tags": ["a", "c"], "name": "n"});
+
+        assert_eq!(
+            indoc! {r#"
+                --- expected
+                +++ actual
+                @@ -1,8 +1,8 @@
+                 {
+                -  "id": 1,
+                +  "id": 2,
+                   "name": "n",
+                   "tags": [
+                     "a",
+                -    "b"
+                +    "c"
+                   ]
+                 }"#},
+            super::unified_diff(&expected, &actual)
+        );
+    }
+
+    #[test]
+    fn separate_hunks() {
+        let old: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+        let mut new = old.clone();
+        new[1] = String::from("x");
+        new[18] = String::from("y");
+        let old: Vec<&str> = old.iter().map(String::as_str).collect();
+        let new: Vec<&str> = new.iter().map(String::as_str).collect();
+
+        assert_eq!(
+            indoc! {"
+                --- expected
+                +++ actual
+                @@ -1,5 +1,5 @@
+                 0
+                -1
+                +x
+                 2
+                 3
+                 4
+                @@ -16,5 +16,5 @@
+                 15
+                 16
+                 17
+                -18
+                +y
+                 19"},
+            super::diff_lines(&old, &new)
+        );
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(
+            "--- expected\n+++ actual",
+            super::diff_lines(&["a", "b"], &["a", "b"])
+        );
+    }
+
+    #[test]
+    fn large_values() {
+        let expected: Vec<usize> = (0..40_000).collect();
+        let mut actual = expected.clone();
+        actual[20_000] = 0;
+
+        let diff = super::unified_diff(&serde_json::json!(expected), &serde_json::json!(actual));
+        assert_eq!(
+            vec!["-  20000,", "+  0,"],
+            diff.lines()
+                .skip(2)
+                .filter(|line| line.starts_with(['-', '+']))
+                .collect::<Vec<_>>()
+        );
+
+        // Completely different values are shown as replaced as a whole.
+        let actual: Vec<usize> = (40_000..80_000).collect();
+        let diff = super::unified_diff(&serde_json::json!(expected), &serde_json::json!(actual));
+        assert_eq!(80_005, diff.lines().count());
+    }
+}
@@ -0,0 +1,73 @@
+//! Load expected documents from JSON files.
+
+use crate::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Parsed fixtures, by path, so each file is read once per test binary.
+static FIXTURES: OnceLock<Mutex<HashMap<PathBuf, Value>>> = OnceLock::new();
+
+/// Read and parse the JSON file at `path`. With the `json5` feature, the file
+/// may use the JSON5 syntax.
+///
+/// Panics if the file cannot be read or is not valid JSON.
+pub(crate) fn load(path: &Path) -> Value {
+    let fixtures = FIXTURES.get_or_init(Default::default);
+    if let Some(value) = fixtures.lock().unwrap().get(path) {
+        return value.clone();
+    }
+
+    let text = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read fixture {}: {}", path.display(), err));
+    let value = parse(&text)
+        .unwrap_or_else(|err| panic!("failed to parse fixture {}: {}", path.display(), err));
+    fixtures
+        .lock()
+        .unwrap()
+        .insert(path.to_path_buf(), value.clone());
+    value
+}
+
+#[cfg(not(feature = "json5"))]
+fn parse(text: &str) -> Result<Value, serde_json::Error> {
+    serde_json::from_str(text)
+}
+
+#[cfg(feature = "json5")]
+fn parse(text: &str) -> Result<Value, crate::formats::ParseError> {
+    crate::formats::from_json5_str(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    #[test]
+    fn load() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/expected_user.json");
+
+        assert_eq!(
+            serde_json::json!({"id": 5, "name": "charlesvdv", "roles": ["admin", "user"]}),
+            super::load(&path)
+        );
+        assert_eq!(super::load(&path), super::load(&path));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to read fixture")]
+    fn load_missing_file() {
+        super::load(Path::new("tests/fixtures/missing.json"));
+    }
+
+    #[test]
+    #[cfg(feature = "json5")]
+    fn load_json5() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/expected_user.json5");
+
+        assert_eq!(
+            serde_json::json!({"id": 5, "name": "charlesvdv", "roles": ["admin", "user"]}),
+            super::load(&path)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Decoding of CBOR ([RFC 8949](https://www.rfc-editor.org/rfc/rfc8949)) into
+//! a [Value], with `ciborium`.
+//!
+//! Values map onto JSON like `serde_json` transcodes them: byte strings become
+//! arrays of numbers, integer keys become strings, tags are ignored and
+//! infinite or NaN floats as well as `undefined` become null.
+
+use super::bytes::{Transcoded, MAX_DEPTH};
+use super::ParseError;
+use ciborium::de::Error;
+use serde_json::Value;
+
+/// Decode a single CBOR data item.
+///
+/// ```
+/// let value = assert_json::formats::from_cbor_slice(&[0xa1, 0x61, 0x61, 0x01]).unwrap();
+/// assert_json::assert_json!(value, { "a": 1 });
+/// ```
+pub fn from_cbor_slice(bytes: &[u8]) -> Result<Value, ParseError> {
+    let mut rest = bytes;
+    let result = ciborium::de::from_reader_with_recursion_limit(&mut rest, MAX_DEPTH);
+    let read = bytes.len() - rest.len();
+    match result {
+        Ok(Transcoded(_)) if !rest.is_empty() => {
+            Err(ParseError::at_offset(read, "unexpected trailing bytes"))
+        }
+        Ok(Transcoded(value)) => Ok(value),
+        Err(Error::Io(_)) => Err(ParseError::at_offset(read, "unexpected end of input")),
+        Err(Error::Syntax(offset)) => Err(ParseError::at_offset(offset, "invalid CBOR")),
+        Err(Error::Semantic(offset, message)) => Err(ParseError::at_offset(
+            offset.unwrap_or(read.saturating_sub(1)),
+            message,
+        )),
+        Err(Error::RecursionLimitExceeded) => Err(ParseError::at_offset(
+            read.saturating_sub(1),
+            "recursion limit exceeded",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn integers() {
+        assert_eq!(from_cbor_slice(&[0x17]).unwrap(), json!(23));
+        assert_eq!(from_cbor_slice(&[0x18, 0x64]).unwrap(), json!(100));
+        assert_eq!(from_cbor_slice(&[0x19, 0x03, 0xe8]).unwrap(), json!(1000));
+        assert_eq!(from_cbor_slice(&[0x20]).unwrap(), json!(-1));
+        assert_eq!(from_cbor_slice(&[0x38, 0x63]).unwrap(), json!(-100));
+        assert_eq!(
+            from_cbor_slice(&[0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).unwrap(),
+            json!(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(from_cbor_slice(&[0xf9, 0x3e, 0x00]).unwrap(), json!(1.5));
+        assert_eq!(
+            from_cbor_slice(&[0xf9, 0x00, 0x01]).unwrap(),
+            json!(5.960464477539063e-8)
+        );
+        assert_eq!(
+            from_cbor_slice(&[0xfa, 0x47, 0xc3, 0x50, 0x00]).unwrap(),
+            json!(100000.0)
+        );
+        assert_eq!(
+            from_cbor_slice(&[0xfb, 0x3f, 0xf1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9a]).unwrap(),
+            json!(1.1)
+        );
+        assert_eq!(from_cbor_slice(&[0xf9, 0x7c, 0x00]).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn simple_values() {
+        assert_eq!(from_cbor_slice(&[0xf4]).unwrap(), json!(false));
+        assert_eq!(from_cbor_slice(&[0xf5]).unwrap(), json!(true));
+        assert_eq!(from_cbor_slice(&[0xf6]).unwrap(), Value::Null);
+        assert_eq!(from_cbor_slice(&[0xf7]).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            from_cbor_slice(&[0x64, 0x49, 0x45, 0x54, 0x46]).unwrap(),
+            json!("IETF")
+        );
+        assert_eq!(
+            from_cbor_slice(&[0x43, 0x01, 0x02, 0x03]).unwrap(),
+            json!([1, 2, 3])
+        );
+        assert_eq!(
+            from_cbor_slice(&[0x7f, 0x62, 0x73, 0x74, 0x61, 0x61, 0xff]).unwrap(),
+            json!("sta")
+        );
+        assert_eq!(
+            from_cbor_slice(&[0x5f, 0x41, 0x01, 0x42, 0x02, 0x03, 0xff]).unwrap(),
+            json!([1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn collections() {
+        // {"a": 1, "b": [2, 3]}
+        let bytes = [0xa2, 0x61, 0x61, 0x01, 0x61, 0x62, 0x82, 0x02, 0x03];
+        assert_eq!(
+            from_cbor_slice(&bytes).unwrap(),
+            json!({"a": 1, "b": [2, 3]})
+        );
+        // [_ 1, [2, 3]]
+        let bytes = [0x9f, 0x01, 0x82, 0x02, 0x03, 0xff];
+        assert_eq!(from_cbor_slice(&bytes).unwrap(), json!([1, [2, 3]]));
+        // {_ "a": 1}
+        let bytes = [0xbf, 0x61, 0x61, 0x01, 0xff];
+        assert_eq!(from_cbor_slice(&bytes).unwrap(), json!({"a": 1}));
+    }
+
+    #[test]
+    fn integer_keys() {
+        assert_eq!(
+            from_cbor_slice(&[0xa1, 0x01, 0x02]).unwrap(),
+            json!({"1": 2})
+        );
+    }
+
+    #[test]
+    fn tags() {
+        // 1(1363896240)
+        let bytes = [0xc1, 0x1a, 0x51, 0x4b, 0x67, 0xb0];
+        assert_eq!(from_cbor_slice(&bytes).unwrap(), json!(1363896240));
+        // 2(h'0100'), a bignum
+        let bytes = [0xc2, 0x42, 0x01, 0x00];
+        assert_eq!(from_cbor_slice(&bytes).unwrap(), json!(256));
+    }
+
+    #[test]
+    fn errors() {
+        let err = from_cbor_slice(&[0x82, 0x01]).unwrap_err();
+        assert_eq!(err.message(), "unexpected end of input");
+        assert_eq!(err.offset(), Some(2));
+        assert_eq!(err.to_string(), "unexpected end of input at byte 2");
+
+        let err = from_cbor_slice(&[0x01, 0x02]).unwrap_err();
+        assert_eq!(err.message(), "unexpected trailing bytes");
+        assert_eq!(err.offset(), Some(1));
+
+        let err = from_cbor_slice(&[0xa1, 0x80, 0x01]).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "invalid type: sequence, expected a string or an integer key"
+        );
+
+        let err = from_cbor_slice(&[0x62, 0xff, 0xfe]).unwrap_err();
+        assert_eq!(err.to_string(), "invalid CBOR at byte 0");
+
+        let err = from_cbor_slice(&[0xff]).unwrap_err();
+        assert_eq!(err.message(), "invalid type: break, expected non-break");
+
+        let err = from_cbor_slice(&[0x1c]).unwrap_err();
+        assert_eq!(err.message(), "invalid CBOR");
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let bytes = vec![0x81; MAX_DEPTH + 2];
+        let err = from_cbor_slice(&bytes).unwrap_err();
+        assert_eq!(err.message(), "recursion limit exceeded");
+    }
+}
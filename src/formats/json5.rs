@@ -0,0 +1,298 @@
+//! [JSON5](https://spec.json5.org) documents, parsed with the `json5` crate.
+//!
+//! JSON5 extends JSON with comments, trailing commas, unquoted keys, single
+//! quoted strings and more number notations, which makes large expected
+//! documents easier to maintain.
+
+use super::ParseError;
+use crate::Value;
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Number};
+use std::fmt;
+
+/// Parse a JSON5 document into a [Value].
+///
+/// `Infinity` and `NaN` are rejected as they can't be represented in JSON.
+/// When a key is repeated, the last value wins.
+///
+/// ```
+/// use assert_json::{assert_json, formats, validators};
+///
+/// let expected = formats::from_json5_str(r#"
+/// {
+///     // Pinned until the next migration.
+///     version: 3,
+///     name: 'charlesvdv',
+///     roles: ['admin', 'user',],
+/// }
+/// "#).unwrap();
+///
+/// assert_json!(
+///     r#"{"version": 3, "name": "charlesvdv", "roles": ["admin", "user"]}"#,
+///     validators::eq(expected)
+/// );
+/// ```
+pub fn from_json5_str(text: &str) -> Result<Value, ParseError> {
+    match json5::from_str::<JsonValue>(text) {
+        Ok(JsonValue(value)) => Ok(value),
+        Err(err) => {
+            let message = match (err.code(), err.position()) {
+                (Some(code), _) => code.to_string(),
+                (None, Some(position)) => {
+                    let message = err.to_string();
+                    let suffix = format!(" at {}", position);
+                    message.strip_suffix(&suffix).unwrap_or(&message).to_owned()
+                }
+                (None, None) => err.to_string(),
+            };
+            Err(match err.position() {
+                Some(position) => ParseError::new(position.line + 1, position.column + 1, message),
+                // Errors at the end of the text have no position.
+                None => {
+                    let chars: Vec<char> = text.chars().collect();
+                    ParseError::at_char(&chars, chars.len(), message)
+                }
+            })
+        }
+    }
+}
+
+/// Maximum nesting of arrays and objects, to bound the recursion.
+const MAX_DEPTH: usize = 256;
+
+/// A [Value] deserialized from JSON5, rejecting the numbers JSON can't hold
+/// where they are written.
+struct JsonValue(Value);
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        JsonSeed { depth: 0 }
+            .deserialize(deserializer)
+            .map(JsonValue)
+    }
+}
+
+/// Deserializes a value nested in `depth` arrays and objects.
+struct JsonSeed {
+    depth: usize,
+}
+
+impl<'de> DeserializeSeed<'de> for JsonSeed {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.depth > MAX_DEPTH {
+            return Err(de::Error::custom("recursion limit exceeded"));
+        }
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for JsonSeed {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON5 value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        match i64::try_from(value) {
+            Ok(value) => Ok(Value::from(value)),
+            Err(_) => self.visit_f64(value as f64),
+        }
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        match u64::try_from(value) {
+            Ok(value) => Ok(Value::from(value)),
+            Err(_) => self.visit_f64(value as f64),
+        }
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        Number::from_f64(value).map(Value::Number).ok_or_else(|| {
+            let name = match value {
+                _ if value.is_nan() => "NaN",
+                _ if value > 0.0 => "Infinity",
+                _ => "-Infinity",
+            };
+            E::custom(format!("'{name}' can't be represented in JSON"))
+        })
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::String(value.to_owned()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array = Vec::new();
+        while let Some(element) = seq.next_element_seed(JsonSeed {
+            depth: self.depth + 1,
+        })? {
+            array.push(element);
+        }
+        Ok(Value::Array(array))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut object = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(JsonSeed {
+                depth: self.depth + 1,
+            })?;
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(text: &str) -> Value {
+        from_json5_str(text).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            parse(r#"{"a": [1, -2.5, "x", true, false, null], "b": {}}"#),
+            json!({"a": [1, -2.5, "x", true, false, null], "b": {}})
+        );
+    }
+
+    #[test]
+    fn comments_and_trailing_commas() {
+        let text = "
+            // The user.
+            {
+                /* pinned */ id: 5,
+                roles: ['admin', 'user',], // trailing comma
+            }
+        ";
+        assert_eq!(parse(text), json!({"id": 5, "roles": ["admin", "user"]}));
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            parse("{ $id: 1, _private: 2, café: 3, 'single': 4, \\u0061b: 5, null: 6 }"),
+            json!({"$id": 1, "_private": 2, "café": 3, "single": 4, "ab": 5, "null": 6})
+        );
+        assert_eq!(parse("{ a: 1, a: 2 }"), json!({"a": 2}));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(parse(r#"'it\'s "quoted"'"#), json!("it's \"quoted\""));
+        assert_eq!(parse(r#""\x41é\v\0\q""#), json!("Aé\u{0b}\0q"));
+        assert_eq!(parse(r#""😀""#), json!("😀"));
+        assert_eq!(parse("'line \\\nbreak'"), json!("line break"));
+        assert_eq!(parse("'line \\\r\nbreak'"), json!("line break"));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse("0x1F"), json!(31));
+        assert_eq!(parse("-0xff"), json!(-255));
+        assert_eq!(parse("+1"), json!(1));
+        assert_eq!(parse(".5"), json!(0.5));
+        assert_eq!(parse("5."), json!(5.0));
+        assert_eq!(parse("-1.5e+2"), json!(-150.0));
+        assert_eq!(parse("1E-1"), json!(0.1));
+        assert_eq!(parse("18446744073709551615"), json!(u64::MAX));
+        assert_eq!(parse("-9223372036854775808"), json!(i64::MIN));
+        assert_eq!(parse("[1,-1]"), json!([1, -1]));
+    }
+
+    #[test]
+    fn errors() {
+        let err = from_json5_str("{\n  a: Infinity,\n}").unwrap_err();
+        assert_eq!(err.message(), "'Infinity' can't be represented in JSON");
+        assert_eq!((err.line(), err.column()), (2, 6));
+
+        let err = from_json5_str("-NaN").unwrap_err();
+        assert_eq!(err.message(), "'NaN' can't be represented in JSON");
+
+        let err = from_json5_str("{ a: 1 b: 2 }").unwrap_err();
+        assert_eq!(err.message(), "expected comma");
+        assert_eq!((err.line(), err.column()), (1, 8));
+
+        let err = from_json5_str("[1, 2").unwrap_err();
+        assert_eq!(err.message(), "EOF parsing array");
+
+        let err = from_json5_str("/* open").unwrap_err();
+        assert_eq!(err.message(), "EOF parsing value");
+
+        let err = from_json5_str("'open").unwrap_err();
+        assert_eq!(err.message(), "EOF parsing string");
+
+        let err = from_json5_str("1 2").unwrap_err();
+        assert_eq!(err.message(), "trailing characters");
+        assert_eq!((err.line(), err.column()), (1, 3));
+
+        let err = from_json5_str("undefined").unwrap_err();
+        assert_eq!(err.message(), "expected value");
+
+        let err = from_json5_str("01").unwrap_err();
+        assert_eq!(err.message(), "leading zero");
+
+        for number in ["1e", "1.2.3", "."] {
+            let err = from_json5_str(number).unwrap_err();
+            assert_eq!(err.message(), "invalid float literal", "{}", number);
+        }
+
+        let err = from_json5_str(r#""\1""#).unwrap_err();
+        assert_eq!(err.message(), "invalid escape sequence");
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let text = "[".repeat(MAX_DEPTH + 2);
+        let err = from_json5_str(&text).unwrap_err();
+        assert_eq!(err.message(), "recursion limit exceeded");
+    }
+}
@@ -0,0 +1,104 @@
+//! Parse documents in other formats into a [Value], to validate them like JSON.
+//!
+//! Each format is enabled by the feature of the same name and covers the
+//! documents which map onto JSON.
+
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+mod bytes;
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "json5")]
+mod json5;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+#[cfg(feature = "json5")]
+pub use self::json5::*;
+#[cfg(feature = "toml")]
+pub use self::toml::*;
+#[cfg(feature = "cbor")]
+pub use cbor::*;
+#[cfg(feature = "msgpack")]
+pub use msgpack::*;
+#[cfg(feature = "yaml")]
+pub use yaml::*;
+
+/// Error raised when a document can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    /// Byte offset of the error, for binary formats.
+    offset: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    #[cfg(any(feature = "json5", feature = "toml", feature = "yaml"))]
+    pub(crate) fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            offset: None,
+            message: message.into(),
+        }
+    }
+
+    /// Error at the character `pos` of a text, split in characters.
+    #[cfg(any(feature = "json5", feature = "toml"))]
+    pub(crate) fn at_char(chars: &[char], pos: usize, message: impl Into<String>) -> Self {
+        let before = &chars[..pos.min(chars.len())];
+        let line = before.iter().filter(|c| **c == '\n').count() + 1;
+        let column = before.iter().rev().take_while(|c| **c != '\n').count() + 1;
+        ParseError::new(line, column, message)
+    }
+
+    #[cfg(any(feature = "cbor", feature = "msgpack"))]
+    pub(crate) fn at_offset(offset: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            offset: Some(offset),
+            message: message.into(),
+        }
+    }
+
+    /// Line of the error, starting at 1. It is 0 for binary formats.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the error, starting at 1. It is 0 for binary formats.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Offset of the byte of the error, for binary formats.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Description of the error, without its position.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.offset {
+            Some(offset) => write!(f, "{} at byte {}", self.message, offset),
+            None => write!(
+                f,
+                "{} at line {} column {}",
+                self.message, self.line, self.column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
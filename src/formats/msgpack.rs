@@ -0,0 +1,171 @@
+//! Decoding of [MessagePack](https://github.com/msgpack/msgpack/blob/master/spec.md)
+//! into a [Value], with `rmp-serde`.
+//!
+//! Values map onto JSON like `serde_json` transcodes them: binaries become
+//! arrays of numbers, integer keys become strings and infinite or NaN floats
+//! become null. Extension types are not supported.
+
+use super::bytes::{Transcoded, MAX_DEPTH};
+use super::ParseError;
+use rmp_serde::decode::Error;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Decode a single MessagePack object.
+///
+/// ```
+/// let value = assert_json::formats::from_msgpack_slice(&[0x81, 0xa1, 0x61, 0x01]).unwrap();
+/// assert_json::assert_json!(value, { "a": 1 });
+/// ```
+pub fn from_msgpack_slice(bytes: &[u8]) -> Result<Value, ParseError> {
+    let mut rest = bytes;
+    let mut deserializer = rmp_serde::Deserializer::new(&mut rest);
+    deserializer.set_max_depth(MAX_DEPTH);
+    let result = Transcoded::deserialize(&mut deserializer);
+    let read = bytes.len() - rest.len();
+    let message = match result {
+        Ok(Transcoded(_)) if !rest.is_empty() => {
+            return Err(ParseError::at_offset(read, "unexpected trailing bytes"))
+        }
+        Ok(Transcoded(value)) => return Ok(value),
+        Err(Error::InvalidMarkerRead(_) | Error::InvalidDataRead(_)) => {
+            return Err(ParseError::at_offset(read, "unexpected end of input"))
+        }
+        // Any marker is expected but the reserved one.
+        Err(Error::TypeMismatch(_)) => String::from("invalid marker"),
+        Err(Error::DepthLimitExceeded) => String::from("recursion limit exceeded"),
+        Err(Error::Utf8Error(_)) => String::from("invalid UTF-8 string"),
+        Err(err) => err.to_string(),
+    };
+    Err(ParseError::at_offset(read.saturating_sub(1), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn integers() {
+        assert_eq!(from_msgpack_slice(&[0x7f]).unwrap(), json!(127));
+        assert_eq!(from_msgpack_slice(&[0xff]).unwrap(), json!(-1));
+        assert_eq!(from_msgpack_slice(&[0xe0]).unwrap(), json!(-32));
+        assert_eq!(from_msgpack_slice(&[0xcc, 0xc8]).unwrap(), json!(200));
+        assert_eq!(
+            from_msgpack_slice(&[0xcd, 0x03, 0xe8]).unwrap(),
+            json!(1000)
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).unwrap(),
+            json!(u64::MAX)
+        );
+        assert_eq!(from_msgpack_slice(&[0xd0, 0x80]).unwrap(), json!(-128));
+        assert_eq!(
+            from_msgpack_slice(&[0xd1, 0xfc, 0x18]).unwrap(),
+            json!(-1000)
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xd2, 0xff, 0xff, 0xff, 0xff]).unwrap(),
+            json!(-1)
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xd3, 0x80, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+            json!(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(
+            from_msgpack_slice(&[0xca, 0x3f, 0xc0, 0x00, 0x00]).unwrap(),
+            json!(1.5)
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xcb, 0x3f, 0xf1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9a]).unwrap(),
+            json!(1.1)
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xca, 0x7f, 0xc0, 0x00, 0x00]).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn simple_values() {
+        assert_eq!(from_msgpack_slice(&[0xc0]).unwrap(), Value::Null);
+        assert_eq!(from_msgpack_slice(&[0xc2]).unwrap(), json!(false));
+        assert_eq!(from_msgpack_slice(&[0xc3]).unwrap(), json!(true));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            from_msgpack_slice(&[0xa2, 0x68, 0x69]).unwrap(),
+            json!("hi")
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xd9, 0x02, 0x68, 0x69]).unwrap(),
+            json!("hi")
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xda, 0x00, 0x02, 0x68, 0x69]).unwrap(),
+            json!("hi")
+        );
+        assert_eq!(
+            from_msgpack_slice(&[0xc4, 0x02, 0x01, 0x02]).unwrap(),
+            json!([1, 2])
+        );
+    }
+
+    #[test]
+    fn collections() {
+        // {"a": 1, "b": [2, 3]}
+        let bytes = [0x82, 0xa1, 0x61, 0x01, 0xa1, 0x62, 0x92, 0x02, 0x03];
+        assert_eq!(
+            from_msgpack_slice(&bytes).unwrap(),
+            json!({"a": 1, "b": [2, 3]})
+        );
+        let bytes = [0xdc, 0x00, 0x02, 0x01, 0x02];
+        assert_eq!(from_msgpack_slice(&bytes).unwrap(), json!([1, 2]));
+        let bytes = [0xde, 0x00, 0x01, 0xa1, 0x61, 0xc0];
+        assert_eq!(from_msgpack_slice(&bytes).unwrap(), json!({"a": null}));
+    }
+
+    #[test]
+    fn integer_keys() {
+        assert_eq!(
+            from_msgpack_slice(&[0x81, 0x01, 0x02]).unwrap(),
+            json!({"1": 2})
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = from_msgpack_slice(&[0x92, 0x01]).unwrap_err();
+        assert_eq!(err.message(), "unexpected end of input");
+        assert_eq!(err.offset(), Some(2));
+
+        let err = from_msgpack_slice(&[0x01, 0x02]).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected trailing bytes at byte 1");
+
+        let err = from_msgpack_slice(&[0x81, 0x90, 0x01]).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "invalid type: sequence, expected a string or an integer key"
+        );
+
+        let err = from_msgpack_slice(&[0xd4, 0x01, 0x00]).unwrap_err();
+        assert_eq!(err.message(), "extension types are not supported");
+        assert_eq!(err.offset(), Some(0));
+
+        let err = from_msgpack_slice(&[0xc1]).unwrap_err();
+        assert_eq!(err.message(), "invalid marker");
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let bytes = vec![0x91; MAX_DEPTH + 2];
+        let err = from_msgpack_slice(&bytes).unwrap_err();
+        assert_eq!(err.message(), "recursion limit exceeded");
+    }
+}
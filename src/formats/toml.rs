@@ -0,0 +1,243 @@
+//! TOML documents, parsed with the `toml` crate.
+//!
+//! Dates and times have no JSON equivalent: they are kept as strings.
+
+use super::ParseError;
+use crate::Value;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::Map;
+use std::fmt;
+
+/// Parse a TOML document into a [Value] holding an object.
+///
+/// Dates and times are parsed into strings, like `"1979-05-27T07:32:00Z"`,
+/// which the datetime validators understand. Infinite and NaN floats are
+/// rejected as they can't be represented in JSON.
+///
+/// ```
+/// use assert_json::{assert_json, formats, validators};
+///
+/// let manifest = formats::from_toml_str(r#"
+/// [package]
+/// name = "assert_json"
+/// edition = "2021"
+///
+/// [dependencies]
+/// serde = { version = "1.0", features = ["derive"] }
+/// "#).unwrap();
+///
+/// assert_json!(manifest, {
+///     "package": { "name": "assert_json" },
+///     "dependencies": { "serde": { "features": ["derive"] } },
+/// });
+/// ```
+pub fn from_toml_str(text: &str) -> Result<Value, ParseError> {
+    match toml::from_str::<JsonValue>(text) {
+        Ok(JsonValue(value)) => Ok(value),
+        Err(err) => {
+            let chars: Vec<char> = match err.span() {
+                Some(span) => text[..span.start].chars().collect(),
+                None => text.chars().collect(),
+            };
+            Err(ParseError::at_char(&chars, chars.len(), err.message()))
+        }
+    }
+}
+
+/// Key under which the `toml` deserializer hands out dates and times.
+const DATETIME_KEY: &str = "$__toml_private_datetime";
+
+/// A [Value] deserialized from TOML, rejecting the floats JSON can't hold
+/// where they are written.
+struct JsonValue(Value);
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(JsonVisitor).map(JsonValue)
+    }
+}
+
+struct JsonVisitor;
+
+impl<'de> Visitor<'de> for JsonVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a TOML value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        serde_json::Number::from_f64(value)
+            .map(Value::Number)
+            .ok_or_else(|| E::custom(format!("'{value}' can't be represented in JSON")))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::String(value.to_owned()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array = Vec::new();
+        while let Some(JsonValue(element)) = seq.next_element()? {
+            array.push(element);
+        }
+        Ok(Value::Array(array))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut object = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if key == DATETIME_KEY {
+                return Ok(Value::String(map.next_value()?));
+            }
+            let JsonValue(value) = map.next_value()?;
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    fn parse(text: &str) -> crate::Value {
+        super::from_toml_str(text).unwrap()
+    }
+
+    #[test]
+    fn tables() {
+        assert_eq!(
+            json!({
+                "title": "example",
+                "owner": {"name": "Tom", "address": {"city": "Paris"}},
+                "servers": {"alpha": {"ip": "10.0.0.1"}, "beta": {"ip": "10.0.0.2"}},
+                "products": [{"name": "Hammer"}, {}, {"name": "Nail", "sizes": [{"mm": 2}]}],
+            }),
+            parse(
+                r#"
+# a comment
+title = "example"
+
+[owner]
+name = "Tom"  # inline comment
+address.city = "Paris"
+
+[servers.alpha]
+ip = "10.0.0.1"
+
+[servers.beta]
+ip = "10.0.0.2"
+
+[[products]]
+name = "Hammer"
+
+[[products]]
+
+[[products]]
+name = "Nail"
+[[products.sizes]]
+mm = 2
+"#
+            )
+        );
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(
+            json!({
+                "int": [1, -17, 1000, 255, 8, 5],
+                "float": [2.5, -0.01, 5e22, 1000.0],
+                "bool": [true, false],
+                "nested": [[1, 2], ["a"], []],
+                "inline": {"x": 1, "y": {"z": "w"}},
+                "dates": ["1979-05-27T07:32:00Z", "1979-05-27T00:32:00.999-07:00", "1979-05-27", "07:32:00"],
+            }),
+            parse(
+                r#"
+int = [1, -17, 1_000, 0xff, 0o10, 0b101]
+float = [2.5, -0.01, 5e+22, 1e3]
+bool = [true, false]
+nested = [
+  [1, 2], # comment
+  ["a"],
+  [],
+]
+inline = { x = 1, y.z = "w" }
+dates = [1979-05-27T07:32:00Z, 1979-05-27 00:32:00.999-07:00, 1979-05-27, 07:32:00]
+"#
+            )
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            json!({
+                "basic": "tab\t\"quoted\" \u{e9}",
+                "literal": "C:\\Users\\n",
+                "multi": "line 1\nline 2",
+                "folded": "a b c",
+                "raw": "no \\escape\n",
+                "quoted key": 1,
+            }),
+            parse(
+                "basic = \"tab\\t\\\"quoted\\\" \\u00E9\"\n\
+                 literal = 'C:\\Users\\n'\n\
+                 multi = \"\"\"\nline 1\nline 2\"\"\"\n\
+                 folded = \"\"\"a \\\n    b \\\n    c\"\"\"\n\
+                 raw = '''no \\escape\n'''\n\
+                 \"quoted key\" = 1\n"
+            )
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = super::from_toml_str("a = 1\na = 2\n").unwrap_err();
+        assert_eq!("duplicate key at line 2 column 1", err.to_string());
+
+        assert_eq!(
+            "duplicate key",
+            super::from_toml_str("[a]\n[a]\n").unwrap_err().message()
+        );
+        let err = super::from_toml_str("x = 1\n[t]\nb = [1, -inf]").unwrap_err();
+        assert_eq!(
+            "'-inf' can't be represented in JSON at line 3 column 9",
+            err.to_string()
+        );
+        assert!(super::from_toml_str("a = 1 b = 2").is_err());
+        assert!(super::from_toml_str("a = 01").is_err());
+        assert!(super::from_toml_str("a = \"open").is_err());
+        assert!(super::from_toml_str("a = 1\n[a]\n").is_err());
+    }
+}
@@ -0,0 +1,298 @@
+//! YAML documents, parsed with `serde_yaml`.
+//!
+//! Anchors and aliases are resolved, merge keys (`<<`) are applied and tags
+//! are dropped, keeping the tagged value. Keys which are not strings are
+//! converted to their text.
+
+use super::ParseError;
+use crate::Value;
+use serde::Deserialize;
+use serde_json::Map;
+
+/// Parse a YAML document into a [Value].
+///
+/// Scalars are resolved with the YAML 1.2 core schema: `null` and `~` are
+/// null, `true` and `false` are booleans, integers and floats are numbers and
+/// everything else is a string. An empty document is null. Fail if the text
+/// holds more than one document, see [from_yaml_documents], or a number which
+/// JSON can't hold, like `.inf`.
+///
+/// ```
+/// use assert_json::{assert_json, formats};
+///
+/// let manifest = formats::from_yaml_str("
+/// kind: Deployment
+/// spec:
+///   replicas: 3
+///   containers:
+///     - name: web
+///       ports: [80, 443]
+/// ").unwrap();
+///
+/// assert_json!(manifest, {
+///     "kind": "Deployment",
+///     "spec": { "replicas": 3, "containers": [{ "name": "web", "ports": [80, 443] }] },
+/// });
+/// ```
+pub fn from_yaml_str(text: &str) -> Result<Value, ParseError> {
+    let mut documents = from_yaml_documents(text)?;
+    if documents.len() > 1 {
+        let line = second_document_line(text);
+        return Err(ParseError::new(
+            line,
+            1,
+            "unexpected second document, use from_yaml_documents",
+        ));
+    }
+    Ok(documents.pop().unwrap_or(Value::Null))
+}
+
+/// Parse a stream of YAML documents, separated by `---` lines, into a [Value]
+/// per document.
+///
+/// ```
+/// use assert_json::formats;
+///
+/// let documents = formats::from_yaml_documents("kind: Service\n---\nkind: Deployment\n").unwrap();
+/// assert_eq!(2, documents.len());
+/// ```
+pub fn from_yaml_documents(text: &str) -> Result<Vec<Value>, ParseError> {
+    serde_yaml::Deserializer::from_str(text)
+        .map(|document| {
+            let mut value = serde_yaml::Value::deserialize(document).map_err(parse_error)?;
+            value.apply_merge().map_err(parse_error)?;
+            convert(value)
+        })
+        .collect()
+}
+
+/// Line of the start of the second document, after a `---` marker.
+fn second_document_line(text: &str) -> usize {
+    let mut markers = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| *line == "---" || line.starts_with("--- "));
+    let first = markers.next();
+    // The first document may start without marker.
+    let content_before = text
+        .lines()
+        .take(first.map_or(0, |(index, _)| index))
+        .any(|line| !line.trim().is_empty() && !line.starts_with(['#', '%']));
+    let second = if content_before {
+        first
+    } else {
+        markers.next()
+    };
+    second.map_or(1, |(index, _)| index + 1)
+}
+
+fn parse_error(err: serde_yaml::Error) -> ParseError {
+    let (line, column) = err
+        .location()
+        .map_or((0, 0), |location| (location.line(), location.column()));
+    // The message of serde_yaml ends with the position.
+    let message = err.to_string();
+    let message = match message.find(" at line ") {
+        Some(index) => String::from(&message[..index]),
+        None => message,
+    };
+    ParseError::new(line, column, message)
+}
+
+/// Convert a YAML value into a JSON value.
+fn convert(value: serde_yaml::Value) -> Result<Value, ParseError> {
+    Ok(match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(b) => Value::Bool(b),
+        serde_yaml::Value::Number(number) => {
+            if let Some(n) = number.as_i64() {
+                Value::from(n)
+            } else if let Some(n) = number.as_u64() {
+                Value::from(n)
+            } else {
+                let n = number.as_f64().unwrap_or(f64::NAN);
+                serde_json::Number::from_f64(n)
+                    .map(Value::Number)
+                    .ok_or_else(|| {
+                        ParseError::new(0, 0, format!("{} is not a JSON number", number))
+                    })?
+            }
+        }
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(sequence) => Value::Array(
+            sequence
+                .into_iter()
+                .map(convert)
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_yaml::Value::Mapping(mapping) => {
+            let mut object = Map::new();
+            for (key, value) in mapping {
+                let key = match convert(key)? {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                };
+                object.insert(key, convert(value)?);
+            }
+            Value::Object(object)
+        }
+        serde_yaml::Value::Tagged(tagged) => convert(tagged.value)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    fn parse(text: &str) -> crate::Value {
+        super::from_yaml_str(text).unwrap()
+    }
+
+    #[test]
+    fn block_collections() {
+        assert_eq!(
+            json!({
+                "kind": "Deployment",
+                "metadata": {"name": "web", "labels": {"app": "web"}},
+                "spec": {
+                    "containers": [
+                        {"name": "web", "image": "nginx:1.25", "args": ["-v", "--port=80"]},
+                        {"name": "sidecar"},
+                    ],
+                    "volumes": [],
+                },
+            }),
+            parse(
+                "
+# a deployment
+kind: Deployment
+metadata:
+  name: web   # inline comment
+  labels:
+    app: web
+spec:
+  containers:
+  - name: web
+    image: nginx:1.25
+    args:
+      - -v
+      - --port=80
+  - name: sidecar
+  volumes: []
+"
+            )
+        );
+    }
+
+    #[test]
+    fn nested_sequences() {
+        assert_eq!(
+            json!([[1, 2], [3], {"a": null}]),
+            parse("- - 1\n  - 2\n- - 3\n- a:\n")
+        );
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(
+            json!([
+                null, null, true, false, 12, -3, 255, 8, 1.5, -0.25, 1000.0, "1.2.3", "yes", "a b"
+            ]),
+            parse("[~, null, true, False, 12, -3, 0xff, 0o10, 1.5, -.25, 1e3, 1.2.3, yes, a b]")
+        );
+        assert_eq!(json!("http://example.com"), parse("http://example.com"));
+        assert!(super::from_yaml_str(".inf").is_err());
+    }
+
+    #[test]
+    fn quoted_scalars() {
+        assert_eq!(
+            json!({"a": "x # not a comment", "b": "it's", "c": "tab\tand \u{e9}", "d: e": "f g"}),
+            parse(
+                "a: \"x # not a comment\"\nb: 'it''s'\nc: \"tab\\tand \\u00e9\"\n\"d: e\": \"f\n  g\"\n"
+            )
+        );
+    }
+
+    #[test]
+    fn block_scalars() {
+        assert_eq!(
+            json!({
+                "literal": "line 1\n  line 2\n",
+                "folded": "a b\nc\n",
+                "strip": "x",
+                "keep": "y\n\n",
+            }),
+            parse(
+                "literal: |\n  line 1\n    line 2\nfolded: >\n  a\n  b\n\n  c\nstrip: |-\n  x\nkeep: |+\n  y\n\n"
+            )
+        );
+    }
+
+    #[test]
+    fn flow_collections() {
+        assert_eq!(
+            json!({"a": [1, {"b": "c", "d": null}, []], "e": {"f": [true]}}),
+            parse("a: [1, {b: c, d}, [],]\ne: {\n  f: [true]\n}\n")
+        );
+    }
+
+    #[test]
+    fn anchors_and_tags() {
+        assert_eq!(
+            json!({
+                "defaults": {"image": "rust:1", "retry": 2},
+                "test": {"image": "rust:1", "retry": 2, "script": "cargo test"},
+                "lint": {"image": "rust:1", "retry": 2},
+                "ref": "secret",
+            }),
+            parse(
+                "defaults: &defaults\n  image: rust:1\n  retry: 2\ntest:\n  <<: *defaults\n  script: cargo test\nlint: *defaults\nref: !Ref secret\n"
+            )
+        );
+        assert_eq!(json!({"1": "a", "true": "b"}), parse("1: a\ntrue: b\n"));
+    }
+
+    #[test]
+    fn multi_line_plain_scalar() {
+        assert_eq!(
+            json!({"description": "a long text on two lines", "next": 1}),
+            parse("description: a long text\n  on two lines\nnext: 1\n")
+        );
+    }
+
+    #[test]
+    fn documents() {
+        assert_eq!(
+            vec![json!({"a": 1}), json!(null), json!([2])],
+            super::from_yaml_documents("%YAML 1.2\n---\na: 1\n---\n--- [2]\n").unwrap()
+        );
+        assert_eq!(json!(null), parse(""));
+        assert_eq!(
+            2,
+            super::from_yaml_str("a: 1\n---\nb: 2\n")
+                .unwrap_err()
+                .line()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = super::from_yaml_str("a: 1\n  b: 2\n").unwrap_err();
+        assert_eq!((2, 4), (err.line(), err.column()));
+        assert_eq!(
+            "mapping values are not allowed in this context",
+            err.message()
+        );
+
+        assert_eq!(
+            "duplicate entry with key \"a\" at line 1 column 1",
+            super::from_yaml_str("a: 1\na: 2\n")
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(super::from_yaml_str("a: b: c\n").is_err());
+        assert!(super::from_yaml_str("a: [1, 2\n").is_err());
+        assert!(super::from_yaml_str("a:\n\t- 1\n").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Documents generated from the inputs of fuzzers.
+
+use crate::{Validator, Value};
+use arbitrary::{Arbitrary, Unstructured};
+use std::marker::PhantomData;
+
+/// A validator named by a type, so that fuzzers can generate documents of
+/// [Generated] from it.
+pub trait Contract {
+    /// The validator the generated documents match.
+    fn validator() -> impl Validator;
+}
+
+/// A document matching the validator of the contract `C`, generated with
+/// [generate_seeded](Validator::generate_seeded) from a seed taken from the
+/// input of a fuzzer.
+///
+/// Inputs for which no document is found are rejected as
+/// `arbitrary::Error::IncorrectFormat`.
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use assert_json::{validators, Contract, Generated, Validator};
+///
+/// struct Order;
+///
+/// impl Contract for Order {
+///     fn validator() -> impl Validator {
+///         validators::object_contains(vec![
+///             ("quantity", Box::new(validators::gt(0)) as Box<dyn Validator>),
+///         ])
+///     }
+/// }
+///
+/// // Like the input of `fuzz_target!(|order: Generated<Order>| ...)`.
+/// let mut input = Unstructured::new(b"fuzzer input");
+/// let order = Generated::<Order>::arbitrary(&mut input).unwrap();
+/// assert!(order.value["quantity"].as_f64().unwrap() > 0.0);
+/// ```
+pub struct Generated<C> {
+    pub value: Value,
+    contract: PhantomData<C>,
+}
+
+impl<'a, C> Arbitrary<'a> for Generated<C>
+where
+    C: Contract,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let seed = u64::arbitrary(u)?;
+        let value = C::validator()
+            .generate_seeded(seed)
+            .ok_or(arbitrary::Error::IncorrectFormat)?;
+        Ok(Generated {
+            value,
+            contract: PhantomData,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+impl<C> std::fmt::Debug for Generated<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Generated")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Contract, Generated};
+    use crate::{check, validators, Validator};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    struct User;
+
+    impl Contract for User {
+        fn validator() -> impl Validator {
+            validators::object_contains(vec![
+                (
+                    "id",
+                    Box::new(validators::between(1, 100)) as Box<dyn Validator>,
+                ),
+                ("email", Box::new(validators::is_email())),
+            ])
+        }
+    }
+
+    struct Impossible;
+
+    impl Contract for Impossible {
+        fn validator() -> impl Validator {
+            validators::ge(5).and(validators::lt(5))
+        }
+    }
+
+    #[test]
+    fn arbitrary() {
+        let inputs: [&[u8]; 3] = [b"", b"12345678", b"\xff\x00\xff\x00\xff\x00\xff\x00"];
+        for input in inputs {
+            let user = Generated::<User>::arbitrary(&mut Unstructured::new(input)).unwrap();
+            assert!(check(&user.value, &User::validator()).is_ok(), "{:?}", user);
+        }
+        assert!(matches!(
+            Generated::<Impossible>::arbitrary(&mut Unstructured::new(b"12345678")),
+            Err(arbitrary::Error::IncorrectFormat)
+        ));
+    }
+}
@@ -0,0 +1,1246 @@
+//! Generation of example values from the JSON Schema of a validator, see
+//! [Validator::generate](crate::Validator::generate).
+//!
+//! The schema is taken as a conjunction of subschemas, `allOf` and `$ref`
+//! being flattened into it. The first alternative of an `anyOf` or a `oneOf`
+//! leading to a value is picked, preferring the alternatives which are not
+//! null. String examples are preferred to patterns. Keywords which can't
+//! guide the generation, like `not`, are left to the validation of the
+//! generated value.
+//!
+//! With a seed, the alternatives, the values within ranges, the lengths and
+//! the optional properties are picked at random instead: the random choices
+//! are all 0 without seed, which gives the deterministic example.
+
+use crate::Value;
+use serde_json::{Map, Number};
+use std::cell::Cell;
+
+/// Maximum nesting of the generated values, which stops recursive schemas.
+const MAX_DEPTH: usize = 32;
+
+/// Generate a value matching the JSON Schema, if possible.
+pub(crate) fn example(schema: &Value) -> Option<Value> {
+    generate(schema, None)
+}
+
+/// Generate a value matching the JSON Schema at random, if possible: the same
+/// seed gives the same value.
+pub(crate) fn random_example(schema: &Value, seed: u64) -> Option<Value> {
+    generate(schema, Some(seed))
+}
+
+fn generate(schema: &Value, seed: Option<u64>) -> Option<Value> {
+    let generator = Generator {
+        root: schema,
+        random: Random::new(seed),
+    };
+    generator.generate(vec![schema], 0, &[])
+}
+
+/// Generate a string matching the regular expression, if its syntax is
+/// supported.
+fn regex_example(pattern: &str, random: &Random) -> Option<String> {
+    let mut parser = RegexParser {
+        chars: pattern.chars().collect(),
+        pos: 0,
+        random,
+    };
+    let example = parser.alternation()?;
+    (parser.pos == parser.chars.len()).then_some(example)
+}
+
+/// Source of the random choices, SplitMix64 seeded by the caller.
+struct Random {
+    state: Option<Cell<u64>>,
+}
+
+impl Random {
+    fn new(seed: Option<u64>) -> Random {
+        Random {
+            state: seed.map(Cell::new),
+        }
+    }
+
+    fn is_seeded(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// A number below `bound`, always 0 without seed.
+    fn below(&self, bound: u64) -> u64 {
+        let Some(state) = &self.state else {
+            return 0;
+        };
+        if bound <= 1 {
+            return 0;
+        }
+        let next = state.get().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state.set(next);
+        let mut z = next;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        (z ^ (z >> 31)) % bound
+    }
+
+    /// An index below `len`.
+    fn index(&self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+}
+
+struct Generator<'s> {
+    root: &'s Value,
+    random: Random,
+}
+
+/// Alternatives of which one must match, by subschemas to add to the
+/// conjunction.
+type Choice<'s> = Vec<Vec<&'s Value>>;
+
+/// Subschemas which a value must all match, as objects.
+#[derive(Clone, Default)]
+struct Conjunction<'s> {
+    schemas: Vec<&'s Map<String, Value>>,
+    choices: Vec<Choice<'s>>,
+    /// The `$ref`s followed from the root to the value.
+    references: Vec<&'s str>,
+    /// Whether a `$ref` was followed twice, in which case optional
+    /// properties are left out to end the recursion.
+    recursive: bool,
+}
+
+impl<'s> Conjunction<'s> {
+    /// The values of the keyword, in the subschemas defining it.
+    fn all<'a>(&'a self, keyword: &'a str) -> impl Iterator<Item = &'s Value> + 'a {
+        self.schemas
+            .iter()
+            .filter_map(move |schema| schema.get(keyword))
+    }
+
+    fn first(&self, keyword: &str) -> Option<&'s Value> {
+        self.all(keyword).next()
+    }
+
+    fn has(&self, keywords: &[&str]) -> bool {
+        keywords.iter().any(|keyword| self.first(keyword).is_some())
+    }
+
+    /// The greatest of the numeric values of the keyword.
+    fn max(&self, keyword: &str) -> Option<f64> {
+        self.all(keyword).filter_map(Value::as_f64).reduce(f64::max)
+    }
+
+    /// The least of the numeric values of the keyword.
+    fn min(&self, keyword: &str) -> Option<f64> {
+        self.all(keyword).filter_map(Value::as_f64).reduce(f64::min)
+    }
+}
+
+const TYPES: [&str; 7] = [
+    "object", "array", "string", "number", "integer", "boolean", "null",
+];
+
+impl<'s> Generator<'s> {
+    fn generate(
+        &self,
+        schemas: Vec<&'s Value>,
+        depth: usize,
+        references: &[&'s str],
+    ) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let mut conjunction = Conjunction {
+            references: references.to_vec(),
+            ..Conjunction::default()
+        };
+        for schema in schemas {
+            self.flatten(schema, &mut conjunction, 0)?;
+        }
+        self.choose(conjunction, depth)
+    }
+
+    /// Add the schema to the conjunction, `None` meaning it matches nothing.
+    fn flatten(
+        &self,
+        schema: &'s Value,
+        conjunction: &mut Conjunction<'s>,
+        references: usize,
+    ) -> Option<()> {
+        let object = match schema {
+            Value::Bool(true) => return Some(()),
+            Value::Object(object) => object,
+            _ => return None,
+        };
+        conjunction.schemas.push(object);
+        if let Some(Value::String(reference)) = object.get("$ref") {
+            if references > MAX_DEPTH {
+                return None;
+            }
+            let target = self.root.pointer(reference.strip_prefix('#')?)?;
+            if conjunction.references.contains(&reference.as_str()) {
+                conjunction.recursive = true;
+            } else {
+                conjunction.references.push(reference);
+            }
+            self.flatten(target, conjunction, references + 1)?;
+        }
+        if let Some(Value::Array(schemas)) = object.get("allOf") {
+            for schema in schemas {
+                self.flatten(schema, conjunction, references)?;
+            }
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(alternatives)) = object.get(keyword) {
+                let mut choice: Choice<'s> =
+                    alternatives.iter().map(|schema| vec![schema]).collect();
+                // Nulls make poor examples.
+                choice.sort_by_key(|schemas| schemas[0].get("type") == Some(&Value::from("null")));
+                conjunction.choices.push(choice);
+            }
+        }
+        if let Some(condition) = object.get("if") {
+            let mut then_branch = vec![condition];
+            then_branch.extend(object.get("then"));
+            let else_branch = object.get("else").into_iter().collect();
+            conjunction.choices.push(vec![then_branch, else_branch]);
+        }
+        Some(())
+    }
+
+    fn choose(&self, mut conjunction: Conjunction<'s>, depth: usize) -> Option<Value> {
+        let Some(choice) = conjunction.choices.pop() else {
+            return self.value(&conjunction, depth);
+        };
+        let start = self.random.index(choice.len());
+        let (before, after) = choice.split_at(start);
+        after.iter().chain(before).find_map(|schemas| {
+            let mut conjunction = conjunction.clone();
+            for schema in schemas {
+                self.flatten(schema, &mut conjunction, 0)?;
+            }
+            self.choose(conjunction, depth)
+        })
+    }
+
+    fn value(&self, conjunction: &Conjunction<'s>, depth: usize) -> Option<Value> {
+        if let Some(expected) = conjunction.first("const") {
+            return Some(expected.clone());
+        }
+        let enums: Vec<&Vec<Value>> = conjunction
+            .all("enum")
+            .filter_map(Value::as_array)
+            .collect();
+        if let Some((candidates, others)) = enums.split_first() {
+            let candidates: Vec<&Value> = candidates
+                .iter()
+                .filter(|candidate| others.iter().all(|other| other.contains(candidate)))
+                .collect();
+            return candidates
+                .get(self.random.index(candidates.len()))
+                .map(|candidate| (*candidate).clone());
+        }
+
+        match self.pick_type(conjunction)? {
+            "object" => self.object(conjunction, depth),
+            "array" => self.array(conjunction, depth),
+            "string" => self.string(conjunction),
+            "number" => self.number(conjunction, false),
+            "integer" => self.number(conjunction, true),
+            "boolean" => Some(Value::Bool(self.random.below(2) == 0)),
+            _ => Some(Value::Null),
+        }
+    }
+
+    /// Pick the type allowed by every subschema which their keywords hint at.
+    fn pick_type(&self, conjunction: &Conjunction<'s>) -> Option<&'static str> {
+        let mut allowed: Vec<&'static str> = TYPES.to_vec();
+        for types in conjunction.all("type") {
+            let types: Vec<&str> = match types {
+                Value::String(name) => vec![name.as_str()],
+                Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+                _ => continue,
+            };
+            allowed.retain(|name| {
+                types.contains(name) || (*name == "integer" && types.contains(&"number"))
+            });
+        }
+
+        let hints: [(&str, &[&str]); 4] = [
+            (
+                "object",
+                &[
+                    "properties",
+                    "required",
+                    "patternProperties",
+                    "additionalProperties",
+                    "minProperties",
+                    "propertyNames",
+                ],
+            ),
+            (
+                "array",
+                &[
+                    "items",
+                    "prefixItems",
+                    "contains",
+                    "minItems",
+                    "uniqueItems",
+                ],
+            ),
+            ("string", &["pattern", "format", "minLength", "maxLength"]),
+            (
+                "number",
+                &[
+                    "minimum",
+                    "maximum",
+                    "exclusiveMinimum",
+                    "exclusiveMaximum",
+                    "multipleOf",
+                ],
+            ),
+        ];
+        for (name, keywords) in hints {
+            if conjunction.has(keywords) {
+                if allowed.contains(&name) {
+                    return Some(name);
+                }
+                if name == "number" && allowed.contains(&"integer") {
+                    return Some("integer");
+                }
+            }
+        }
+        if allowed.len() == TYPES.len() {
+            let any = ["null", "boolean", "integer", "string"];
+            return Some(any[self.random.index(any.len())]);
+        }
+        // Keep the order of the first listed types.
+        let listed = conjunction.first("type");
+        let listed: Vec<&str> = match listed {
+            Some(Value::String(name)) => vec![name.as_str()],
+            Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        listed
+            .into_iter()
+            .chain(TYPES)
+            .find_map(|name| allowed.iter().find(|allowed| **allowed == name).copied())
+    }
+
+    fn object(&self, conjunction: &Conjunction<'s>, depth: usize) -> Option<Value> {
+        let required: Vec<&'s str> = conjunction
+            .all("required")
+            .filter_map(Value::as_array)
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let mut keys = required.clone();
+        if !conjunction.recursive {
+            for properties in conjunction.all("properties").filter_map(Value::as_object) {
+                keys.extend(properties.keys().map(String::as_str));
+            }
+        }
+        let mut seen = std::collections::HashSet::new();
+        keys.retain(|key| seen.insert(*key));
+
+        let additional: Vec<&'s Value> = conjunction.all("additionalProperties").collect();
+        let mut object = Map::new();
+        for key in keys {
+            let mut schemas: Vec<&'s Value> = conjunction
+                .all("properties")
+                .filter_map(|properties| properties.get(key))
+                .collect();
+            if schemas.is_empty() {
+                schemas.extend(&additional);
+            }
+            if !required.contains(&key) && self.random.below(2) == 1 {
+                continue;
+            }
+            // Optional properties are left out if no value matches them.
+            match self.generate(schemas, depth + 1, &conjunction.references) {
+                Some(value) => {
+                    object.insert(String::from(key), value);
+                }
+                None if required.contains(&key) => return None,
+                None => {}
+            }
+        }
+
+        let min_properties = conjunction.max("minProperties").unwrap_or(0.0) as usize;
+        let patterns = conjunction
+            .all("patternProperties")
+            .filter_map(Value::as_object);
+        for (pattern, schema) in patterns.flatten() {
+            if object.len() >= min_properties.max(1) {
+                break;
+            }
+            if let Some(key) = regex_example(pattern, &self.random) {
+                let value = self.generate(vec![schema], depth + 1, &conjunction.references)?;
+                object.entry(key).or_insert(value);
+            }
+        }
+        let names = conjunction
+            .first("propertyNames")
+            .and_then(|names| names.get("pattern"))
+            .and_then(Value::as_str);
+        let mut index = 0;
+        while object.len() < min_properties {
+            index += 1;
+            let key = match names.and_then(|names| regex_example(names, &self.random)) {
+                Some(name) if index == 1 => name,
+                Some(name) => format!("{}{}", name, index),
+                None => format!("key{}", index),
+            };
+            let value = self.generate(additional.clone(), depth + 1, &conjunction.references)?;
+            object.entry(key).or_insert(value);
+        }
+        Some(Value::Object(object))
+    }
+
+    fn array(&self, conjunction: &Conjunction<'s>, depth: usize) -> Option<Value> {
+        let items: Vec<&'s Value> = conjunction.all("items").collect();
+        let mut array = vec![];
+        if let Some(Value::Array(prefix)) = conjunction.first("prefixItems") {
+            for schema in prefix {
+                array.push(self.generate(vec![schema], depth + 1, &conjunction.references)?);
+            }
+        }
+        for contains in conjunction.all("contains") {
+            let mut schemas = items.clone();
+            schemas.push(contains);
+            array.push(self.generate(schemas, depth + 1, &conjunction.references)?);
+        }
+        let min_items = conjunction.max("minItems").unwrap_or(0.0) as usize;
+        let max_items = conjunction
+            .min("maxItems")
+            .map_or(usize::MAX, |max| max as usize);
+        let len = min_items.max(array.len()) + self.random.index(4);
+        while array.len() < len.min(max_items).max(min_items) {
+            array.push(self.generate(items.clone(), depth + 1, &conjunction.references)?);
+        }
+        Some(Value::Array(array))
+    }
+}
+
+/// Characters of the random strings.
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+impl Generator<'_> {
+    fn string(&self, conjunction: &Conjunction<'_>) -> Option<Value> {
+        let min_len = conjunction.max("minLength").unwrap_or(0.0) as usize;
+        let max_len = conjunction.min("maxLength").map(|len| len as usize);
+
+        // Examples hint at strings the patterns alone don't lead to, like
+        // encoded documents.
+        let examples: Vec<&Value> = conjunction
+            .all("examples")
+            .filter_map(Value::as_array)
+            .flatten()
+            .filter(|example| {
+                example.as_str().is_some_and(|example| {
+                    let len = example.chars().count();
+                    len >= min_len && max_len.is_none_or(|max| len <= max)
+                })
+            })
+            .collect();
+        if let Some(example) = examples.get(self.random.index(examples.len())) {
+            return Some((*example).clone());
+        }
+
+        let mut patterns: Vec<&str> = conjunction
+            .all("pattern")
+            .filter_map(Value::as_str)
+            .collect();
+        if !patterns.is_empty() {
+            // Concatenate the examples, so that the start and end anchors hold.
+            patterns.sort_by_key(|pattern| (!pattern.starts_with('^'), pattern.ends_with('$')));
+            let mut example = String::new();
+            for pattern in &patterns {
+                example.push_str(&regex_example(pattern, &self.random)?);
+            }
+            let padding = min_len.saturating_sub(example.chars().count());
+            if !patterns.iter().any(|pattern| pattern.ends_with('$')) {
+                example.push_str(&"a".repeat(padding));
+            } else if !patterns.iter().any(|pattern| pattern.starts_with('^')) {
+                example.insert_str(0, &"a".repeat(padding));
+            }
+            return Some(Value::String(example));
+        }
+
+        let format = conjunction.first("format").and_then(Value::as_str);
+        if let Some(example) = format.and_then(|format| self.random_format(format)) {
+            return Some(Value::String(example));
+        }
+        let example = match format {
+            Some("date-time") => "2024-01-01T00:00:00Z",
+            Some("date") => "2024-01-01",
+            Some("time") => "00:00:00Z",
+            Some("email") => "user@example.com",
+            Some("uuid") => "6f2c1a9e-8a7b-4c3d-9e5f-0a1b2c3d4e5f",
+            Some("uri") | Some("url") => "https://example.com/",
+            Some("hostname") => "example.com",
+            Some("ipv4") => "192.0.2.1",
+            Some("ipv6") => "2001:db8::1",
+            _ if self.random.is_seeded() => {
+                let extra = max_len.map_or(8, |max| max.saturating_sub(min_len).min(8));
+                let len = min_len + self.random.index(extra + 1);
+                let example = (0..len)
+                    .map(|_| ALPHANUMERIC[self.random.index(ALPHANUMERIC.len())] as char)
+                    .collect();
+                return Some(Value::String(example));
+            }
+            _ => {
+                let mut example: String = "example"
+                    .chars()
+                    .take(max_len.unwrap_or(usize::MAX))
+                    .collect();
+                let padding = min_len.saturating_sub(example.chars().count());
+                example.push_str(&"x".repeat(padding));
+                return Some(Value::String(example));
+            }
+        };
+        Some(Value::from(example))
+    }
+
+    /// A random string of the format, if seeded and the format is known.
+    fn random_format(&self, format: &str) -> Option<String> {
+        if !self.random.is_seeded() {
+            return None;
+        }
+        let number = |low: u64, high: u64| low + self.random.below(high - low + 1);
+        let date = || {
+            format!(
+                "{:04}-{:02}-{:02}",
+                number(1970, 2099),
+                number(1, 12),
+                number(1, 28)
+            )
+        };
+        let time = || {
+            format!(
+                "{:02}:{:02}:{:02}Z",
+                number(0, 23),
+                number(0, 59),
+                number(0, 59)
+            )
+        };
+        let word = |chars: &[u8]| -> String {
+            (0..number(1, 8))
+                .map(|_| chars[self.random.index(chars.len())] as char)
+                .collect()
+        };
+        let example = match format {
+            "date-time" => format!("{}T{}", date(), time()),
+            "date" => date(),
+            "time" => time(),
+            "email" => format!("{}@{}.com", word(ALPHANUMERIC), word(&ALPHANUMERIC[..26])),
+            "uuid" => {
+                // Version 4, of the RFC 4122 variant.
+                let mut digits = format!(
+                    "{:016x}{:016x}",
+                    self.random.below(u64::MAX),
+                    self.random.below(u64::MAX)
+                );
+                digits.replace_range(12..13, "4");
+                let variant = ["8", "9", "a", "b"][self.random.index(4)];
+                digits.replace_range(16..17, variant);
+                format!(
+                    "{}-{}-{}-{}-{}",
+                    &digits[..8],
+                    &digits[8..12],
+                    &digits[12..16],
+                    &digits[16..20],
+                    &digits[20..]
+                )
+            }
+            "ipv4" => (0..4)
+                .map(|_| self.random.below(256).to_string())
+                .collect::<Vec<_>>()
+                .join("."),
+            "ipv6" => (0..8)
+                .map(|_| format!("{:x}", self.random.below(0x10000)))
+                .collect::<Vec<_>>()
+                .join(":"),
+            _ => return None,
+        };
+        Some(example)
+    }
+
+    fn number(&self, conjunction: &Conjunction<'_>, integer: bool) -> Option<Value> {
+        let minimum = conjunction.max("minimum");
+        let exclusive_minimum = conjunction.max("exclusiveMinimum");
+        let maximum = conjunction.min("maximum");
+        let exclusive_maximum = conjunction.min("exclusiveMaximum");
+        let multiple_of = conjunction.first("multipleOf").and_then(Value::as_f64);
+
+        let step = if integer {
+            1.0
+        } else {
+            multiple_of.unwrap_or(1.0)
+        };
+        let lower = match (minimum, exclusive_minimum) {
+            (Some(min), Some(exclusive)) if exclusive >= min => Some((exclusive, true)),
+            (Some(min), _) => Some((min, false)),
+            (None, Some(exclusive)) => Some((exclusive, true)),
+            (None, None) => None,
+        };
+        let upper = match (maximum, exclusive_maximum) {
+            (Some(max), Some(exclusive)) if exclusive <= max => Some((exclusive, true)),
+            (Some(max), _) => Some((max, false)),
+            (None, Some(exclusive)) => Some((exclusive, true)),
+            (None, None) => None,
+        };
+
+        let mut value = match (lower, upper) {
+            (Some((low, false)), _) => low,
+            (Some((low, true)), Some((high, _))) if !integer && multiple_of.is_none() => {
+                (low + high) / 2.0
+            }
+            (Some((low, true)), _) => low + step.min(1.0),
+            (None, Some((high, exclusive))) if high < 0.0 || (exclusive && high == 0.0) => {
+                high - if exclusive { step.min(1.0) } else { 0.0 }
+            }
+            (None, _) => 0.0,
+        };
+        // Move away from the bound at random, within range.
+        let span = match (lower, upper) {
+            (_, Some((high, exclusive))) => {
+                high - value - if exclusive { step.min(1.0) } else { 0.0 }
+            }
+            (Some(_), None) => 1000.0,
+            (None, None) => 2000.0,
+        };
+        if span > 0.0 && self.random.is_seeded() {
+            let span = span.min(1e6);
+            let offset = if integer {
+                self.random.below(span as u64 + 1) as f64
+            } else {
+                self.random.below((span * 100.0) as u64 + 1) as f64 / 100.0
+            };
+            value += offset;
+            if lower.is_none() && upper.is_none() {
+                value -= 1000.0;
+            }
+        }
+        if integer {
+            value = value.ceil();
+        }
+        if let Some(divisor) = multiple_of.filter(|divisor| *divisor > 0.0) {
+            value = (value / divisor).ceil() * divisor;
+            if matches!(lower, Some((low, true)) if value <= low) {
+                value += divisor;
+            }
+        }
+
+        if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 {
+            Some(Value::from(value as i64))
+        } else {
+            Number::from_f64(value).map(Value::Number)
+        }
+    }
+}
+
+/// Generation of the shortest strings matching a regular expression, for the
+/// common subset of the syntax.
+struct RegexParser<'r> {
+    chars: Vec<char>,
+    pos: usize,
+    random: &'r Random,
+}
+
+impl RegexParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Alternatives, of which one is used.
+    fn alternation(&mut self) -> Option<String> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.eat('|') {
+            alternatives.push(self.sequence()?);
+        }
+        let index = self.random.index(alternatives.len());
+        Some(alternatives.swap_remove(index))
+    }
+
+    fn sequence(&mut self) -> Option<String> {
+        let mut example = String::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            let count = self.quantifier()?;
+            example.push_str(&atom.repeat(count));
+        }
+        Some(example)
+    }
+
+    fn atom(&mut self) -> Option<String> {
+        let c = self.peek()?;
+        self.pos += 1;
+        match c {
+            '^' | '$' => Some(String::new()),
+            '.' => {
+                let c = ALPHANUMERIC[self.random.index(ALPHANUMERIC.len())];
+                Some(String::from(c as char))
+            }
+            '\\' => self.escape(),
+            '[' => self.class().map(String::from),
+            '(' => self.group(),
+            '*' | '+' | '?' => None,
+            c => Some(String::from(c)),
+        }
+    }
+
+    fn escape(&mut self) -> Option<String> {
+        let c = self.peek()?;
+        self.pos += 1;
+        let example = match c {
+            'd' => "0",
+            'D' | 'w' | 'S' => "a",
+            'W' | 's' => " ",
+            'b' | 'B' | 'A' | 'z' => "",
+            'n' => "\n",
+            't' => "\t",
+            'r' => "\r",
+            'p' | 'P' => {
+                if self.eat('{') {
+                    while !self.eat('}') {
+                        self.peek()?;
+                        self.pos += 1;
+                    }
+                } else {
+                    self.pos += 1;
+                }
+                if c == 'p' {
+                    "a"
+                } else {
+                    "0"
+                }
+            }
+            // Backreferences can't be followed.
+            '1'..='9' => return None,
+            c => return Some(String::from(c)),
+        };
+        Some(String::from(example))
+    }
+
+    /// A character class, like `[a-z_]` or `[^0-9]`.
+    fn class(&mut self) -> Option<char> {
+        let negated = self.eat('^');
+        let mut ranges: Vec<(char, char)> = vec![];
+        let mut first = true;
+        loop {
+            let c = self.peek()?;
+            self.pos += 1;
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+            let (low, high) = match c {
+                '\\' => {
+                    let escaped = self.peek()?;
+                    self.pos += 1;
+                    match escaped {
+                        'd' => ('0', '9'),
+                        'w' => ('a', 'z'),
+                        's' => (' ', ' '),
+                        'n' => ('\n', '\n'),
+                        't' => ('\t', '\t'),
+                        c => (c, c),
+                    }
+                }
+                c if self.peek() == Some('-') && self.chars.get(self.pos + 1) != Some(&']') => {
+                    self.pos += 1;
+                    let high = match self.peek()? {
+                        '\\' => {
+                            self.pos += 1;
+                            self.peek()?
+                        }
+                        high => high,
+                    };
+                    self.pos += 1;
+                    (c, high)
+                }
+                c => (c, c),
+            };
+            ranges.push((low, high));
+        }
+
+        let contains = |c: char| ranges.iter().any(|(low, high)| (*low..=*high).contains(&c));
+        if negated {
+            "a0_-xX ".chars().find(|c| !contains(*c))
+        } else {
+            let (low, high) = ranges.get(self.random.index(ranges.len()))?;
+            let span = (*high as u32).saturating_sub(*low as u32);
+            char::from_u32(*low as u32 + self.random.below(u64::from(span) + 1) as u32)
+                .or(Some(*low))
+        }
+    }
+
+    fn group(&mut self) -> Option<String> {
+        let mut discard = false;
+        if self.eat('?') {
+            match self.peek()? {
+                ':' => self.pos += 1,
+                '=' | '!' => {
+                    self.pos += 1;
+                    discard = true;
+                }
+                '<' if matches!(self.chars.get(self.pos + 1), Some('=' | '!')) => {
+                    self.pos += 2;
+                    discard = true;
+                }
+                '<' | 'P' => {
+                    while !self.eat('>') {
+                        self.peek()?;
+                        self.pos += 1;
+                    }
+                }
+                _ => {
+                    // Flags, like `(?i)` or `(?i:...)`.
+                    while let Some(c) = self.peek() {
+                        if !(c.is_ascii_alphabetic() || c == '-') {
+                            break;
+                        }
+                        self.pos += 1;
+                    }
+                    if self.eat(')') {
+                        return Some(String::new());
+                    }
+                    if !self.eat(':') {
+                        return None;
+                    }
+                }
+            }
+        }
+        let example = self.alternation()?;
+        if !self.eat(')') {
+            return None;
+        }
+        Some(if discard { String::new() } else { example })
+    }
+
+    /// The minimum number of repetitions of the preceding atom.
+    fn quantifier(&mut self) -> Option<usize> {
+        let (min, max) = match self.peek() {
+            Some('*') => {
+                self.pos += 1;
+                (0, None)
+            }
+            Some('?') => {
+                self.pos += 1;
+                (0, Some(1))
+            }
+            Some('+') => {
+                self.pos += 1;
+                (1, None)
+            }
+            Some('{') => {
+                let start = self.pos;
+                self.pos += 1;
+                let digits = self.digits();
+                let (closed, max) = match self.peek() {
+                    Some('}') => (true, digits.parse().ok()),
+                    Some(',') => {
+                        self.pos += 1;
+                        let max = self.digits();
+                        (self.peek() == Some('}'), max.parse().ok())
+                    }
+                    _ => (false, None),
+                };
+                match digits.parse() {
+                    Ok(min) if closed => {
+                        self.pos += 1;
+                        (min, max)
+                    }
+                    // Not a quantifier: a literal brace.
+                    _ => {
+                        self.pos = start;
+                        return Some(1);
+                    }
+                }
+            }
+            _ => return Some(1),
+        };
+        // Lazy and possessive quantifiers have the same bounds.
+        if !self.eat('?') {
+            self.eat('+');
+        }
+        let extra = max.map_or(3, |max: usize| max.saturating_sub(min).min(3));
+        Some(min + self.random.index(extra + 1))
+    }
+
+    fn digits(&mut self) -> String {
+        let mut digits = String::new();
+        while let Some(c) = self.peek().filter(char::is_ascii_digit) {
+            digits.push(c);
+            self.pos += 1;
+        }
+        digits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{example, Random};
+    use serde_json::json;
+
+    fn shortest(pattern: &str) -> Option<String> {
+        super::regex_example(pattern, &Random::new(None))
+    }
+
+    #[test]
+    fn regex() {
+        assert_eq!(Some(String::from("abc")), shortest("^abc$"));
+        assert_eq!(Some(String::from("a0")), shortest(r"^[a-z]+\d{1,3}$"));
+        assert_eq!(Some(String::from("SKU-000")), shortest(r"^SKU-[0-9]{3}$"));
+        assert_eq!(Some(String::from("cat")), shortest("^(cat|dog)s?$"));
+        assert_eq!(Some(String::from("a.b")), shortest(r"a\.b"));
+        assert_eq!(Some(String::from("x")), shortest("(?i)x(?=y)"));
+        assert_eq!(Some(String::from("a")), shortest("[^0-9]"));
+        assert_eq!(Some(String::from("a{")), shortest("a{"));
+        assert_eq!(None, shortest(r"(a)\1"));
+        assert_eq!(None, shortest("(a"));
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(Some(json!(null)), example(&json!({})));
+        assert_eq!(Some(json!(true)), example(&json!({"type": "boolean"})));
+        assert_eq!(Some(json!("a")), example(&json!({"const": "a"})));
+        assert_eq!(
+            Some(json!(2)),
+            example(&json!({"enum": [1, 2], "allOf": [{"enum": [2, 3]}]}))
+        );
+        assert_eq!(
+            None,
+            example(&json!({"enum": [1], "allOf": [{"enum": [2]}]}))
+        );
+        assert_eq!(None, example(&json!(false)));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Some(json!(0)), example(&json!({"type": "number"})));
+        assert_eq!(
+            Some(json!(18)),
+            example(&json!({"type": "number", "minimum": 18}))
+        );
+        assert_eq!(
+            Some(json!(6)),
+            example(&json!({"type": "integer", "exclusiveMinimum": 5}))
+        );
+        assert_eq!(
+            Some(json!(2.5)),
+            example(&json!({"type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 5}))
+        );
+        assert_eq!(
+            Some(json!(-3)),
+            example(&json!({"type": "integer", "maximum": -3}))
+        );
+        assert_eq!(
+            Some(json!(12)),
+            example(&json!({"minimum": 10, "multipleOf": 4}))
+        );
+        assert_eq!(
+            Some(json!(1.5)),
+            example(&json!({"minimum": 1.2, "multipleOf": 0.5}))
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(Some(json!("example")), example(&json!({"type": "string"})));
+        assert_eq!(
+            Some(json!("exa")),
+            example(&json!({"type": "string", "maxLength": 3}))
+        );
+        assert_eq!(
+            Some(json!("examplexxx")),
+            example(&json!({"type": "string", "minLength": 10}))
+        );
+        assert_eq!(
+            Some(json!("user@example.com")),
+            example(&json!({"type": "string", "format": "email"}))
+        );
+        assert_eq!(
+            Some(json!("#tagaa")),
+            example(&json!({"allOf": [{"pattern": "tag"}, {"pattern": "^#"}], "minLength": 6}))
+        );
+        assert_eq!(
+            Some(json!("ab")),
+            example(&json!({"allOf": [{"pattern": "b$"}, {"pattern": "^a"}]}))
+        );
+        assert_eq!(
+            Some(json!("e30")),
+            example(&json!({"pattern": "^[a-z]+$", "examples": ["e30"]}))
+        );
+        assert_eq!(
+            Some(json!("name")),
+            example(&json!({"type": "string", "examples": ["n", 1, "name"], "minLength": 2}))
+        );
+    }
+
+    #[test]
+    fn arrays() {
+        assert_eq!(
+            Some(json!([1, "example", null])),
+            example(&json!({
+                "type": "array",
+                "prefixItems": [{"const": 1}, {"type": "string"}],
+                "minItems": 3,
+            }))
+        );
+        assert_eq!(
+            Some(json!([5, 0])),
+            example(&json!({
+                "type": "array",
+                "items": {"type": "integer"},
+                "contains": {"minimum": 5},
+                "minItems": 2,
+            }))
+        );
+    }
+
+    #[test]
+    fn objects() {
+        assert_eq!(
+            Some(json!({"id": 1, "name": "example", "role": null})),
+            example(&json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer", "minimum": 1},
+                    "name": {"type": "string"},
+                },
+                "required": ["id", "name", "role"],
+            }))
+        );
+        assert_eq!(
+            Some(json!({"user_": 0})),
+            example(&json!({
+                "type": "object",
+                "patternProperties": {"^user_.*$": {"type": "number"}},
+            }))
+        );
+        assert_eq!(
+            Some(json!({"key1": true, "key2": true})),
+            example(&json!({"additionalProperties": {"type": "boolean"}, "minProperties": 2}))
+        );
+    }
+
+    #[test]
+    fn alternatives() {
+        assert_eq!(
+            Some(json!(18)),
+            example(&json!({"anyOf": [{"type": "null"}, {"type": "number", "minimum": 18}]}))
+        );
+        assert_eq!(
+            Some(json!("example")),
+            example(&json!({"oneOf": [false, {"type": "string"}]}))
+        );
+        assert_eq!(
+            Some(json!({"kind": "a", "a": 0})),
+            example(&json!({
+                "if": {"properties": {"kind": {"const": "a"}}, "required": ["kind"]},
+                "then": {"properties": {"a": {"type": "number"}}, "required": ["a"]},
+            }))
+        );
+    }
+
+    #[test]
+    fn references() {
+        let schema = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {"children": {"type": "array", "items": {"$ref": "#/$defs/node"}}},
+                    "required": ["children"],
+                },
+            },
+        });
+
+        assert_eq!(Some(json!({"children": []})), example(&schema));
+    }
+
+    #[test]
+    fn recursive_references() {
+        let schema = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {"node": {"properties": {"next": {"$ref": "#/$defs/node"}}}},
+        });
+        assert_eq!(Some(json!({"next": {}})), example(&schema));
+
+        let schema = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {"node": {"required": ["next"], "properties": {"next": {"$ref": "#/$defs/node"}}}},
+        });
+        assert_eq!(None, example(&schema));
+    }
+
+    #[test]
+    fn validators() {
+        use crate::{validators, Validator};
+        use std::collections::HashMap;
+
+        let validator = validators::object(HashMap::from([
+            (
+                String::from("id"),
+                Box::new(validators::u64(|_| Ok(()))) as Box<dyn Validator>,
+            ),
+            (
+                String::from("age"),
+                Box::new(validators::null_or(validators::between(18, 120))),
+            ),
+            (
+                String::from("sku"),
+                Box::new(validators::starts_with("SKU-")),
+            ),
+            (
+                String::from("tags"),
+                Box::new(
+                    validators::array_for_each(validators::is_string())
+                        .and(validators::array_size(1)),
+                ),
+            ),
+        ]));
+        assert_eq!(
+            Some(json!({"id": 0, "age": 18, "sku": "SKU-", "tags": ["example"]})),
+            validator.generate()
+        );
+
+        assert_eq!(
+            None,
+            validators::custom(|_| Err(String::from("never"))).generate()
+        );
+        assert_eq!(None, validators::ge(5).and(validators::lt(5)).generate());
+    }
+
+    #[test]
+    fn random() {
+        let unseeded = Random::new(None);
+        assert_eq!(vec![0, 0, 0], vec![unseeded.below(10); 3]);
+
+        let first: Vec<u64> = (0..8).map(|_| Random::new(Some(7)).below(100)).collect();
+        assert!(first.iter().all(|n| *n == first[0]));
+        let random = Random::new(Some(7));
+        let values: Vec<u64> = (0..8).map(|_| random.below(100)).collect();
+        assert!(values.iter().all(|n| *n < 100));
+        assert!(values.iter().any(|n| *n != values[0]));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn random_regex() {
+        let pattern = r"^(cat|dog)s?-[a-f]{2,4}\d*$";
+        let regex = regex::Regex::new(pattern).unwrap();
+        for seed in 0..32 {
+            let example = super::regex_example(pattern, &Random::new(Some(seed))).unwrap();
+            assert!(regex.is_match(&example), "{}", example);
+        }
+    }
+
+    #[test]
+    fn random_examples() {
+        use super::random_example;
+
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "minimum": 1, "maximum": 100},
+                "kind": {"enum": ["a", "b", "c"]},
+                "name": {"type": "string", "minLength": 2, "maxLength": 5},
+                "tags": {"type": "array", "items": {"type": "boolean"}, "maxItems": 3},
+            },
+            "required": ["id", "kind"],
+        });
+        let values: Vec<_> = (0..32)
+            .map(|seed| random_example(&schema, seed).unwrap())
+            .collect();
+
+        for value in &values {
+            let id = value["id"].as_i64().unwrap();
+            assert!((1..=100).contains(&id), "{}", value);
+            assert!(["a", "b", "c"].contains(&value["kind"].as_str().unwrap()));
+            if let Some(name) = value.get("name") {
+                assert!((2..=5).contains(&name.as_str().unwrap().len()), "{}", value);
+            }
+            if let Some(tags) = value.get("tags") {
+                assert!(tags.as_array().unwrap().len() <= 3, "{}", value);
+            }
+        }
+        assert!(values.iter().any(|value| value != &values[0]));
+        assert!(values.iter().any(|value| value.get("name").is_none()));
+        assert_eq!(random_example(&schema, 3), random_example(&schema, 3));
+    }
+
+    #[test]
+    fn random_formats() {
+        use crate::{check, validators, Validator};
+
+        let formats: Vec<(&str, Box<dyn Validator>)> = vec![
+            ("uuid", Box::new(validators::is_uuid())),
+            ("email", Box::new(validators::is_email())),
+            ("date-time", Box::new(validators::is_datetime())),
+            ("ipv4", Box::new(validators::is_ipv4())),
+            ("ipv6", Box::new(validators::is_ipv6())),
+        ];
+        for (format, validator) in formats {
+            let values: Vec<_> = (0..16)
+                .map(|seed| validator.generate_seeded(seed).unwrap())
+                .collect();
+            for value in &values {
+                assert!(check(value, &validator).is_ok(), "{}", value);
+            }
+            assert!(values.iter().any(|value| value != &values[0]), "{}", format);
+        }
+    }
+
+    #[test]
+    fn seeded_validators() {
+        use crate::{check, validators, Validator};
+
+        let validator =
+            validators::array_for_each(validators::between(18, 120)).and(validators::array_size(2));
+        for seed in 0..32 {
+            let value = validator.generate_seeded(seed).unwrap();
+            assert!(check(&value, &validator).is_ok(), "{}", value);
+        }
+        assert_eq!(
+            None,
+            validators::ge(5).and(validators::lt(5)).generate_seeded(1)
+        );
+        assert_eq!(
+            validator.generate_from_bytes(b"input"),
+            validator.generate_from_bytes(b"input")
+        );
+    }
+
+    #[cfg(feature = "proptest")]
+    mod strategy {
+        use crate::{check, validators, Validator};
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn into_strategy(value in validators::object_contains(vec![
+                ("id", Box::new(validators::gt(0)) as Box<dyn Validator>),
+                ("email", Box::new(validators::is_email())),
+            ]).into_strategy()) {
+                prop_assert!(value["id"].as_f64().unwrap() > 0.0);
+                prop_assert!(check(&value["email"], &validators::is_email()).is_ok());
+            }
+        }
+    }
+}
@@ -49,30 +49,429 @@ fn get_value_type_id(val: &Value) -> String {
     }
 }
 
+fn join_keys(keys: &[String]) -> String {
+    keys.iter()
+        .map(|key| format!("'{}'", key))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn invalid_value(actual: &Value, expected: &str) -> String {
+    let (expected, actual) = truncate::excerpts(expected, &actual.to_string());
+    format!("Invalid value. Expected {} but got {}.", expected, actual)
+}
+
+fn join_errors(errors: &[Error]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
 /// Validation error
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error<'a> {
     #[error("Invalid type. Expected {} but got {}.", .1, get_value_type_id(.0))]
     InvalidType(&'a Value, String),
-    #[error("Invalid value. Expected {1} but got {0}.")]
+    #[error("{}", invalid_value(.0, .1))]
     InvalidValue(&'a Value, String),
-    #[error("Missing key '{1}' in object")]
+    #[error("Missing key '{}' in object{}", .1, suggest::did_you_mean(.0, std::slice::from_ref(.1)))]
     MissingObjectKey(&'a Value, String),
     #[error("Key '{1}' is not expected in object")]
     UnexpectedObjectKey(&'a Value, String),
+    #[error("Missing keys {} in object{}", join_keys(.1), suggest::did_you_mean(.0, .1))]
+    MissingObjectKeys(&'a Value, Vec<String>),
+    #[error("Keys {} are not expected in object", join_keys(.1))]
+    UnexpectedObjectKeys(&'a Value, Vec<String>),
+    #[error("None of the alternatives matched: {}", join_errors(.1))]
+    NoMatchingAlternative(&'a Value, Vec<Error<'a>>),
+    #[error("Missing value at JSON pointer '{1}'")]
+    MissingPointer(&'a Value, String),
+    #[error("Unexpected match. Expected value to not match but {} matched.", truncate::display_value(.0))]
+    UnexpectedMatch(&'a Value),
+    #[error("{0}: {1}")]
+    Labeled(String, Box<Error<'a>>),
+    #[error("Invalid array element at index {0}. {1}")]
+    InvalidArrayElement(usize, Box<Error<'a>>),
+    #[error("Invalid object key '{1}'. {2}")]
+    InvalidObjectKey(&'a Value, String, String),
+    #[error("Invalid object value at key '{0}'. {1}")]
+    InvalidObjectValue(String, Box<Error<'a>>),
+    #[error("No array element matched: {}", if .1.is_empty() { String::from("array is empty") } else { join_errors(.1) })]
+    NoMatchingElement(&'a Value, Vec<Error<'a>>),
+    #[error("{}", invalid_value(.0, &.1.to_string()))]
+    ValueMismatch(&'a Value, Value),
+    #[error("{}", invalid_value(.0, .1))]
+    InvalidLength(&'a Value, String),
+}
+
+/// Category of a validation [Error], for programmatic handling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The value has another JSON type than expected.
+    TypeMismatch,
+    /// The value is not the expected one.
+    ValueMismatch,
+    /// The length of a string, an array or an object is not the expected one.
+    LengthMismatch,
+    /// Expected object keys are missing.
+    MissingKey,
+    /// Object keys are not expected.
+    ExtraKey,
+    /// An object key does not match its validator.
+    InvalidKey,
+    /// There is no value at a JSON pointer.
+    MissingPointer,
+    /// None of the alternatives or of the array elements matched.
+    NoMatch,
+    /// The value matched while it was expected not to.
+    UnexpectedMatch,
+    /// The input text is not valid JSON, see [check_str].
+    InvalidJson,
 }
 
 impl<'a> Error<'a> {
+    /// The JSON pointer of the failing node within `root`, like `/items/3/name`.
+    ///
+    /// `root` must be the value which was validated. An empty string denotes
+    /// `root` itself; `None` is returned if the node can't be found in `root`.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let value = serde_json::json!({"items": [{"name": "a"}, {"name": 5}]});
+    /// let validator = validators::at("/items", validators::array_for_each(validators::object_contains([
+    ///     ("name", Box::new(validators::is_string()) as Box<dyn Validator>),
+    /// ])));
+    ///
+    /// let error = validator.validate(&value).unwrap_err();
+    /// assert_eq!(Some(String::from("/items/1/name")), error.pointer(&value));
+    /// ```
+    pub fn pointer(&self, root: &Value) -> Option<String> {
+        validators::pointer_of(root, self.location())
+    }
+
+    /// The line and column, both starting at 1, of the failing node in the
+    /// JSON text `source` which `root` was parsed from.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator, Value};
+    ///
+    /// let source = "{\n  \"id\": \"5\"\n}";
+    /// let value: Value = serde_json::from_str(source).unwrap();
+    ///
+    /// let error = validators::at("/id", validators::is_number())
+    ///     .validate(&value)
+    ///     .unwrap_err();
+    /// assert_eq!(Some((2, 9)), error.line_column(&value, source));
+    /// ```
+    pub fn line_column(&self, root: &Value, source: &str) -> Option<(usize, usize)> {
+        source::line_column(source, &self.pointer(root)?)
+    }
+
     fn location(&self) -> &'a Value {
         match self {
             Error::InvalidType(loc, _) => loc,
             Error::InvalidValue(loc, _) => loc,
             Error::MissingObjectKey(loc, _) => loc,
             Error::UnexpectedObjectKey(loc, _) => loc,
+            Error::MissingObjectKeys(loc, _) => loc,
+            Error::UnexpectedObjectKeys(loc, _) => loc,
+            Error::NoMatchingAlternative(loc, _) => loc,
+            Error::MissingPointer(loc, _) => loc,
+            Error::UnexpectedMatch(loc) => loc,
+            Error::Labeled(_, err) => err.location(),
+            Error::InvalidArrayElement(_, err) => err.location(),
+            Error::InvalidObjectKey(loc, _, _) => loc,
+            Error::InvalidObjectValue(_, err) => err.location(),
+            Error::NoMatchingElement(loc, _) => loc,
+            Error::ValueMismatch(loc, _) => loc,
+            Error::InvalidLength(loc, _) => loc,
+        }
+    }
+
+    /// The category of the error. Labels and array or object wrappers report
+    /// the kind of the underlying error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidType(_, _) => ErrorKind::TypeMismatch,
+            Error::InvalidValue(_, _) | Error::ValueMismatch(_, _) => ErrorKind::ValueMismatch,
+            Error::InvalidLength(_, _) => ErrorKind::LengthMismatch,
+            Error::MissingObjectKey(_, _) | Error::MissingObjectKeys(_, _) => ErrorKind::MissingKey,
+            Error::UnexpectedObjectKey(_, _) | Error::UnexpectedObjectKeys(_, _) => {
+                ErrorKind::ExtraKey
+            }
+            Error::InvalidObjectKey(_, _, _) => ErrorKind::InvalidKey,
+            Error::MissingPointer(_, _) => ErrorKind::MissingPointer,
+            Error::NoMatchingAlternative(_, _) | Error::NoMatchingElement(_, _) => {
+                ErrorKind::NoMatch
+            }
+            Error::UnexpectedMatch(_) => ErrorKind::UnexpectedMatch,
+            Error::Labeled(_, err)
+            | Error::InvalidArrayElement(_, err)
+            | Error::InvalidObjectValue(_, err) => err.kind(),
+        }
+    }
+
+    /// A unified diff between the expected and the actual value, if the error
+    /// compares them structurally.
+    pub fn diff(&self) -> Option<String> {
+        match self {
+            Error::ValueMismatch(actual, expected) => Some(diff::unified_diff(expected, actual)),
+            Error::Labeled(_, err) => err.diff(),
+            Error::InvalidArrayElement(_, err) => err.diff(),
+            Error::InvalidObjectValue(_, err) => err.diff(),
+            _ => None,
+        }
+    }
+
+    /// A [JSON Patch](https://www.rfc-editor.org/rfc/rfc6902) transforming
+    /// `root` into the expected value, if the error compares values
+    /// structurally.
+    ///
+    /// `root` must be the value which was validated.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let value = serde_json::json!({"user": {"id": 1, "name": "n"}});
+    /// let error = validators::at("/user", validators::eq(serde_json::json!({"id": 2})))
+    ///     .validate(&value)
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     Some(serde_json::json!([
+    ///         {"op": "remove", "path": "/user/name"},
+    ///         {"op": "replace", "path": "/user/id", "value": 2},
+    ///     ])),
+    ///     error.patch(&value)
+    /// );
+    /// ```
+    pub fn patch(&self, root: &Value) -> Option<Value> {
+        match self {
+            Error::ValueMismatch(actual, expected) => Some(Value::from(patch::json_patch(
+                &self.pointer(root)?,
+                actual,
+                expected,
+            ))),
+            Error::Labeled(_, err) => err.patch(root),
+            Error::InvalidArrayElement(_, err) => err.patch(root),
+            Error::InvalidObjectValue(_, err) => err.patch(root),
+            _ => None,
+        }
+    }
+
+    /// A machine-readable report of the error, for CI tooling and dashboards.
+    ///
+    /// The report holds the JSON pointer of the failing node within `root`, the
+    /// kind of error, what was expected, the actual value and the message.
+    /// Errors on array elements and object values are reported on the failing
+    /// node itself with the full message, and alternatives are nested in
+    /// `errors`.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let value = serde_json::json!({"id": "5"});
+    /// let error = validators::at("/id", validators::is_number())
+    ///     .validate(&value)
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     serde_json::json!({
+    ///         "path": "/id",
+    ///         "kind": "invalid_type",
+    ///         "expected": "number",
+    ///         "actual": "5",
+    ///         "message": "Invalid type. Expected number but got string.",
+    ///     }),
+    ///     error.to_json(&value)
+    /// );
+    /// ```
+    pub fn to_json(&self, root: &Value) -> Value {
+        let (kind, expected) = match self {
+            Error::Labeled(label, err) => {
+                let mut report = err.to_json(root);
+                report["label"] = Value::from(label.as_str());
+                report["message"] = Value::from(self.to_string());
+                return report;
+            }
+            Error::InvalidArrayElement(_, err) | Error::InvalidObjectValue(_, err) => {
+                let mut report = err.to_json(root);
+                report["message"] = Value::from(self.to_string());
+                return report;
+            }
+            Error::InvalidType(_, expected) => ("invalid_type", Value::from(expected.as_str())),
+            Error::InvalidValue(_, expected) => ("invalid_value", Value::from(expected.as_str())),
+            Error::MissingObjectKey(_, key) => ("missing_object_key", Value::from(key.as_str())),
+            Error::UnexpectedObjectKey(_, key) => {
+                ("unexpected_object_key", Value::from(key.as_str()))
+            }
+            Error::MissingObjectKeys(_, keys) => ("missing_object_keys", Value::from(keys.clone())),
+            Error::UnexpectedObjectKeys(_, keys) => {
+                ("unexpected_object_keys", Value::from(keys.clone()))
+            }
+            Error::NoMatchingAlternative(_, _) => ("no_matching_alternative", Value::Null),
+            Error::MissingPointer(_, pointer) => ("missing_pointer", Value::from(pointer.as_str())),
+            Error::UnexpectedMatch(_) => ("unexpected_match", Value::Null),
+            Error::InvalidObjectKey(_, key, _) => ("invalid_object_key", Value::from(key.as_str())),
+            Error::NoMatchingElement(_, _) => ("no_matching_element", Value::Null),
+            Error::ValueMismatch(_, expected) => ("value_mismatch", expected.clone()),
+            Error::InvalidLength(_, expected) => ("invalid_length", Value::from(expected.as_str())),
+        };
+
+        let mut report = serde_json::json!({
+            "path": self.pointer(root),
+            "kind": kind,
+            "expected": expected,
+            "actual": self.location().clone(),
+            "message": self.to_string(),
+        });
+        if let Error::NoMatchingAlternative(_, errors) | Error::NoMatchingElement(_, errors) = self
+        {
+            report["errors"] = errors.iter().map(|err| err.to_json(root)).collect();
+        }
+        report
+    }
+
+    /// Detach the error from the validated value.
+    ///
+    /// `root` is used to record the JSON pointer of the failing node. Use
+    /// [OwnedError::from] when the root is not at hand.
+    pub fn into_owned(self, root: &Value) -> OwnedError {
+        OwnedError {
+            pointer: self.pointer(root),
+            ..OwnedError::from(self)
         }
     }
 }
 
+/// A validation error which owns its data.
+///
+/// Unlike [Error], it can be returned from helper functions or sent across
+/// threads.
+///
+/// ```
+/// use assert_json::{validators, OwnedError, Validator};
+///
+/// fn check_id(body: &str) -> Result<(), OwnedError> {
+///     let value: serde_json::Value = serde_json::from_str(body).unwrap();
+///     validators::at("/id", validators::is_number())
+///         .validate(&value)
+///         .map_err(|err| err.into_owned(&value))
+/// }
+///
+/// let error = check_id(r#"{"id": "5"}"#).unwrap_err();
+/// assert_eq!(Some("/id"), error.pointer());
+/// assert_eq!("at /id: Invalid type. Expected number but got string.", error.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedError {
+    kind: ErrorKind,
+    message: String,
+    pointer: Option<String>,
+    actual: Value,
+}
+
+impl OwnedError {
+    /// The category of the original [Error].
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The message of the original [Error].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The JSON pointer of the failing node, if known.
+    pub fn pointer(&self) -> Option<&str> {
+        self.pointer.as_deref()
+    }
+
+    /// The failing node.
+    pub fn actual(&self) -> &Value {
+        &self.actual
+    }
+}
+
+impl std::fmt::Display for OwnedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.pointer.as_deref() {
+            Some(pointer) if !pointer.is_empty() => write!(f, "at {}: {}", pointer, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for OwnedError {}
+
+impl From<Error<'_>> for OwnedError {
+    fn from(error: Error<'_>) -> Self {
+        OwnedError {
+            kind: error.kind(),
+            message: error.to_string(),
+            pointer: None,
+            actual: error.location().clone(),
+        }
+    }
+}
+
+/// A failed [try_assert_json!] assertion.
+///
+/// It displays as the message [assert_json!] would panic with and holds the
+/// underlying errors.
+///
+/// ```
+/// use assert_json::{try_assert_json, AssertionError};
+///
+/// fn test_id() -> Result<(), AssertionError> {
+///     try_assert_json!(r#"{"id": 5}"#, { "id": 6 })?;
+///     Ok(())
+/// }
+///
+/// let error = test_id().unwrap_err();
+/// assert_eq!(Some("/id"), error.errors()[0].pointer());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssertionError {
+    message: String,
+    errors: Vec<OwnedError>,
+}
+
+impl AssertionError {
+    pub(crate) fn new(root: &Value, source: Option<&str>, errors: Vec<Error>) -> AssertionError {
+        let owned_errors = errors
+            .iter()
+            .map(|error| OwnedError {
+                kind: error.kind(),
+                message: error.to_string(),
+                pointer: error.pointer(root),
+                actual: error.location().clone(),
+            })
+            .collect();
+        AssertionError {
+            message: macros_utils::format_source_errors(root, source, errors),
+            errors: owned_errors,
+        }
+    }
+
+    /// The errors which made the assertion fail.
+    pub fn errors(&self) -> &[OwnedError] {
+        &self.errors
+    }
+}
+
+impl std::fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AssertionError {}
+
 /// Abstract the validation action for [assert_json!] macro.
 ///
 /// Any custom validation rule can be easily use in the macro
@@ -124,6 +523,157 @@ impl<'a> Error<'a> {
 pub trait Validator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>>;
 
+    /// Validate the value and report every mismatch instead of only the first one.
+    ///
+    /// The default implementation reports the error of [Validator::validate].
+    /// Array and object validators override it to keep validating the remaining
+    /// elements after a failure.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let validator = validators::array_for_each(validators::is_string());
+    /// let value = serde_json::json!(["a", 1, 2]);
+    /// assert_eq!(2, validator.validate_all(&value).len());
+    /// ```
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        self.validate(value).err().into_iter().collect()
+    }
+
+    /// A human-readable description of the expected value, like
+    /// `a string matching ^ord-\d+$`.
+    ///
+    /// Combinators use it to describe their inner validators. The default
+    /// implementation gives a generic description.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let validator = validators::array_for_each(validators::ge(18));
+    /// assert_eq!(
+    ///     "an array whose elements are each a number >= 18",
+    ///     validator.describe()
+    /// );
+    /// ```
+    fn describe(&self) -> String {
+        String::from("a value matching a custom validator")
+    }
+
+    /// A JSON Schema accepting the values the validator matches, to derive
+    /// API contracts from test expectations.
+    ///
+    /// Constraints JSON Schema can't express, like custom predicates, are
+    /// left out: the schema may accept more values than the validator. The
+    /// default implementation gives `{}`, which accepts any value.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let validator = validators::array_for_each(validators::ge(18));
+    /// assert_eq!(
+    ///     serde_json::json!({"type": "array", "items": {"type": "number", "minimum": 18}}),
+    ///     validator.to_json_schema()
+    /// );
+    /// ```
+    fn to_json_schema(&self) -> Value {
+        Value::Object(serde_json::Map::new())
+    }
+
+    /// Generate an example value matching the validator, like a payload for
+    /// a mock server or an example for documentation.
+    ///
+    /// The value is built from [to_json_schema](Validator::to_json_schema),
+    /// picking representative values for types, ranges, patterns, arrays and
+    /// objects, and checked against the validator. `None` means no matching
+    /// value was found, like for custom predicates.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let validator = validators::array_for_each(validators::ge(18)).and(validators::array_size(2));
+    /// assert_eq!(Some(serde_json::json!([18, 18])), validator.generate());
+    /// ```
+    fn generate(&self) -> Option<Value> {
+        let value = generate::example(&self.to_json_schema())?;
+        check(&value, self).is_ok().then_some(value)
+    }
+
+    /// Generate a random value matching the validator, like
+    /// [generate](Validator::generate) with alternatives, values, lengths and
+    /// optional properties picked at random: the same seed gives the same
+    /// value.
+    ///
+    /// Property-based tests can derive documents from random seeds, like
+    /// [into_strategy](Validator::into_strategy) does for `proptest`.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let validator = validators::array_for_each(validators::ge(18));
+    /// for seed in 0..16 {
+    ///     let value = validator.generate_seeded(seed).unwrap();
+    ///     assert!(assert_json::check(&value, &validator).is_ok());
+    /// }
+    /// ```
+    fn generate_seeded(&self, seed: u64) -> Option<Value> {
+        let schema = self.to_json_schema();
+        // Random choices may break constraints the generation doesn't follow:
+        // try a few seeds derived from the requested one.
+        (0..16u64).find_map(|attempt| {
+            let value = generate::random_example(&schema, seed.wrapping_add(attempt << 32))?;
+            check(&value, self).is_ok().then_some(value)
+        })
+    }
+
+    /// Generate a random value matching the validator from arbitrary bytes,
+    /// like the inputs of a fuzzer: the bytes are hashed into the seed of
+    /// [generate_seeded](Validator::generate_seeded).
+    ///
+    /// Fuzz targets, like those of `cargo fuzz`, then feed consumers with
+    /// documents shaped by the contract instead of mostly invalid JSON.
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    ///
+    /// let validator = validators::object_contains(vec![
+    ///     ("id", Box::new(validators::gt(0)) as Box<dyn Validator>),
+    /// ]);
+    /// let value = validator.generate_from_bytes(b"fuzzer input").unwrap();
+    /// assert!(value["id"].as_f64().unwrap() > 0.0);
+    /// ```
+    fn generate_from_bytes(&self, data: &[u8]) -> Option<Value> {
+        // FNV-1a.
+        let seed = data.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.generate_seeded(seed)
+    }
+
+    /// Convert into a `proptest` strategy generating values matching the
+    /// validator, from random seeds given to
+    /// [generate_seeded](Validator::generate_seeded).
+    ///
+    /// ```
+    /// use assert_json::{validators, Validator};
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(age in validators::between(18, 120).into_strategy())| {
+    ///     prop_assert!((18.0..=120.0).contains(&age.as_f64().unwrap()));
+    /// });
+    /// ```
+    #[cfg(feature = "proptest")]
+    fn into_strategy(self) -> impl proptest::strategy::Strategy<Value = Value>
+    where
+        Self: Sized + 'static,
+    {
+        use proptest::strategy::Strategy;
+
+        proptest::arbitrary::any::<u64>()
+            .prop_filter_map("no value matching the validator", move |seed| {
+                self.generate_seeded(seed)
+            })
+    }
+
     fn and<T>(self, validator: T) -> And<Self, T>
     where
         Self: Sized,
@@ -136,6 +686,186 @@ pub trait Validator {
     }
 }
 
+impl<T> Validator for Box<T>
+where
+    T: Validator + ?Sized,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        (**self).validate(value)
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        (**self).validate_all(value)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn to_json_schema(&self) -> Value {
+        (**self).to_json_schema()
+    }
+}
+
+/// Match `null` for `None` and a value equals to `v` for `Some(v)`.
+impl<T> From<Option<T>> for Box<dyn Validator>
+where
+    T: Into<Value> + Clone + 'static,
+{
+    fn from(expected: Option<T>) -> Self {
+        match expected {
+            Some(expected) => Box::new(validators::eq(expected)),
+            None => Box::new(validators::eq(Value::Null)),
+        }
+    }
+}
+
+/// Match an array whose elements match each of the converted validators, in
+/// order.
+impl<T> From<Vec<T>> for Box<dyn Validator>
+where
+    T: Into<Box<dyn Validator>>,
+{
+    fn from(expected: Vec<T>) -> Self {
+        Box::new(validators::array(
+            expected.into_iter().map(Into::into).collect(),
+        ))
+    }
+}
+
+/// Match an object which contains each of the keys, with values matching the
+/// converted validators. Extra keys are allowed, as with
+/// [validators::object].
+impl<T> From<std::collections::HashMap<String, T>> for Box<dyn Validator>
+where
+    T: Into<Box<dyn Validator>>,
+{
+    fn from(expected: std::collections::HashMap<String, T>) -> Self {
+        Box::new(validators::object(
+            expected
+                .into_iter()
+                .map(|(key, validator)| (key, validator.into()))
+                .collect(),
+        ))
+    }
+}
+
+/// Validate any [serde::Serialize] value against a validator.
+///
+/// The value is serialized to a [Value] first. As the serialized value only
+/// lives within this function, the error is returned as its message.
+///
+/// ```
+/// use assert_json::{validate_serialize, validators};
+///
+/// let tags = vec!["a", "b"];
+/// assert_eq!(Ok(()), validate_serialize(&tags, &validators::array_len(2)));
+/// ```
+pub fn validate_serialize<T, V>(value: &T, validator: &V) -> Result<(), String>
+where
+    T: serde::Serialize + ?Sized,
+    V: Validator + ?Sized,
+{
+    let value =
+        serde_json::to_value(value).map_err(|err| format!("Failed to serialize value: {}", err))?;
+    validator.validate(&value).map_err(|err| err.to_string())
+}
+
+/// Validate `actual` without panicking, reporting every mismatch.
+///
+/// This is what [assert_json!] does before formatting the errors, for use
+/// outside of tests, like in request validation middlewares.
+///
+/// ```
+/// use assert_json::{check, validators, ErrorKind};
+///
+/// let value = serde_json::json!({"id": "5"});
+/// let errors = check(&value, &validators::at("/id", validators::is_number())).unwrap_err();
+/// assert_eq!(ErrorKind::TypeMismatch, errors[0].kind());
+/// ```
+pub fn check<'a, V>(actual: &'a Value, validator: &V) -> Result<(), Vec<Error<'a>>>
+where
+    V: Validator + ?Sized,
+{
+    let errors = validators::with_document(actual, || validator.validate_all(actual));
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Parse `actual` as JSON and validate it without panicking.
+///
+/// As the parsed value only lives within this function, the errors are
+/// returned as [OwnedError]s holding their JSON pointer. Invalid JSON text is
+/// reported as a single [ErrorKind::InvalidJson] error.
+///
+/// ```
+/// use assert_json::{check_str, validators};
+///
+/// let errors = check_str(r#"{"id": "5"}"#, &validators::at("/id", validators::is_number()))
+///     .unwrap_err();
+/// assert_eq!(Some("/id"), errors[0].pointer());
+/// ```
+pub fn check_str<V>(actual: &str, validator: &V) -> Result<(), Vec<OwnedError>>
+where
+    V: Validator + ?Sized,
+{
+    let value: Value = serde_json::from_str(actual).map_err(|err| {
+        vec![OwnedError {
+            kind: ErrorKind::InvalidJson,
+            message: format!("Invalid JSON text: {}", err),
+            pointer: None,
+            actual: Value::String(String::from(actual)),
+        }]
+    })?;
+    check(&value, validator).map_err(|errors| {
+        errors
+            .into_iter()
+            .map(|error| error.into_owned(&value))
+            .collect()
+    })
+}
+
+/// Validate each line of a newline-delimited JSON (NDJSON) text without
+/// panicking.
+///
+/// Blank lines are skipped. The errors are keyed by line number, starting at
+/// 1, an invalid JSON line being reported as a single
+/// [ErrorKind::InvalidJson] error.
+///
+/// ```
+/// use assert_json::{check_ndjson, validators};
+///
+/// let log = "{\"level\": \"info\"}\n{\"level\": 3}\n";
+/// let errors = check_ndjson(log, &validators::at("/level", validators::is_string())).unwrap_err();
+/// assert_eq!(vec![&2], errors.keys().collect::<Vec<_>>());
+/// ```
+pub fn check_ndjson<V>(
+    actual: &str,
+    validator: &V,
+) -> Result<(), std::collections::BTreeMap<usize, Vec<OwnedError>>>
+where
+    V: Validator + ?Sized,
+{
+    let errors: std::collections::BTreeMap<_, _> = actual
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| {
+            check_str(line, validator)
+                .err()
+                .map(|errors| (index + 1, errors))
+        })
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 #[doc(hidden)]
 pub struct And<T, U> {
     first: T,
@@ -150,12 +880,244 @@ where
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
         self.first.validate(value).and(self.second.validate(value))
     }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let mut errors = self.first.validate_all(value);
+        errors.extend(self.second.validate_all(value));
+        errors
+    }
+
+    fn describe(&self) -> String {
+        format!("{} and {}", self.first.describe(), self.second.describe())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        validators::all_of_schemas([self.first.to_json_schema(), self.second.to_json_schema()])
+    }
 }
 
 /// Custom validators for different JSON types
 pub mod validators;
 
+pub mod formats;
+
+/// Validation of responses against an OpenAPI specification
+#[cfg(feature = "openapi")]
+pub mod openapi;
+
+mod coverage;
+#[cfg(feature = "miette")]
+mod diagnostic;
+mod diff;
+mod fixture;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod generate;
+mod matcher;
+mod patch;
+mod response;
+mod shrink;
+mod snapshot;
+mod source;
+mod suggest;
+mod truncate;
+
+pub use coverage::unvalidated_fields;
+#[cfg(feature = "miette")]
+pub use diagnostic::JsonDiagnostic;
+#[cfg(feature = "arbitrary")]
+pub use fuzz::{Contract, Generated};
+pub use matcher::BodyMatcher;
+pub use response::HttpResponse;
+#[cfg(any(feature = "http", feature = "reqwest"))]
+pub use response::ResponseJsonAssert;
+pub use shrink::shrink;
+pub use truncate::set_max_value_len;
+
 #[macro_use]
 mod macros;
 #[doc(hidden)]
 pub mod macros_utils;
+
+#[cfg(test)]
+mod tests {
+    use crate::{validators, Validator};
+
+    #[test]
+    fn check() {
+        let value = serde_json::json!({"a": 1, "b": 2});
+        let validator = validators::object_strict(std::collections::HashMap::from([
+            (
+                String::from("a"),
+                Box::new(validators::is_string()) as Box<dyn Validator>,
+            ),
+            (String::from("b"), Box::new(validators::is_string())),
+        ]));
+
+        assert_eq!(Ok(()), super::check(&value, &validators::any()));
+        assert_eq!(2, super::check(&value, &validator).unwrap_err().len());
+    }
+
+    #[test]
+    fn check_str() {
+        let validator = validators::at("/a", validators::eq(1));
+
+        assert_eq!(Ok(()), super::check_str(r#"{"a": 1}"#, &validator));
+        let errors = super::check_str(r#"{"a": 2}"#, &validator).unwrap_err();
+        assert_eq!(
+            vec![(Some("/a"), crate::ErrorKind::ValueMismatch)],
+            errors
+                .iter()
+                .map(|error| (error.pointer(), error.kind()))
+                .collect::<Vec<_>>()
+        );
+
+        let errors = super::check_str(r#"{"a": "#, &validator).unwrap_err();
+        assert_eq!(crate::ErrorKind::InvalidJson, errors[0].kind());
+        assert!(errors[0].message().starts_with("Invalid JSON text: "));
+    }
+
+    #[test]
+    fn check_ndjson() {
+        let validator = validators::at("/n", validators::is_number());
+        let text = "{\"n\": 1}\n\n{\"n\": \"2\"}\n{\"n\"\n{\"n\": 4}";
+
+        let errors = super::check_ndjson(text, &validator).unwrap_err();
+        assert_eq!(vec![&3, &4], errors.keys().collect::<Vec<_>>());
+        assert_eq!(crate::ErrorKind::TypeMismatch, errors[&3][0].kind());
+        assert_eq!(crate::ErrorKind::InvalidJson, errors[&4][0].kind());
+        assert_eq!(Ok(()), super::check_ndjson("{\"n\": 1}\n", &validator));
+    }
+
+    #[test]
+    fn truncated_value() {
+        let expected: Vec<u64> = (0..10_000).collect();
+        let mut actual = expected.clone();
+        actual[5000] = 0;
+        let actual = serde_json::json!(actual);
+
+        let message = validators::eq(expected)
+            .validate(&actual)
+            .unwrap_err()
+            .to_string();
+        assert!(message.len() < 3000);
+        assert!(message.contains(",4999,0,5001,"));
+        assert!(message.contains("more bytes"));
+    }
+
+    #[test]
+    fn error_to_json() {
+        let value = serde_json::json!({"items": [{"id": 1}, {"id": "2"}]});
+        let validator = validators::labeled(
+            "items",
+            validators::at(
+                "/items",
+                validators::array_for_each(validators::object_contains([(
+                    "id",
+                    Box::new(validators::any_of(vec![
+                        Box::new(validators::is_number()),
+                        Box::new(validators::is_null()),
+                    ])) as Box<dyn Validator>,
+                )])),
+            ),
+        );
+
+        let error = validator.validate(&value).unwrap_err();
+        assert_eq!(
+            serde_json::json!({
+                "path": "/items/1/id",
+                "kind": "no_matching_alternative",
+                "expected": null,
+                "actual": "2",
+                "label": "items",
+                "message": "items: Invalid array element at index 1. None of the alternatives matched: \
+                    Invalid type. Expected number but got string. | \
+                    Invalid type. Expected null but got string.",
+                "errors": [
+                    {
+                        "path": "/items/1/id",
+                        "kind": "invalid_type",
+                        "expected": "number",
+                        "actual": "2",
+                        "message": "Invalid type. Expected number but got string.",
+                    },
+                    {
+                        "path": "/items/1/id",
+                        "kind": "invalid_type",
+                        "expected": "null",
+                        "actual": "2",
+                        "message": "Invalid type. Expected null but got string.",
+                    },
+                ],
+            }),
+            error.to_json(&value)
+        );
+    }
+
+    #[test]
+    fn owned_error() {
+        fn assert_send_sync<T: std::error::Error + Send + Sync + 'static>(_: &T) {}
+
+        let value = serde_json::json!({"tags": ["a", 5]});
+        let error = validators::at("/tags", validators::array_for_each(validators::is_string()))
+            .validate(&value)
+            .unwrap_err()
+            .into_owned(&value);
+
+        assert_send_sync(&error);
+        assert_eq!(super::ErrorKind::TypeMismatch, error.kind());
+        assert_eq!(Some("/tags/1"), error.pointer());
+        assert_eq!(&serde_json::json!(5), error.actual());
+        assert_eq!(
+            "Invalid array element at index 1. Invalid type. Expected string but got number.",
+            error.message()
+        );
+
+        let error = super::OwnedError::from(super::Error::UnexpectedMatch(&value));
+        assert_eq!(None, error.pointer());
+        assert_eq!(
+            "Unexpected match. Expected value to not match but {\"tags\":[\"a\",5]} matched.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn error_kind() {
+        use super::ErrorKind;
+
+        let kind = |validator: Box<dyn Validator>, value: serde_json::Value| {
+            validator.validate(&value).unwrap_err().kind()
+        };
+
+        assert_eq!(
+            ErrorKind::TypeMismatch,
+            kind(Box::new(validators::is_string()), serde_json::json!(1))
+        );
+        assert_eq!(
+            ErrorKind::ValueMismatch,
+            kind(Box::new(validators::eq(1)), serde_json::json!(2))
+        );
+        assert_eq!(
+            ErrorKind::LengthMismatch,
+            kind(Box::new(validators::str_len(1)), serde_json::json!("ab"))
+        );
+        assert_eq!(
+            ErrorKind::MissingKey,
+            kind(Box::new(validators::has_key("a")), serde_json::json!({}))
+        );
+        assert_eq!(
+            ErrorKind::ExtraKey,
+            kind(
+                Box::new(validators::object_strict(Default::default())),
+                serde_json::json!({"a": 1})
+            )
+        );
+        assert_eq!(
+            ErrorKind::LengthMismatch,
+            kind(
+                Box::new(validators::labeled("tags", validators::array_len(1))),
+                serde_json::json!([])
+            )
+        );
+    }
+}
@@ -1,7 +1,44 @@
 /// Assert that a json value matches its validation rules
 ///
-/// `$val` parameter can be either a string or a `assert_json::Value`.
+/// `$val` parameter can be either a JSON string, a `assert_json::Value` or any
+/// type implementing `serde::Serialize`.
 /// `validators` is the validation rule expressed as a JSON-like structure.
+///
+/// Objects match as long as the listed keys match: other keys are ignored. A
+/// trailing `..` entry, like in `{"id": 1, ..}`, makes it explicit. An entry
+/// whose key is marked with `#[keys]` validates the values of all the keys
+/// matching the wildcard pattern, see
+/// [object_keys_matching](crate::validators::object_keys_matching). With the
+/// `regex` feature, `#[keys(regex)]` matches keys with a regular expression.
+/// Comments and trailing commas are allowed.
+///
+/// Values are expressions converted into validators: plain values match equal
+/// JSON values, [validators](crate::validators) are used as is and closures
+/// taking a `&Value` and returning a `bool` are used as predicates.
+///
+/// Instead of a JSON-like structure, the validation rules can be a list of
+/// `"/json/pointer" => value` entries validating a few deeply nested values.
+///
+/// Arrays match element by element. A `..` rest pattern matches any number of
+/// elements, so `[first, ..]` matches a prefix, `[.., last]` a suffix and
+/// `[first, .., last]` both.
+///
+/// ```
+/// use assert_json::assert_json;
+///
+/// assert_json!(r#"{"id": 1, "name": "n"}"#, { "id": 1, .. });
+/// assert_json!("[1, 2, 3, 4]", [1, .., 4]);
+/// assert_json!(
+///     r#"{"data": {"items": [{"name": "foo", "tags": []}]}}"#,
+///     "/data/items/0/name" => "foo",
+///     "/data/items/0/tags" => [],
+/// );
+/// assert_json!("4", |v: &assert_json::Value| v.as_u64() == Some(4));
+/// assert_json!(
+///     r#"{"metric_cpu": 3, "metric_mem": 12}"#,
+///     { #[keys] "metric_*": assert_json::validators::gt(0) }
+/// );
+/// ```
 #[macro_export]
 macro_rules! assert_json {
     ($val:expr , $($validator:tt)+) => ({
@@ -9,15 +46,411 @@ macro_rules! assert_json {
         use $crate::Validator;
         use $crate::macros_utils::*;
 
-        let validator = $crate::expand_json_validator!($($validator)+);
-        let input = Into::<Input>::into($val).get();
-        let result = validator.validate(&input);
-        if let Err(error) = result {
-            panic!("{}", format_error(&input, error));
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        let input = (&&InputSource($val)).to_input();
+        let errors = validate_input(input.value(), &validator);
+        if !errors.is_empty() {
+            panic!("{}", format_source_errors(input.value(), input.source(), errors));
+        }
+    });
+}
+
+/// Same as [assert_json!] but return a `Result` instead of panicking.
+///
+/// The error is an [AssertionError](crate::AssertionError), which displays as
+/// the message [assert_json!] would panic with.
+///
+/// ```
+/// use assert_json::try_assert_json;
+///
+/// let responses = [r#"{"status": "ok"}"#, r#"{"status": "ko"}"#];
+/// let failures = responses
+///     .iter()
+///     .filter(|response| try_assert_json!(**response, { "status": "ok" }).is_err())
+///     .count();
+/// assert_eq!(1, failures);
+/// ```
+#[macro_export]
+macro_rules! try_assert_json {
+    ($val:expr , $($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::Validator;
+        use $crate::macros_utils::*;
+
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        let input = (&&InputSource($val)).to_input();
+        let errors = validate_input(input.value(), &validator);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(assertion_error(input.value(), input.source(), errors))
+        }
+    });
+}
+
+/// Assert that a json value does not match the validation rules.
+///
+/// Same syntax as [assert_json!]. On failure, the message describes the
+/// expectation which unexpectedly matched.
+///
+/// ```
+/// use assert_json::{refute_json, validators};
+///
+/// // the password must no longer be returned
+/// refute_json!(r#"{"id": 5}"#, { "password": validators::any() });
+/// ```
+#[macro_export]
+macro_rules! refute_json {
+    ($val:expr , $($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::Validator;
+        use $crate::macros_utils::*;
+
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        let input = (&&InputSource($val)).to_input();
+        if validator.validate(input.value()).is_ok() {
+            panic!("{}", format_refutation(input.value(), input.source(), &validator));
+        }
+    });
+}
+
+/// Assert that two json values are equal.
+///
+/// Both values can be either a JSON string, a `assert_json::Value` or any type
+/// implementing `serde::Serialize`. On failure, the message holds a diff of
+/// the two values.
+///
+/// ```
+/// use assert_json::assert_json_eq;
+///
+/// let expected = serde_json::json!({"id": 5, "tags": ["a"]});
+/// assert_json_eq!(r#"{"tags": ["a"], "id": 5}"#, expected);
+/// ```
+#[macro_export]
+macro_rules! assert_json_eq {
+    ($actual:expr , $expected:expr $(,)?) => {{
+        use $crate::macros_utils::*;
+
+        let input = (&&InputSource($actual)).to_input();
+        let expected = (&&InputSource($expected)).to_input().get();
+        let validator = $crate::validators::eq(expected);
+        let errors = validate_input(input.value(), &validator);
+        if !errors.is_empty() {
+            panic!(
+                "{}",
+                format_source_errors(input.value(), input.source(), errors)
+            );
+        }
+    }};
+}
+
+/// Assert that a json value is equal to the JSON document stored in a file.
+///
+/// Same as [assert_json_eq!] but the expected value is read from the file at
+/// `$path`, relative to the directory of the crate manifest. Each file is read
+/// and parsed once, then cached. Panics if the file cannot be read or parsed.
+///
+/// ```
+/// use assert_json::assert_json_fixture;
+///
+/// let user = serde_json::json!({"id": 5, "name": "charlesvdv", "roles": ["admin", "user"]});
+/// assert_json_fixture!(user, "tests/fixtures/expected_user.json");
+/// ```
+#[macro_export]
+macro_rules! assert_json_fixture {
+    ($actual:expr , $path:expr $(,)?) => {{
+        use $crate::macros_utils::*;
+
+        let input = (&&InputSource($actual)).to_input();
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path);
+        let validator = $crate::validators::eq_fixture(path);
+        let errors = validate_input(input.value(), &validator);
+        if !errors.is_empty() {
+            panic!(
+                "{}",
+                format_source_errors(input.value(), input.source(), errors)
+            );
+        }
+    }};
+}
+
+/// Assert that a json value matches the snapshot stored in a file.
+///
+/// The snapshot is written with the actual value, pretty-printed, when the
+/// file at `$path` does not exist yet or when the `UPDATE_SNAPSHOTS`
+/// environment variable is set. A missing snapshot fails instead when the `CI`
+/// environment variable is set. Otherwise the actual value must be equal to the
+/// snapshot, except for the `"[ignore]"` strings of the snapshot which match
+/// any value, like generated identifiers or timestamps. Updating a snapshot
+/// keeps its `"[ignore]"` strings.
+///
+/// A relative `$path` is relative to the directory of the crate manifest.
+///
+/// ```
+/// use assert_json::assert_json_snapshot;
+///
+/// // tests/snapshots/user.json holds {"id": "[ignore]", "name": "charlesvdv"}
+/// assert_json_snapshot!(r#"{"id": 42, "name": "charlesvdv"}"#, "tests/snapshots/user.json");
+/// ```
+#[macro_export]
+macro_rules! assert_json_snapshot {
+    ($actual:expr , $path:expr $(,)?) => {{
+        use $crate::macros_utils::*;
+
+        let input = (&&InputSource($actual)).to_input();
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path);
+        if let Some(validator) = load_snapshot(path, input.value()) {
+            let errors = validate_input(input.value(), &validator);
+            if !errors.is_empty() {
+                panic!(
+                    "{}",
+                    format_source_errors(input.value(), input.source(), errors)
+                );
+            }
+        }
+    }};
+}
+
+/// Assert that a json value includes the expected structure.
+///
+/// Same as [assert_json!] except that arrays may have extra elements, in any
+/// order: each expected element must match a distinct element of the actual
+/// array. Objects already allow extra keys.
+///
+/// ```
+/// use assert_json::assert_json_include;
+///
+/// assert_json_include!(
+///     r#"{"id": 5, "tags": ["b", "a", "c"], "items": [{"id": 1, "name": "n"}]}"#,
+///     { "tags": ["a", "b"], "items": [{"id": 1}] }
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_json_include {
+    ($val:expr , $($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::Validator;
+        use $crate::macros_utils::*;
+        #[allow(unused_imports)]
+        use $crate::macros_utils::include::{array_literal, array_rest_literal, empty_array_literal};
+
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        let input = (&&InputSource($val)).to_input();
+        let errors = validate_input(input.value(), &validator);
+        if !errors.is_empty() {
+            panic!("{}", format_source_errors(input.value(), input.source(), errors));
         }
     });
 }
 
+/// Assert that a YAML document matches its validation rules.
+///
+/// `$val` is YAML text, parsed with
+/// [from_yaml_str](crate::formats::from_yaml_str), and the validation rules
+/// are the same as [assert_json!]. Panics if the text is not valid YAML.
+///
+/// ```
+/// use assert_json::{assert_yaml, validators};
+///
+/// assert_yaml!("
+/// jobs:
+///   test:
+///     runs-on: ubuntu-latest
+///     steps:
+///       - uses: actions/checkout@v4
+///       - run: cargo test
+/// ", {
+///     "jobs": { "test": { "runs-on": "ubuntu-latest", "steps": [.., { "run": "cargo test" }] } },
+/// });
+/// ```
+#[cfg(feature = "yaml")]
+#[macro_export]
+macro_rules! assert_yaml {
+    ($val:expr , $($validator:tt)+) => ({
+        let value = $crate::formats::from_yaml_str(AsRef::<str>::as_ref(&$val))
+            .unwrap_or_else(|err| panic!("failed to parse YAML: {}", err));
+        $crate::assert_json!(value, $($validator)+)
+    });
+}
+
+/// Assert that a TOML document matches its validation rules.
+///
+/// `$val` is TOML text, parsed with
+/// [from_toml_str](crate::formats::from_toml_str), and the validation rules
+/// are the same as [assert_json!]. Panics if the text is not valid TOML.
+///
+/// ```
+/// use assert_json::assert_toml;
+///
+/// assert_toml!(r#"
+/// [server]
+/// port = 8080
+/// hosts = ["a", "b"]
+/// "#, { "server": { "port": 8080, "hosts": ["a", ..] } });
+/// ```
+#[cfg(feature = "toml")]
+#[macro_export]
+macro_rules! assert_toml {
+    ($val:expr , $($validator:tt)+) => ({
+        let value = $crate::formats::from_toml_str(AsRef::<str>::as_ref(&$val))
+            .unwrap_or_else(|err| panic!("failed to parse TOML: {}", err));
+        $crate::assert_json!(value, $($validator)+)
+    });
+}
+
+/// Assert that a response body matches the OpenAPI specification of the
+/// operation.
+///
+/// `$spec` is the specification, as a [Value](crate::Value), for instance
+/// loaded with [openapi::load](crate::openapi::load), and `$val` is the body,
+/// like with [assert_json!]. The content type defaults to
+/// `application/json`. See [openapi::response](crate::openapi::response) for
+/// how the response is looked up.
+///
+/// ```
+/// use assert_json::assert_openapi;
+///
+/// let spec = serde_json::json!({
+///     "openapi": "3.1.0",
+///     "paths": {
+///         "/health": {
+///             "get": {
+///                 "responses": {
+///                     "200": {
+///                         "content": {
+///                             "text/plain": { "schema": { "type": "string" } },
+///                             "application/json": {
+///                                 "schema": { "type": "object", "required": ["status"] }
+///                             }
+///                         }
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// });
+///
+/// assert_openapi!(spec, "GET /health", 200, r#"{"status": "ok"}"#);
+/// assert_openapi!(spec, "GET /health", 200, "text/plain", serde_json::json!("ok"));
+/// ```
+#[cfg(feature = "openapi")]
+#[macro_export]
+macro_rules! assert_openapi {
+    ($spec:expr , $operation:expr , $status:expr , $val:expr $(,)?) => {
+        $crate::assert_openapi!($spec, $operation, $status, "application/json", $val)
+    };
+    ($spec:expr , $operation:expr , $status:expr , $content_type:expr , $val:expr $(,)?) => {{
+        let validator = $crate::openapi::response(&$spec, $operation, $status, $content_type);
+        $crate::assert_json!($val, validator)
+    }};
+}
+
+/// Assert that each line of a newline-delimited JSON (NDJSON) text matches
+/// the validation rules.
+///
+/// The validation rules are the same as [assert_json!]. Blank lines are
+/// skipped. An optional `count = ...` argument, converted into a validator
+/// like any expected value, checks the number of lines. A failure reports
+/// every failing line along with its line number.
+///
+/// ```
+/// use assert_json::{assert_ndjson, validators};
+///
+/// let export = r#"
+/// {"id": 1, "level": "info"}
+/// {"id": 2, "level": "warn"}
+/// "#;
+/// assert_ndjson!(export, { "id": validators::gt(0), "level": validators::is_string() });
+/// assert_ndjson!(export, count = 2, { "id": validators::any() });
+/// assert_ndjson!(export, count = validators::ge(1), { "id": validators::any() });
+/// ```
+#[macro_export]
+macro_rules! assert_ndjson {
+    ($val:expr , count = $count:expr , $($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::Validator;
+        use $crate::macros_utils::*;
+
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        let count = $crate::expand_json_validator!($count);
+        if let Some(report) = format_ndjson_errors(AsRef::<str>::as_ref(&$val), &validator, Some(&count)) {
+            panic!("{}", report);
+        }
+    });
+    ($val:expr , $($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::Validator;
+        use $crate::macros_utils::*;
+
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        if let Some(report) = format_ndjson_errors(AsRef::<str>::as_ref(&$val), &validator, None) {
+            panic!("{}", report);
+        }
+    });
+}
+
+/// Assert that an HTTP response has a JSON body matching the validation
+/// rules.
+///
+/// `$response` is an [HttpResponse](crate::HttpResponse), or a reference to
+/// one, and the validation rules are the same as [assert_json!]. The content
+/// type must be JSON, like `application/json` or `application/problem+json`.
+/// A failure reports the status and the headers of the response.
+///
+/// ```
+/// use assert_json::{assert_json_response, validators, HttpResponse};
+///
+/// let response = HttpResponse::new(201, r#"{"id": 5, "name": "charlesvdv"}"#)
+///     .header("Content-Type", "application/json");
+/// assert_json_response!(&response, { "id": validators::gt(0), .. });
+/// ```
+#[macro_export]
+macro_rules! assert_json_response {
+    ($response:expr , $($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::Validator;
+        use $crate::macros_utils::*;
+
+        let validator = $crate::validators::document($crate::expand_json_validator!($($validator)+));
+        let response = $response;
+        let response: &$crate::HttpResponse = ::std::borrow::Borrow::borrow(&response);
+        if let Some(report) = format_response_errors(response, &validator) {
+            panic!("{}", report);
+        }
+    });
+}
+
+/// Build a [BodyMatcher](crate::BodyMatcher) matching request bodies against
+/// the validation rules.
+///
+/// The validation rules are the same as [assert_json!]. They are evaluated
+/// again for each body to match: the variables they use are moved into the
+/// matcher and must be `Send` and `Sync`, and clone the ones which aren't
+/// `Copy`, like `{ "name": name.clone() }`.
+///
+/// ```
+/// use assert_json::{body_matcher, validators};
+///
+/// let min_quantity = 1;
+/// let matcher = body_matcher!({
+///     "sku": validators::starts_with("SKU-"),
+///     "quantity": validators::ge(min_quantity),
+/// });
+/// assert!(matcher.matches(br#"{"sku": "SKU-42", "quantity": 3}"#));
+/// ```
+#[macro_export]
+macro_rules! body_matcher {
+    ($($validator:tt)+) => {
+        $crate::BodyMatcher::new(move || {
+            #[allow(unused_imports)]
+            use $crate::Validator;
+            use $crate::macros_utils::*;
+
+            $crate::expand_json_validator!($($validator)+)
+        })
+    };
+}
+
 /// Heavily inspired by https://github.com/serde-rs/json.
 /// Thanks dtolnay!
 #[macro_export]
@@ -27,48 +460,79 @@ macro_rules! expand_json_validator {
     // array handling
     // *******************************************************************
 
+    // Arrays are parsed either as a `literal`, which may hold a `..` rest
+    // pattern, or as the `suffix` following such a pattern.
+
     // Done with trailing comma.
-    (@array [$($elems:expr,)*]) => {
+    (@array literal [$($elems:expr,)*]) => {
+        array_literal($crate::expand_json_vec_validator![$($elems,)*])
+    };
+
+    // Done without trailing comma.
+    (@array literal [$($elems:expr),*]) => {
+        array_literal($crate::expand_json_vec_validator![$($elems),*])
+    };
+
+    // Done with trailing comma.
+    (@array suffix [$($elems:expr,)*]) => {
         $crate::expand_json_vec_validator![$($elems,)*]
     };
 
     // Done without trailing comma.
-    (@array [$($elems:expr),*]) => {
+    (@array suffix [$($elems:expr),*]) => {
         $crate::expand_json_vec_validator![$($elems),*]
     };
 
+    // Rest pattern as the last element.
+    (@array literal [$($elems:expr,)*] .. $(,)?) => {
+        array_rest_literal($crate::expand_json_vec_validator![$($elems,)*], $crate::expand_json_vec_validator![])
+    };
+
+    // Rest pattern followed by the last elements.
+    (@array literal [$($elems:expr,)*] .. , $($rest:tt)+) => {
+        array_rest_literal(
+            $crate::expand_json_vec_validator![$($elems,)*],
+            $crate::expand_json_validator!(@array suffix [] $($rest)+),
+        )
+    };
+
+    // A second rest pattern.
+    (@array suffix [$($elems:expr,)*] .. $($rest:tt)*) => {
+        $crate::json_unexpected!(..)
+    };
+
     // Next element is `null`.
-    (@array [$($elems:expr,)*] null $($rest:tt)*) => {
-        $crate::expand_json_validator!(@array [$($elems,)* Box::new($crate::expand_json_validator!(null))] $($rest)*)
+    (@array $kind:ident [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array $kind [$($elems,)* Box::new($crate::expand_json_validator!(null))] $($rest)*)
     };
 
     // Next element is an array.
-    (@array [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
-        $crate::expand_json_validator!(@array [$($elems,)* Box::new($crate::expand_json_validator!([$($array)*]))] $($rest)*)
+    (@array $kind:ident [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array $kind [$($elems,)* Box::new($crate::expand_json_validator!([$($array)*]))] $($rest)*)
     };
 
     // Next element is a map.
-    (@array [$($elems:expr,)*] {$($map:tt)*} $($rest:tt)*) => {
-        $crate::expand_json_validator!(@array [$($elems,)* Box::new($crate::expand_json_validator!({$($map)*}))] $($rest)*)
+    (@array $kind:ident [$($elems:expr,)*] {$($map:tt)*} $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array $kind [$($elems,)* Box::new($crate::expand_json_validator!({$($map)*}))] $($rest)*)
     };
 
     // Next element is an expression followed by comma.
-    (@array [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
-        $crate::expand_json_validator!(@array [$($elems,)* $crate::expand_json_validator!($next),] $($rest)*)
+    (@array $kind:ident [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array $kind [$($elems,)* $crate::expand_json_validator!($next),] $($rest)*)
     };
 
     // Last element is an expression with no trailing comma.
-    (@array [$($elems:expr,)*] $last:expr) => {
-        $crate::expand_json_validator!(@array [$($elems,)* $crate::expand_json_validator!($last)])
+    (@array $kind:ident [$($elems:expr,)*] $last:expr) => {
+        $crate::expand_json_validator!(@array $kind [$($elems,)* $crate::expand_json_validator!($last)])
     };
 
     // Comma after the most recent element.
-    (@array [$($elems:expr),*] , $($rest:tt)*) => {
-        $crate::expand_json_validator!(@array [$($elems,)*] $($rest)*)
+    (@array $kind:ident [$($elems:expr),*] , $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array $kind [$($elems,)*] $($rest)*)
     };
 
     // Unexpected token after most recent element.
-    (@array [$($elems:expr),*] $unexpected:tt $($rest:tt)*) => {
+    (@array $kind:ident [$($elems:expr),*] $unexpected:tt $($rest:tt)*) => {
         $crate::json_unexpected!($unexpected)
     };
 
@@ -78,6 +542,34 @@ macro_rules! expand_json_validator {
 
     (@object $object:ident () () ()) => {};
 
+    // Rest pattern as the last entry, with or without trailing comma. Other
+    // keys are always allowed, it only makes it explicit.
+    (@object $object:ident () (.. $(,)?) $copy:tt) => {};
+
+    // Insert the current `#[keys]` entry, matching the keys with wildcards,
+    // followed by trailing comma.
+    (@object $object:ident [# [keys] $pattern:expr] ($value:expr) , $($rest:tt)*) => {
+        $object.insert_matching($pattern, $value);
+        $crate::expand_json_validator!(@object $object () ($($rest)*) ($($rest)*));
+    };
+
+    // Insert the last `#[keys]` entry without trailing comma.
+    (@object $object:ident [# [keys] $pattern:expr] ($value:expr)) => {
+        $object.insert_matching($pattern, $value);
+    };
+
+    // Insert the current `#[keys(regex)]` entry, matching the keys with a
+    // regular expression, followed by trailing comma.
+    (@object $object:ident [# [keys(regex)] $pattern:expr] ($value:expr) , $($rest:tt)*) => {
+        $object.insert_matching_regex($pattern, $value);
+        $crate::expand_json_validator!(@object $object () ($($rest)*) ($($rest)*));
+    };
+
+    // Insert the last `#[keys(regex)]` entry without trailing comma.
+    (@object $object:ident [# [keys(regex)] $pattern:expr] ($value:expr)) => {
+        $object.insert_matching_regex($pattern, $value);
+    };
+
     // Insert the current entry followed by trailing comma.
     (@object $object:ident [$($key:tt)+] ($value:expr) , $($rest:tt)*) => {
         let _ = $object.insert(($($key)+).into(), $value);
@@ -160,6 +652,45 @@ macro_rules! expand_json_validator {
         $crate::expand_json_validator!(@object $object ($($key)* $tt) ($($rest)*) ($($rest)*));
     };
 
+    // *******************************************************************
+    // path handling
+    // *******************************************************************
+
+    // Done with all the `"/pointer" => value` entries.
+    (@paths [$($elems:expr,)*]) => {
+        $crate::validators::all_of(vec![$($elems),*])
+    };
+
+    // Next value is `null`.
+    (@paths [$($elems:expr,)*] $path:literal => null $(, $($rest:tt)*)?) => {
+        $crate::expand_json_validator!(@paths [$($elems,)* Box::new($crate::validators::at($path, $crate::expand_json_validator!(null))),] $($($rest)*)?)
+    };
+
+    // Next value is an array.
+    (@paths [$($elems:expr,)*] $path:literal => [$($array:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::expand_json_validator!(@paths [$($elems,)* Box::new($crate::validators::at($path, $crate::expand_json_validator!([$($array)*]))),] $($($rest)*)?)
+    };
+
+    // Next value is a map.
+    (@paths [$($elems:expr,)*] $path:literal => {$($map:tt)*} $(, $($rest:tt)*)?) => {
+        $crate::expand_json_validator!(@paths [$($elems,)* Box::new($crate::validators::at($path, $crate::expand_json_validator!({$($map)*}))),] $($($rest)*)?)
+    };
+
+    // Next value is an expression followed by comma.
+    (@paths [$($elems:expr,)*] $path:literal => $value:expr , $($rest:tt)*) => {
+        $crate::expand_json_validator!(@paths [$($elems,)* Box::new($crate::validators::at($path, $crate::expand_json_validator!($value))),] $($rest)*)
+    };
+
+    // Last value is an expression with no trailing comma.
+    (@paths [$($elems:expr,)*] $path:literal => $value:expr) => {
+        $crate::expand_json_validator!(@paths [$($elems,)* Box::new($crate::validators::at($path, $crate::expand_json_validator!($value))),])
+    };
+
+    // Unexpected token instead of an entry.
+    (@paths [$($elems:expr,)*] $unexpected:tt $($rest:tt)*) => {
+        $crate::json_unexpected!($unexpected)
+    };
+
     // *******************************************************************
     // primitive handling
     // *******************************************************************
@@ -168,15 +699,17 @@ macro_rules! expand_json_validator {
         $crate::validators::null()
     };
 
+    // Array literals are built by the functions in scope, which the
+    // asserting macros import from macros_utils.
     ([]) => {
-        $crate::validators::array_empty()
+        empty_array_literal()
     };
 
     ([ $($tt:tt)+ ]) => {
         // {
         //     let mut validators_array = vec![];
         // }
-        $crate::validators::array($crate::expand_json_validator!(@array [] $($tt)+))
+        $crate::expand_json_validator!(@array literal [] $($tt)+)
         // $crate::Value::Array(json_internal!(@array [] $($tt)+))
     };
 
@@ -184,18 +717,29 @@ macro_rules! expand_json_validator {
         $crate::validators::object(std::collections::HashMap::new())
     };
 
+    ({ .. $(,)? }) => {
+        $crate::validators::object(std::collections::HashMap::new())
+    };
+
     ({ $($tt:tt)+ }) => {
-        $crate::validators::object({
-            let mut object: std::collections::HashMap<String, Box<dyn $crate::Validator>> = std::collections::HashMap::new();
+        {
+            let mut object = $crate::macros_utils::ObjectLiteral::new();
             $crate::expand_json_validator!(@object object () ($($tt)+) ($($tt)+));
-            object
-        })
+            object.build()
+        }
+    };
+
+    // `"/pointer" => value` entries validate values deep in the document.
+    ($path:literal => $($rest:tt)+) => {
+        $crate::expand_json_validator!(@paths [] $path => $($rest)+)
     };
 
+    // Closures returning a `bool` are used as predicates, other values are
+    // converted into a ValidatorInput.
     ($other:expr) => {
         {
-            let validator: ValidatorInput = $other.into();
-            validator.get()
+            let source = ValidatorSource($other, stringify!($other));
+            (&&source).kind().to_validator(source).get()
         }
     };
 }
@@ -241,6 +785,97 @@ mod test {
         assert_json!("2.3", 2.3);
     }
 
+    #[test]
+    fn assert_json_big_number() {
+        assert_json!("18446744073709551615", u64::MAX as u128);
+        assert_json!("-9223372036854775808", i64::MIN as i128);
+        assert_json!("5", serde_json::Number::from(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the JSON number range")]
+    fn assert_json_number_out_of_range() {
+        assert_json!("5", u128::MAX);
+    }
+
+    #[test]
+    fn assert_json_option() {
+        let name: Option<&str> = Some("name");
+        let nickname: Option<String> = None;
+        assert_json!(r#"{"name": "name", "nickname": null}"#, {
+            "name": name,
+            "nickname": nickname,
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_option_none_not_null() {
+        assert_json!("5", None::<u64>);
+    }
+
+    #[test]
+    fn assert_json_value_input() {
+        let value = serde_json::json!({"key": [1, 2]});
+        assert_json!(&value, { "key": [1, 2] });
+        assert_json!(value, { "key": [1, 2] });
+    }
+
+    #[test]
+    fn assert_json_string_input() {
+        let json = String::from(r#"{"key": "value"}"#);
+        assert_json!(&json, { "key": "value" });
+        assert_json!(json, { "key": "value" });
+    }
+
+    #[test]
+    fn assert_json_serialize_input() {
+        #[derive(serde::Serialize)]
+        struct User {
+            name: &'static str,
+            roles: Vec<&'static str>,
+        }
+
+        let user = User {
+            name: "jane",
+            roles: vec!["admin"],
+        };
+        assert_json!(user, { "name": "jane", "roles": ["admin"] });
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_serialize_input_fail() {
+        assert_json!(vec![1, 2], [1, 3]);
+    }
+
+    #[test]
+    fn assert_json_vec() {
+        let tags = vec!["a", "b"];
+        assert_json!(r#"{"tags": ["a", "b"]}"#, { "tags": tags });
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_vec_wrong_element() {
+        assert_json!("[1, 2]", vec![1, 3]);
+    }
+
+    #[test]
+    fn assert_json_hash_map() {
+        let mut ids = std::collections::HashMap::new();
+        ids.insert(String::from("a"), vec![1, 2]);
+        ids.insert(String::from("b"), vec![3]);
+        assert_json!(r#"{"a": [1, 2], "b": [3], "c": []}"#, ids);
+    }
+
+    #[test]
+    fn assert_json_vec_of_validators() {
+        let validators: Vec<Box<dyn crate::Validator>> =
+            vec![Box::new(crate::validators::gt(1)), "b".into()];
+        assert_json!(r#"[2, "b"]"#, validators);
+    }
+
     #[test]
     fn assert_json_bool() {
         assert_json!("true", true);
@@ -362,4 +997,355 @@ mod test {
         let num = 5;
         assert_json!("5", num);
     }
+
+    #[test]
+    fn try_assert_json() {
+        assert_eq!(Ok(()), try_assert_json!(r#"{"id": 5}"#, { "id": 5 }));
+
+        let error =
+            try_assert_json!(r#"{"id": 5, "name": 1}"#, { "id": 6, "name": "n" }).unwrap_err();
+        assert_eq!(
+            vec![Some("/id"), Some("/name")],
+            error
+                .errors()
+                .iter()
+                .map(|error| error.pointer())
+                .collect::<Vec<_>>()
+        );
+        assert!(error
+            .to_string()
+            .contains("Invalid JSON at /id (line 1, column 8)"));
+    }
+
+    #[test]
+    fn assert_json_include() {
+        assert_json_include!(r#"{"a": [1, 2, [3, 4]], "b": []}"#, {
+            "a": [[4], 1],
+            "b": [],
+        });
+        assert_json_include!("[1, 2, 3]", [3, .., 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "validator at index 1 has no matching element")]
+    fn assert_json_include_missing_element() {
+        assert_json_include!("[1, 2]", [1, 3]);
+    }
+
+    #[test]
+    fn assert_json_eq() {
+        assert_json_eq!("[1, {\"a\": null}]", serde_json::json!([1, {"a": null}]));
+        assert_json_eq!(serde_json::json!(["a"]), vec!["a"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value")]
+    fn assert_json_eq_mismatch() {
+        assert_json_eq!("[1, 2]", "[1, 3]");
+    }
+
+    #[test]
+    fn refute_json() {
+        refute_json!(r#"{"id": 5}"#, { "id": 6 });
+        refute_json!("[1, 2]", [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matched expectation: an object with 'id' being 5")]
+    fn refute_json_matching() {
+        refute_json!(r#"{"id": 5, "name": "n"}"#, { "id": 5 });
+    }
+
+    #[test]
+    fn assert_json_object_rest() {
+        assert_json!(r#"{"id": 1, "name": "n"}"#, { "id": 1, .. });
+        assert_json!(r#"{"id": 1, "name": "n"}"#, { "id": 1, .., });
+        assert_json!(r#"{"id": 1}"#, { .. });
+        assert_json!(r#"{"a": {"id": 1, "b": 2}}"#, { "a": { "id": 1, .. } });
+    }
+
+    #[test]
+    fn assert_json_array_rest() {
+        assert_json!("[1, 2, 3]", [1, ..]);
+        assert_json!("[1, 2, 3]", [1, 2, ..,]);
+        assert_json!("[1, 2, 3]", [.., 3]);
+        assert_json!("[1, [2, 3], 4]", [1, [.., 3], ..]);
+        assert_json!("[]", [..]);
+        assert_json!("[null, {}, 5]", [null, .., 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid array element at index 2")]
+    fn assert_json_array_rest_mismatch() {
+        assert_json!("[1, 2, 3]", [1, .., 4]);
+    }
+
+    #[test]
+    fn assert_json_object_keys_pattern() {
+        assert_json!(r#"{"id": 1, "metric_cpu": 3, "metric_mem": 12}"#, {
+            "id": 1,
+            #[keys] "metric_*": crate::validators::gt(0),
+        });
+        assert_json!(r#"{"a": {"x_1": "s"}}"#, { "a": { #[keys] "x_?": crate::validators::is_string() } });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid JSON at /metric_mem")]
+    fn assert_json_object_keys_pattern_mismatch() {
+        assert_json!(r#"{"metric_cpu": 3, "metric_mem": 0}"#, {
+            #[keys] "metric_*": crate::validators::gt(0)
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn assert_json_object_keys_regex() {
+        assert_json!(r#"{"metric_cpu": 3}"#, {
+            #[keys(regex)] "^metric_[a-z]+$": crate::validators::gt(0),
+        });
+    }
+
+    #[test]
+    fn assert_json_closure() {
+        use crate::Value;
+
+        assert_json!(r#"{"count": 4}"#, {
+            "count": |v: &Value| v.as_u64() == Some(4),
+        });
+        assert_json!(
+            "[1, 3]",
+            [|v: &Value| v == 1, |v: &Value| v.as_u64() > Some(2)]
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid value. Expected value satisfying `|v: &Value| v.as_u64() == Some(4)` but got 3."
+    )]
+    fn assert_json_closure_mismatch() {
+        use crate::Value;
+
+        assert_json!(r#"{"count": 3}"#, {
+            "count": |v: &Value| v.as_u64() == Some(4)
+        });
+    }
+
+    #[test]
+    fn assert_json_paths() {
+        let json = r#"{"data": {"items": [{"name": "foo", "tags": ["a"]}], "next": null}}"#;
+
+        assert_json!(json, "/data/items/0/name" => "foo");
+        assert_json!(json,
+            "/data/next" => null,
+            "/data/items/0/tags" => [..],
+            "/data/items/0" => { "name": "foo" },
+            "/data/items/0/name" => crate::validators::is_string(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid JSON at /data/items/0/name")]
+    fn assert_json_paths_mismatch() {
+        assert_json!(r#"{"data": {"items": [{"name": "bar"}]}}"#, "/data/items/0/name" => "foo");
+    }
+
+    #[test]
+    fn assert_json_fixture() {
+        assert_json_fixture!(
+            r#"{"roles": ["admin", "user"], "name": "charlesvdv", "id": 5}"#,
+            "tests/fixtures/expected_user.json"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "+    \"guest\"")]
+    fn assert_json_fixture_mismatch() {
+        assert_json_fixture!(
+            r#"{"id": 5, "name": "charlesvdv", "roles": ["admin", "guest"]}"#,
+            "tests/fixtures/expected_user.json"
+        );
+    }
+
+    #[test]
+    fn assert_json_snapshot() {
+        assert_json_snapshot!(
+            r#"{"id": 7, "name": "charlesvdv"}"#,
+            "tests/snapshots/user.json"
+        );
+    }
+
+    #[test]
+    fn assert_json_snapshot_mismatch() {
+        // A snapshot of its own, which UPDATE_SNAPSHOTS overwrites.
+        let path = std::env::temp_dir()
+            .join(format!("assert_json_macros_{}", std::process::id()))
+            .join("user.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, r#"{"id": "[ignore]", "name": "charlesvdv"}"#).unwrap();
+
+        let result = std::panic::catch_unwind(|| {
+            assert_json_snapshot!(r#"{"id": 7, "name": "other"}"#, &path);
+        });
+        let _ = std::fs::remove_file(&path);
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            assert!(result.is_ok());
+        } else {
+            let message = result.unwrap_err().downcast::<String>().unwrap();
+            assert!(message.contains("Invalid JSON at /name"), "{}", message);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn assert_yaml() {
+        assert_yaml!("a: [1, 2]\nb:\n  c: d\n", { "a": [1, 2], "b": { "c": "d" } });
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    #[should_panic(expected = "Invalid JSON at /b/c")]
+    fn assert_yaml_mismatch() {
+        assert_yaml!(String::from("b:\n  c: e\n"), { "b": { "c": "d" } });
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    #[should_panic(expected = "failed to parse YAML: duplicate entry with key \"a\"")]
+    fn assert_yaml_invalid() {
+        assert_yaml!("a: 1\na: 2\n", { "a": 1 });
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn assert_toml() {
+        assert_toml!(include_str!("../Cargo.toml"), {
+            "package": { "name": "assert_json", "edition": "2021" },
+            "features": { "toml": ["dep:toml"] },
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    #[should_panic(expected = "Invalid JSON at /server/port")]
+    fn assert_toml_mismatch() {
+        assert_toml!("[server]\nport = 80\n", { "server": { "port": 8080 } });
+    }
+
+    #[test]
+    #[cfg(feature = "openapi")]
+    fn assert_openapi() {
+        let spec = crate::openapi::load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/openapi.json"
+        ));
+
+        assert_openapi!(spec, "GET /users/{id}", 200, r#"{"id": 1, "name": "n"}"#);
+        assert_openapi!(
+            spec,
+            "GET /users/1",
+            200,
+            "application/json; charset=utf-8",
+            serde_json::json!({"id": 1, "name": "n"}),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "openapi")]
+    #[should_panic(expected = "Invalid JSON at /id")]
+    fn assert_openapi_mismatch() {
+        let spec = crate::openapi::load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/openapi.json"
+        ));
+
+        assert_openapi!(spec, "GET /users/{id}", 200, r#"{"id": 0, "name": "n"}"#);
+    }
+
+    #[test]
+    fn assert_json_response() {
+        let response = crate::HttpResponse::new(200, r#"{"id": 5}"#)
+            .header("Content-Type", "application/json");
+
+        assert_json_response!(response.clone(), { "id": 5 });
+        assert_json_response!(&response, { "id": crate::validators::gt(0) });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid JSON at /id")]
+    fn assert_json_response_mismatch() {
+        let response = crate::HttpResponse::new(200, r#"{"id": 5}"#)
+            .header("Content-Type", "application/json");
+
+        assert_json_response!(response, { "id": 6 });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected a JSON response but got 'text/html'.\n\nResponse status: 500"
+    )]
+    fn assert_json_response_content_type() {
+        let response =
+            crate::HttpResponse::new(500, "<h1>oops</h1>").header("Content-Type", "text/html");
+
+        assert_json_response!(response, { "id": 5 });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid JSON body: EOF while parsing an object")]
+    fn assert_json_response_invalid_body() {
+        let response =
+            crate::HttpResponse::new(200, "{").header("Content-Type", "application/json");
+
+        assert_json_response!(response, { "id": 5 });
+    }
+
+    #[test]
+    fn body_matcher() {
+        let name = "charlesvdv";
+        let matcher = body_matcher!({ "name": name, "tags": [.., "admin"] });
+
+        assert!(matcher.matches(br#"{"name": "charlesvdv", "tags": ["dev", "admin"]}"#));
+        assert!(!matcher.matches(br#"{"name": "charlesvdv", "tags": []}"#));
+    }
+
+    #[test]
+    fn assert_ndjson() {
+        let text = "{\"id\": 1}\n\n{\"id\": 2}\n";
+
+        assert_ndjson!(text, { "id": crate::validators::gt(0) });
+        assert_ndjson!(String::from(text), count = 2, { "id": crate::validators::any() });
+    }
+
+    #[test]
+    #[should_panic(expected = "NDJSON line 3:")]
+    fn assert_ndjson_mismatch() {
+        assert_ndjson!("{\"id\": 1}\n\n{\"id\": 0}\n", { "id": crate::validators::gt(0) });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid NDJSON line count. Invalid value. Expected 3 but got 2.")]
+    fn assert_ndjson_count() {
+        assert_ndjson!("1\n2\n", count = 3, crate::validators::any());
+    }
+
+    #[test]
+    fn assert_json_comments_and_trailing_commas() {
+        let key = "name";
+
+        assert_json!(r#"{"id": 1, "name": "n", "tags": [1]}"#, {
+            // Keys are expressions.
+            "id": 1,
+            key: "n",
+            "tags": [1,],
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "json5")]
+    fn assert_json_fixture_json5() {
+        let response =
+            serde_json::json!({"id": 5, "name": "charlesvdv", "roles": ["admin", "user"]});
+
+        assert_json_fixture!(response, "tests/fixtures/expected_user.json5");
+    }
 }
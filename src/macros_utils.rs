@@ -5,27 +5,220 @@ use codespan_reporting::term::termcolor;
 
 use crate::validators;
 use crate::{Error, Validator, Value};
-use std::collections::BTreeMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Range;
 
-pub struct Input(Value);
+pub struct Input {
+    value: Value,
+    /// The JSON text the value was parsed from, if any.
+    source: Option<String>,
+}
 
 impl Input {
     pub fn get(self) -> Value {
-        self.0
+        self.value
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
     }
 }
 
 impl From<&str> for Input {
     fn from(str_input: &str) -> Input {
         let value = serde_json::from_str(str_input).expect("failed to parse JSON");
-        Input(value)
+        Input {
+            value,
+            source: Some(String::from(str_input)),
+        }
     }
 }
 
 impl From<Value> for Input {
     fn from(value: Value) -> Input {
-        Input(value)
+        Input {
+            value,
+            source: None,
+        }
+    }
+}
+
+/// Wraps the actual value given to [assert_json!](crate::assert_json) so the
+/// conversion can be picked through autoref specialization: strings are
+/// parsed as JSON while other [Serialize] types are serialized.
+pub struct InputSource<T>(pub T);
+
+pub trait ParseInput {
+    fn to_input(&self) -> Input;
+}
+
+impl<T> ParseInput for &InputSource<T>
+where
+    T: AsRef<str>,
+{
+    fn to_input(&self) -> Input {
+        Input::from(self.0.as_ref())
+    }
+}
+
+pub trait SerializeInput {
+    fn to_input(&self) -> Input;
+}
+
+impl<T> SerializeInput for InputSource<T>
+where
+    T: Serialize,
+{
+    fn to_input(&self) -> Input {
+        Input::from(serde_json::to_value(&self.0).expect("failed to serialize JSON"))
+    }
+}
+
+/// Build an array literal of the expectation syntax.
+pub fn array_literal(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    validators::array(validators)
+}
+
+/// Build an empty array literal of the expectation syntax.
+pub fn empty_array_literal() -> impl Validator {
+    validators::array_empty()
+}
+
+/// Build an array literal of the expectation syntax holding a `..` rest pattern.
+pub fn array_rest_literal(
+    prefix: Vec<Box<dyn Validator>>,
+    suffix: Vec<Box<dyn Validator>>,
+) -> impl Validator {
+    validators::array_with_rest(prefix, suffix)
+}
+
+/// Object literal of the expectation syntax, built entry by entry.
+#[derive(Default)]
+pub struct ObjectLiteral {
+    key_validators: HashMap<String, Box<dyn Validator>>,
+    /// Validators of the `#[keys]` entries.
+    pattern_validators: Vec<Box<dyn Validator>>,
+}
+
+impl ObjectLiteral {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: String, validator: Box<dyn Validator>) {
+        self.key_validators.insert(key, validator);
+    }
+
+    /// Insert a `#[keys] "pattern": validator` entry.
+    pub fn insert_matching(&mut self, pattern: &str, validator: Box<dyn Validator>) {
+        self.pattern_validators
+            .push(Box::new(validators::object_keys_matching(
+                pattern, validator,
+            )));
+    }
+
+    /// Insert a `#[keys(regex)] "pattern": validator` entry.
+    #[cfg(feature = "regex")]
+    pub fn insert_matching_regex(&mut self, pattern: &str, validator: Box<dyn Validator>) {
+        self.pattern_validators
+            .push(Box::new(validators::object_keys_matching_regex(
+                pattern, validator,
+            )));
+    }
+
+    pub fn build(self) -> Box<dyn Validator> {
+        let object = Box::new(validators::object(self.key_validators));
+        if self.pattern_validators.is_empty() {
+            return object;
+        }
+
+        let mut all = vec![object as Box<dyn Validator>];
+        all.extend(self.pattern_validators);
+        Box::new(validators::all_of(all))
+    }
+}
+
+/// Array literals of [assert_json_include!](crate::assert_json_include).
+pub mod include {
+    use crate::validators;
+    use crate::Validator;
+
+    /// Match an array including the elements, in any order.
+    pub fn array_literal(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+        validators::array_includes_all(validators)
+    }
+
+    /// Match any array.
+    pub fn empty_array_literal() -> impl Validator {
+        validators::is_array()
+    }
+
+    /// Match an array including the elements, in any order.
+    pub fn array_rest_literal(
+        mut prefix: Vec<Box<dyn Validator>>,
+        suffix: Vec<Box<dyn Validator>>,
+    ) -> impl Validator {
+        prefix.extend(suffix);
+        validators::array_includes_all(prefix)
+    }
+}
+
+/// Wraps an expected value given to [assert_json!](crate::assert_json),
+/// along with its source text, so the conversion can be picked through
+/// autoref specialization: closures returning a `bool` are used as
+/// predicates while other values are converted into a [ValidatorInput].
+pub struct ValidatorSource<T>(pub T, pub &'static str);
+
+pub struct PredicateTag;
+
+pub trait PredicateKind {
+    fn kind(&self) -> PredicateTag {
+        PredicateTag
+    }
+}
+
+impl<F> PredicateKind for &ValidatorSource<F> where F: Fn(&Value) -> bool {}
+
+impl PredicateTag {
+    pub fn to_validator<F>(self, source: ValidatorSource<F>) -> ValidatorInput
+    where
+        F: Fn(&Value) -> bool + 'static,
+    {
+        let ValidatorSource(predicate, text) = source;
+        ValidatorInput(Box::new(validators::described(
+            format!("a value satisfying `{}`", text),
+            validators::custom(move |value| {
+                if predicate(value) {
+                    Ok(())
+                } else {
+                    Err(format!("value satisfying `{}`", text))
+                }
+            }),
+        )))
+    }
+}
+
+pub struct ConvertTag;
+
+pub trait ConvertKind {
+    fn kind(&self) -> ConvertTag {
+        ConvertTag
+    }
+}
+
+impl<T> ConvertKind for ValidatorSource<T> where T: Into<ValidatorInput> {}
+
+impl ConvertTag {
+    pub fn to_validator<T>(self, source: ValidatorSource<T>) -> ValidatorInput
+    where
+        T: Into<ValidatorInput>,
+    {
+        source.0.into()
     }
 }
 
@@ -49,6 +242,13 @@ macro_rules! impl_from_validator_input_default {
                     ValidatorInput(Box::new(validators::eq(u)))
                 }
             }
+
+            impl From<$ty> for Box<dyn Validator> {
+                #[inline]
+                fn from(u: $ty) -> Self {
+                    Box::new(validators::eq(u))
+                }
+            }
         )*
     };
 }
@@ -57,12 +257,87 @@ impl_from_validator_input_default!(
     String, bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64
 );
 
+impl From<serde_json::Number> for ValidatorInput {
+    fn from(num: serde_json::Number) -> Self {
+        ValidatorInput(Box::new(validators::eq(num)))
+    }
+}
+
+impl From<i128> for ValidatorInput {
+    fn from(num: i128) -> Self {
+        ValidatorInput(Box::new(validators::eq_i128(num)))
+    }
+}
+
+impl From<u128> for ValidatorInput {
+    fn from(num: u128) -> Self {
+        ValidatorInput(Box::new(validators::eq_u128(num)))
+    }
+}
+
+impl<T> From<Option<T>> for ValidatorInput
+where
+    T: Into<Value> + Clone + 'static,
+{
+    fn from(expected: Option<T>) -> Self {
+        ValidatorInput(expected.into())
+    }
+}
+
+impl<T> From<Vec<T>> for ValidatorInput
+where
+    T: Into<Box<dyn Validator>>,
+{
+    fn from(expected: Vec<T>) -> Self {
+        ValidatorInput(expected.into())
+    }
+}
+
+impl<T> From<HashMap<String, T>> for ValidatorInput
+where
+    T: Into<Box<dyn Validator>>,
+{
+    fn from(expected: HashMap<String, T>) -> Self {
+        ValidatorInput(expected.into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for ValidatorInput
+where
+    Tz: chrono::TimeZone,
+{
+    fn from(expected: chrono::DateTime<Tz>) -> Self {
+        ValidatorInput(expected.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for ValidatorInput {
+    fn from(expected: time::OffsetDateTime) -> Self {
+        ValidatorInput(expected.into())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for ValidatorInput {
+    fn from(expected: uuid::Uuid) -> Self {
+        ValidatorInput(expected.into())
+    }
+}
+
 impl From<&str> for ValidatorInput {
     fn from(str_input: &str) -> Self {
         ValidatorInput(Box::new(validators::eq(String::from(str_input))))
     }
 }
 
+impl From<&str> for Box<dyn Validator> {
+    fn from(str_input: &str) -> Self {
+        Box::new(validators::eq(String::from(str_input)))
+    }
+}
+
 impl<T> From<T> for ValidatorInput
 where
     T: Validator + 'static,
@@ -72,40 +347,303 @@ where
     }
 }
 
+/// Load the snapshot of [assert_json_snapshot!](crate::assert_json_snapshot)
+/// as a validator, or write `actual` as the new snapshot and return `None`.
+pub fn load_snapshot(
+    path: impl AsRef<std::path::Path>,
+    actual: &Value,
+) -> Option<Box<dyn Validator>> {
+    crate::snapshot::load_or_write(path.as_ref(), actual)
+        .map(|snapshot| crate::snapshot::validator(&snapshot))
+}
+
+/// Validate each line of `text` against `validator`, and the number of lines
+/// against `count`, for [assert_ndjson!](crate::assert_ndjson). Return the
+/// report of the failures, if any.
+pub fn format_ndjson_errors<V>(
+    text: &str,
+    validator: &V,
+    count: Option<&dyn Validator>,
+) -> Option<String>
+where
+    V: Validator + ?Sized,
+{
+    let mut reports = vec![];
+    let mut lines = 0;
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        lines += 1;
+        match serde_json::from_str::<Value>(line) {
+            Ok(json) => {
+                let errors = validate_input(&json, validator);
+                if !errors.is_empty() {
+                    reports.push(format!(
+                        "NDJSON line {}:\n{}",
+                        index + 1,
+                        format_source_errors(&json, Some(line), errors)
+                    ));
+                }
+            }
+            Err(err) => reports.push(format!(
+                "NDJSON line {}:\nInvalid JSON text: {}\n",
+                index + 1,
+                err
+            )),
+        }
+    }
+
+    if let Some(count) = count {
+        let lines = Value::from(lines);
+        let errors = count.validate_all(&lines);
+        if !errors.is_empty() {
+            let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            reports.push(format!(
+                "Invalid NDJSON line count. {}\n",
+                messages.join(" ")
+            ));
+        }
+    }
+
+    if reports.is_empty() {
+        None
+    } else {
+        Some(reports.join("\n"))
+    }
+}
+
+/// Validate the JSON body of `response`, explaining the failure along with
+/// the status and the headers of the response.
+pub fn format_response_errors<V>(response: &crate::HttpResponse, validator: &V) -> Option<String>
+where
+    V: Validator + ?Sized,
+{
+    let report = if !response.is_json() {
+        match response.content_type() {
+            Some(content_type) => format!(
+                "Invalid content type. Expected a JSON response but got '{}'.",
+                content_type
+            ),
+            None => String::from("Missing content type. Expected a JSON response."),
+        }
+    } else {
+        let text = String::from_utf8_lossy(response.body());
+        match serde_json::from_str::<Value>(&text) {
+            Ok(json) => {
+                let errors = validate_input(&json, validator);
+                if errors.is_empty() {
+                    return None;
+                }
+                format_source_errors(&json, Some(&text), errors)
+            }
+            Err(err) => format!("Invalid JSON body: {}", err),
+        }
+    };
+    Some(format!("{}\n\n{}", report.trim_end(), response.summary()))
+}
+
+/// Validate `json`, reporting every error.
+///
+/// The `ASSERT_JSON_COVERAGE` environment variable opts into the coverage
+/// report of the fields of a valid `json` which no validator checked:
+///
+/// - `deny` makes each of these fields an error, failing the assertion;
+/// - any other value prints their JSON pointers to the standard error, as a
+///   single `Unvalidated JSON fields: ...` line which the test harness shows
+///   along with the output of the test.
+pub fn validate_input<'a, V>(json: &'a Value, validator: &V) -> Vec<Error<'a>>
+where
+    V: Validator + ?Sized,
+{
+    match std::env::var_os("ASSERT_JSON_COVERAGE") {
+        None => validator.validate_all(json),
+        Some(mode) => validate_covered(json, validator, mode == "deny"),
+    }
+}
+
+/// Validate `json` and report the fields no validator checked, as errors if
+/// `deny` is set.
+fn validate_covered<'a, V>(json: &'a Value, validator: &V, deny: bool) -> Vec<Error<'a>>
+where
+    V: Validator + ?Sized,
+{
+    match crate::unvalidated_fields(json, validator) {
+        Ok(fields) if deny => fields
+            .iter()
+            .filter_map(|field| json.pointer(field))
+            .map(|value| {
+                Error::InvalidValue(
+                    value,
+                    String::from("a value checked by a validator (ASSERT_JSON_COVERAGE=deny)"),
+                )
+            })
+            .collect(),
+        Ok(fields) => {
+            if !fields.is_empty() {
+                eprintln!("Unvalidated JSON fields: {}", fields.join(", "));
+            }
+            vec![]
+        }
+        Err(errors) => errors,
+    }
+}
+
+pub fn assertion_error<'a>(
+    json: &'a Value,
+    source: Option<&str>,
+    errors: Vec<Error<'a>>,
+) -> crate::AssertionError {
+    crate::AssertionError::new(json, source, errors)
+}
+
+/// Explain that `json`, parsed from `source`, unexpectedly matched `validator`.
+pub fn format_refutation<V>(json: &Value, source: Option<&str>, validator: &V) -> String
+where
+    V: Validator + ?Sized,
+{
+    format!(
+        "{}Matched expectation: {}",
+        format_source_errors(json, source, vec![Error::UnexpectedMatch(json)]),
+        validator.describe()
+    )
+}
+
 pub fn format_error<'a>(json: &'a Value, error: Error<'a>) -> String {
+    format_errors(json, vec![error])
+}
+
+/// Render each error as a diagnostic pointing into the serialized `json`.
+///
+/// With the `color` feature, mismatches are highlighted by kind and colors are
+/// only used when the standard error is a terminal.
+pub fn format_errors<'a>(json: &'a Value, errors: Vec<Error<'a>>) -> String {
+    format_source_errors(json, None, errors)
+}
+
+/// Same as [format_errors], also reporting the line and column of each error
+/// in the `source` text `json` was parsed from.
+pub fn format_source_errors<'a>(
+    json: &'a Value,
+    source: Option<&str>,
+    errors: Vec<Error<'a>>,
+) -> String {
+    let highlight = cfg!(feature = "color");
+    if highlight && !use_color() {
+        let mut writer = termcolor::NoColor::new(Vec::<u8>::new());
+        emit_errors(&mut writer, json, source, errors, false);
+        return String::from_utf8(writer.into_inner()).unwrap();
+    }
+
+    let mut writer = termcolor::Ansi::new(Vec::<u8>::new());
+    emit_errors(&mut writer, json, source, errors, highlight);
+    String::from_utf8(writer.into_inner()).unwrap()
+}
+
+fn emit_errors<'a>(
+    writer: &mut dyn termcolor::WriteColor,
+    json: &'a Value,
+    source: Option<&str>,
+    errors: Vec<Error<'a>>,
+    highlight: bool,
+) {
     let serializer = SpanSerializer::serialize(json);
 
     let mut files = SimpleFiles::new();
     let file = files.add("", serializer.serialized_json());
 
-    let diagnostic = Diagnostic::error()
-        .with_message("Invalid JSON")
-        .with_labels(vec![Label::primary(
-            file,
-            serializer.span(error.location()),
-        )
-        .with_message(error.to_string())]);
+    for error in errors {
+        let mut config = term::Config::default();
+        if highlight {
+            config
+                .styles
+                .primary_label_error
+                .set_fg(Some(highlight_color(&error)));
+        }
 
-    let output = Vec::<u8>::new();
-    let mut writer = termcolor::Ansi::new(output);
-    let config = term::Config::default();
+        let mut message = match error.pointer(json) {
+            Some(pointer) if !pointer.is_empty() => format!("Invalid JSON at {}", pointer),
+            _ => String::from("Invalid JSON"),
+        };
+        if let Some((line, column)) = source.and_then(|source| error.line_column(json, source)) {
+            message.push_str(&format!(" (line {}, column {})", line, column));
+        }
+        let mut diagnostic =
+            Diagnostic::error()
+                .with_message(message)
+                .with_labels(vec![Label::primary(
+                    file,
+                    serializer.span(error.location()),
+                )
+                .with_message(error.to_string())]);
+        if let Some(diff) = error.diff() {
+            let diff = if highlight {
+                highlight_diff(&diff)
+            } else {
+                diff
+            };
+            diagnostic = diagnostic.with_notes(vec![diff]);
+        }
 
-    term::emit(&mut writer, &config, &files, &diagnostic).unwrap();
+        term::emit(writer, &config, &files, &diagnostic).unwrap();
+    }
+}
 
-    String::from_utf8(writer.into_inner()).unwrap()
+/// Whether the panic message is displayed in a terminal which accepts colors.
+fn use_color() -> bool {
+    use std::io::IsTerminal;
+
+    std::env::var_os("NO_COLOR").is_none() && std::io::stderr().is_terminal()
+}
+
+/// Color of the label of an error: red for what is missing, green for what is
+/// unexpected and yellow for mismatching values.
+fn highlight_color(error: &Error) -> termcolor::Color {
+    match error {
+        Error::MissingObjectKey(_, _)
+        | Error::MissingObjectKeys(_, _)
+        | Error::MissingPointer(_, _) => termcolor::Color::Red,
+        Error::UnexpectedObjectKey(_, _) | Error::UnexpectedObjectKeys(_, _) => {
+            termcolor::Color::Green
+        }
+        Error::Labeled(_, err)
+        | Error::InvalidArrayElement(_, err)
+        | Error::InvalidObjectValue(_, err) => highlight_color(err),
+        _ => termcolor::Color::Yellow,
+    }
+}
+
+/// Color the removed lines of a unified diff in red and the added ones in green.
+fn highlight_diff(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            if line.starts_with("---") || line.starts_with("+++") {
+                format!("\x1b[1m{}\x1b[0m", line)
+            } else if line.starts_with('-') {
+                format!("\x1b[31m{}\x1b[0m", line)
+            } else if line.starts_with('+') {
+                format!("\x1b[32m{}\x1b[0m", line)
+            } else if line.starts_with("@@") {
+                format!("\x1b[36m{}\x1b[0m", line)
+            } else {
+                String::from(line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// Serialize a JSON [Value] and keeps the span information of each
 /// elements.
 #[derive(Default)]
-struct SpanSerializer {
+pub(crate) struct SpanSerializer {
     spans: BTreeMap<*const Value, Range<usize>>,
     json: String,
     current_ident: usize,
 }
 
 impl SpanSerializer {
-    fn serialize(input: &Value) -> SpanSerializer {
+    pub(crate) fn serialize(input: &Value) -> SpanSerializer {
         let mut serializer = SpanSerializer::default();
         serializer.serialize_recursive(input);
         serializer
@@ -120,7 +658,9 @@ impl SpanSerializer {
             serde_json::Value::Number(num_val) => {
                 self.json.push_str(&num_val.to_string());
             }
-            serde_json::Value::String(str_val) => self.json.push_str(&format!("\"{}\"", str_val)),
+            serde_json::Value::String(str_val) => self
+                .json
+                .push_str(&format!("\"{}\"", crate::truncate::display_str(str_val))),
             serde_json::Value::Array(arr_val) => {
                 self.json.push_str("[\n");
                 self.current_ident += 1;
@@ -162,11 +702,11 @@ impl SpanSerializer {
         self.json.push_str(&" ".repeat(self.current_ident * 4));
     }
 
-    fn serialized_json(&self) -> &str {
+    pub(crate) fn serialized_json(&self) -> &str {
         &self.json
     }
 
-    fn span(&self, val: &Value) -> Range<usize> {
+    pub(crate) fn span(&self, val: &Value) -> Range<usize> {
         self.spans
             .get(&(val as *const Value))
             .expect("expected span")
@@ -177,7 +717,8 @@ impl SpanSerializer {
 #[cfg(test)]
 mod tests {
     use super::SpanSerializer;
-    use crate::Value;
+    use crate::{Error, Value};
+    use codespan_reporting::term::termcolor;
     use indoc::indoc;
 
     #[test]
@@ -232,4 +773,51 @@ mod tests {
             serializer.serialized_json()
         )
     }
+
+    #[test]
+    fn highlight_color() {
+        let value = Value::Null;
+
+        assert_eq!(
+            termcolor::Color::Red,
+            super::highlight_color(&Error::MissingObjectKey(&value, String::from("key")))
+        );
+        assert_eq!(
+            termcolor::Color::Green,
+            super::highlight_color(&Error::InvalidArrayElement(
+                0,
+                Box::new(Error::UnexpectedObjectKey(&value, String::from("key")))
+            ))
+        );
+        assert_eq!(
+            termcolor::Color::Yellow,
+            super::highlight_color(&Error::InvalidValue(&value, String::from("5")))
+        );
+    }
+
+    #[test]
+    fn highlight_diff() {
+        assert_eq!(
+            "\x1b[1m--- expected\x1b[0m\n\x1b[31m-1\x1b[0m\n\x1b[32m+2\x1b[0m\n 3",
+            super::highlight_diff("--- expected\n-1\n+2\n 3")
+        );
+    }
+
+    #[test]
+    fn validate_covered() {
+        let json = serde_json::json!({"id": 5, "extra": true});
+        let validator = crate::validators::at("/id", crate::validators::eq(5));
+
+        assert_eq!(
+            Vec::<Error>::new(),
+            super::validate_covered(&json, &validator, false)
+        );
+        assert_eq!(
+            vec![Error::InvalidValue(
+                &json["extra"],
+                String::from("a value checked by a validator (ASSERT_JSON_COVERAGE=deny)")
+            )],
+            super::validate_covered(&json, &validator, true)
+        );
+    }
 }
@@ -0,0 +1,401 @@
+//! Match request bodies received by mock servers.
+
+use crate::{check_str, ErrorKind, OwnedError, Validator, Value};
+
+/// Matcher of JSON request bodies, for mock servers matching requests against
+/// expectations.
+///
+/// Mock servers share their matchers between threads while validators are
+/// not `Send`, so the matcher holds a function building the validator for
+/// each body to match. [body_matcher!](crate::body_matcher) builds one from
+/// the same expectation syntax as [assert_json!](crate::assert_json).
+///
+/// ```
+/// use assert_json::{body_matcher, validators};
+///
+/// let matcher = body_matcher!({ "name": validators::is_string(), .. });
+///
+/// assert!(matcher.matches(br#"{"name": "charlesvdv", "admin": false}"#));
+/// assert!(!matcher.matches(br#"{"name": 5}"#));
+/// assert!(!matcher.matches(b"name=charlesvdv"));
+/// ```
+///
+/// Mock servers taking function pointers as custom matchers can't capture a
+/// matcher: keep it in a static instead.
+///
+/// ```
+/// use assert_json::{body_matcher, validators, BodyMatcher};
+/// use std::sync::LazyLock;
+///
+/// static ORDER: LazyLock<BodyMatcher> =
+///     LazyLock::new(|| body_matcher!({ "quantity": validators::gt(0), .. }));
+///
+/// fn is_order(body: &[u8]) -> bool {
+///     ORDER.matches(body)
+/// }
+///
+/// let matcher: fn(&[u8]) -> bool = is_order;
+/// assert!(matcher(br#"{"sku": "SKU-42", "quantity": 3}"#));
+/// ```
+pub struct BodyMatcher {
+    validator: Box<dyn Fn() -> Box<dyn Validator> + Send + Sync>,
+}
+
+impl BodyMatcher {
+    /// Create a matcher validating bodies with the validators built by
+    /// `validator`.
+    pub fn new<F, V>(validator: F) -> BodyMatcher
+    where
+        F: Fn() -> V + Send + Sync + 'static,
+        V: Validator + 'static,
+    {
+        BodyMatcher {
+            validator: Box::new(move || Box::new(validator())),
+        }
+    }
+
+    /// Validate the body, returning the errors of a mismatch.
+    ///
+    /// A body which is not UTF-8 or not JSON is reported as a single
+    /// [ErrorKind::InvalidJson] error.
+    pub fn check(&self, body: &[u8]) -> Result<(), Vec<OwnedError>> {
+        let text = std::str::from_utf8(body).map_err(|err| {
+            vec![OwnedError {
+                kind: ErrorKind::InvalidJson,
+                message: format!("Invalid JSON text: {}", err),
+                pointer: None,
+                actual: Value::String(String::from_utf8_lossy(body).into_owned()),
+            }]
+        })?;
+        check_str(text, &*(self.validator)())
+    }
+
+    /// Whether the body matches.
+    pub fn matches(&self, body: &[u8]) -> bool {
+        self.check(body).is_ok()
+    }
+
+    /// Convert into a predicate on bodies, like the custom request matchers
+    /// of mock servers, which logs why a body doesn't match as a warning of
+    /// the `log` crate.
+    ///
+    /// Mock servers usually only answer that no mock matched a request: the
+    /// log tells which expectation rejected the body and where.
+    ///
+    /// ```
+    /// use assert_json::body_matcher;
+    ///
+    /// let matches = body_matcher!({ "id": 5 }).into_predicate();
+    /// assert!(matches(br#"{"id": 5}"#));
+    /// // Logs that the body is invalid at /id.
+    /// assert!(!matches(br#"{"id": 6}"#));
+    /// ```
+    pub fn into_predicate(self) -> impl Fn(&[u8]) -> bool + Send + Sync + 'static {
+        move |body| match self.check(body) {
+            Ok(()) => true,
+            Err(errors) => {
+                log::warn!("{}", self.report(&errors));
+                false
+            }
+        }
+    }
+
+    /// Convert into a request matcher of `mockito`, for
+    /// `Mock::match_request`, which logs why a body doesn't match like
+    /// [into_predicate](BodyMatcher::into_predicate).
+    ///
+    /// ```
+    /// use assert_json::{body_matcher, validators};
+    ///
+    /// let mut server = mockito::Server::new();
+    /// let mock = server
+    ///     .mock("POST", "/orders")
+    ///     .match_request(body_matcher!({ "quantity": validators::gt(0), .. }).into_mockito_matcher())
+    ///     .with_status(201)
+    ///     .create();
+    /// ```
+    #[cfg(feature = "mockito")]
+    pub fn into_mockito_matcher(
+        self,
+    ) -> impl Fn(&mockito::Request) -> bool + Send + Sync + 'static {
+        let matches = self.into_predicate();
+        move |request| request.body().is_ok_and(|body| matches(body))
+    }
+
+    /// Convert into a request matcher of `httpmock`, for `when.is_true` or
+    /// the older `when.matches`, which logs why a body doesn't match like
+    /// [into_predicate](BodyMatcher::into_predicate).
+    ///
+    /// ```
+    /// use assert_json::{body_matcher, validators};
+    /// use httpmock::{Method::POST, MockServer};
+    ///
+    /// let server = MockServer::start();
+    /// let mock = server.mock(|when, then| {
+    ///     when.method(POST)
+    ///         .path("/orders")
+    ///         .is_true(body_matcher!({ "quantity": validators::gt(0), .. }).into_httpmock_matcher());
+    ///     then.status(201);
+    /// });
+    /// ```
+    #[cfg(feature = "httpmock")]
+    pub fn into_httpmock_matcher(
+        self,
+    ) -> impl Fn(&httpmock::HttpMockRequest) -> bool + Send + Sync + 'static {
+        let matches = self.into_predicate();
+        move |request| matches(request.body_ref())
+    }
+
+    /// Explain why a body doesn't match.
+    fn report(&self, errors: &[OwnedError]) -> String {
+        let mut report = format!(
+            "request body doesn't match {}:",
+            (self.validator)().describe()
+        );
+        for error in errors {
+            report.push_str(&format!("\n  {}", error));
+        }
+        report
+    }
+}
+
+impl std::fmt::Debug for BodyMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BodyMatcher")
+            .field("expectation", &(self.validator)().describe())
+            .finish()
+    }
+}
+
+/// Match the bodies of the requests received by a `wiremock` mock server.
+///
+/// ```
+/// use assert_json::{body_matcher, validators};
+/// use wiremock::matchers::method;
+/// use wiremock::{Mock, ResponseTemplate};
+///
+/// let mock = Mock::given(method("POST"))
+///     .and(body_matcher!({ "quantity": validators::gt(0), .. }))
+///     .respond_with(ResponseTemplate::new(201));
+/// ```
+#[cfg(feature = "wiremock")]
+impl wiremock::Match for BodyMatcher {
+    fn matches(&self, request: &wiremock::Request) -> bool {
+        BodyMatcher::matches(self, &request.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BodyMatcher;
+    use crate::{validators, ErrorKind};
+
+    #[test]
+    fn matches() {
+        let matcher = BodyMatcher::new(|| validators::at("/id", validators::gt(0)));
+
+        assert!(matcher.matches(br#"{"id": 5}"#));
+        assert!(!matcher.matches(br#"{"id": 0}"#));
+    }
+
+    #[test]
+    fn check() {
+        let matcher = BodyMatcher::new(|| validators::at("/id", validators::gt(0)));
+
+        let errors = matcher.check(br#"{"id": 0}"#).unwrap_err();
+        assert_eq!(Some("/id"), errors[0].pointer());
+
+        let errors = matcher.check(b"{").unwrap_err();
+        assert_eq!(ErrorKind::InvalidJson, errors[0].kind());
+
+        let errors = matcher.check(&[0xff]).unwrap_err();
+        assert_eq!(ErrorKind::InvalidJson, errors[0].kind());
+    }
+
+    #[test]
+    fn same_as() {
+        let matcher =
+            BodyMatcher::new(|| validators::at("/confirmation", validators::same_as("/password")));
+
+        assert!(matcher.matches(br#"{"password": "p", "confirmation": "p"}"#));
+        assert!(!matcher.matches(br#"{"password": "p", "confirmation": "q"}"#));
+    }
+
+    /// Logger keeping the messages, to check what matchers log.
+    struct Logger(std::sync::Mutex<Vec<String>>);
+
+    impl log::Log for Logger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: Logger = Logger(std::sync::Mutex::new(Vec::new()));
+
+    fn capture_logs() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Warn);
+    }
+
+    /// Whether a message containing `text` was logged.
+    fn logged(text: &str) -> bool {
+        LOGGER
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|message| message.contains(text))
+    }
+
+    #[test]
+    fn into_predicate() {
+        let matches =
+            BodyMatcher::new(|| validators::at("/id", validators::gt(0))).into_predicate();
+        capture_logs();
+
+        assert!(matches(br#"{"id": 5}"#));
+        assert!(!matches(br#"{"id": 0}"#));
+        assert!(logged(
+            "at /id: Invalid value. Expected number > 0 but got 0."
+        ));
+    }
+
+    #[test]
+    fn report() {
+        let matcher = BodyMatcher::new(|| validators::at("/id", validators::is_number()));
+        let errors = matcher.check(br#"{"id": "5"}"#).unwrap_err();
+
+        assert_eq!(
+            "request body doesn't match a value with a number at '/id':\n  \
+             at /id: Invalid type. Expected number but got string.",
+            matcher.report(&errors)
+        );
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        static MATCHER: std::sync::LazyLock<BodyMatcher> = std::sync::LazyLock::new(|| {
+            BodyMatcher::new(|| validators::at("/id", validators::gt(0)))
+        });
+
+        let threads: Vec<_> = (0..4)
+            .map(|id| {
+                std::thread::spawn(move || {
+                    MATCHER.matches(format!(r#"{{"id": {}}}"#, id).as_bytes())
+                })
+            })
+            .collect();
+        let matches: Vec<bool> = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect();
+
+        assert_eq!(vec![false, true, true, true], matches);
+    }
+
+    #[test]
+    fn debug() {
+        let matcher = BodyMatcher::new(validators::is_string);
+
+        assert_eq!(
+            "BodyMatcher { expectation: \"a string\" }",
+            format!("{:?}", matcher)
+        );
+    }
+
+    /// Send a POST request with the body to the server, returning the status.
+    #[cfg(any(feature = "httpmock", feature = "mockito", feature = "wiremock"))]
+    fn post(address: &str, body: &str) -> u16 {
+        use std::io::{Read, Write};
+
+        let mut stream = std::net::TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /orders HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            address,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response[9..12].parse().unwrap()
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "wiremock")]
+    async fn wiremock() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(crate::body_matcher!({ "quantity": validators::gt(0), .. }))
+            .respond_with(ResponseTemplate::new(201))
+            .mount(&server)
+            .await;
+
+        let address = server.address().to_string();
+        let statuses = tokio::task::spawn_blocking(move || {
+            [
+                post(&address, r#"{"sku": "SKU-42", "quantity": 3}"#),
+                post(&address, r#"{"sku": "SKU-42", "quantity": 0}"#),
+            ]
+        })
+        .await
+        .unwrap();
+        assert_eq!([201, 404], statuses);
+    }
+
+    #[test]
+    #[cfg(feature = "mockito")]
+    fn mockito() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/orders")
+            .match_request(
+                crate::body_matcher!({ "quantity": validators::gt(0), .. }).into_mockito_matcher(),
+            )
+            .with_status(201)
+            .create();
+        capture_logs();
+
+        let address = server.host_with_port();
+        assert_eq!(201, post(&address, r#"{"sku": "SKU-42", "quantity": 3}"#));
+        assert_eq!(501, post(&address, r#"{"sku": "SKU-7", "quantity": 0}"#));
+        assert!(logged(
+            "at /quantity: Invalid value. Expected number > 0 but got 0."
+        ));
+        mock.assert();
+    }
+
+    #[test]
+    #[cfg(feature = "httpmock")]
+    fn httpmock() {
+        use httpmock::{Method::POST, MockServer};
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/orders").is_true(
+                crate::body_matcher!({ "quantity": validators::gt(0), .. }).into_httpmock_matcher(),
+            );
+            then.status(201);
+        });
+        capture_logs();
+
+        let address = server.address().to_string();
+        assert_eq!(201, post(&address, r#"{"sku": "SKU-42", "quantity": 3}"#));
+        assert_eq!(404, post(&address, r#"{"sku": "SKU-9", "quantity": -1}"#));
+        assert!(logged(
+            "at /quantity: Invalid value. Expected number > 0 but got -1."
+        ));
+        mock.assert();
+    }
+}
@@ -0,0 +1,458 @@
+//! Validation of responses against an [OpenAPI](https://spec.openapis.org/oas/latest.html)
+//! specification, to keep contract tests in sync with the spec.
+//!
+//! See [assert_openapi!](crate::assert_openapi).
+
+use crate::validators::{self, escape_token};
+use crate::{Validator, Value};
+use std::path::Path;
+
+/// Load an OpenAPI specification from a JSON file. With the `yaml` feature,
+/// `.yaml` and `.yml` files are parsed as YAML.
+///
+/// Panics if the file cannot be read or parsed.
+pub fn load(path: impl AsRef<Path>) -> Value {
+    let path = path.as_ref();
+    #[cfg(feature = "yaml")]
+    if matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml" | "yml")
+    ) {
+        let text = std::fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+        return crate::formats::from_yaml_str(&text)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {}", path.display(), err));
+    }
+    crate::fixture::load(path)
+}
+
+/// Match if the value is a valid body of the response of the operation with
+/// the status and content type, according to the specification.
+///
+/// The operation is a method and a path, like `"GET /users/{id}"`: the path is
+/// either a path of the specification or a concrete path matching one of its
+/// templates, like `"/users/5"`. The response of the status is looked up
+/// first, then the range of the status, like `2XX`, and finally the `default`
+/// response. The schema of the response is compiled like
+/// [json_schema](crate::validators::json_schema), `$ref`s being resolved
+/// against the specification. Swagger 2.0 response schemas are supported too.
+///
+/// Panics if the specification has no such response.
+///
+/// ```
+/// use assert_json::{assert_json, openapi};
+///
+/// let spec = serde_json::json!({
+///     "openapi": "3.1.0",
+///     "paths": {
+///         "/users/{id}": {
+///             "get": {
+///                 "responses": {
+///                     "200": {
+///                         "content": {
+///                             "application/json": {
+///                                 "schema": { "$ref": "#/components/schemas/User" }
+///                             }
+///                         }
+///                     }
+///                 }
+///             }
+///         }
+///     },
+///     "components": {
+///         "schemas": {
+///             "User": {
+///                 "type": "object",
+///                 "required": ["id"],
+///                 "properties": { "id": { "type": "integer" } }
+///             }
+///         }
+///     }
+/// });
+///
+/// assert_json!(
+///     r#"{"id": 5}"#,
+///     openapi::response(&spec, "GET /users/5", 200, "application/json")
+/// );
+/// ```
+pub fn response(spec: &Value, operation: &str, status: u16, content_type: &str) -> impl Validator {
+    let (method, path) = operation.split_once(' ').unwrap_or_else(|| {
+        panic!(
+            "invalid operation '{}': expected a method and a path, like 'GET /users/{{id}}'",
+            operation
+        )
+    });
+    let template = find_path(spec, path.trim())
+        .unwrap_or_else(|| panic!("no path matching '{}' in the OpenAPI spec", path.trim()));
+    let mut pointer = format!(
+        "/paths/{}/{}",
+        escape_token(&template),
+        method.to_ascii_lowercase()
+    );
+    if spec.pointer(&pointer).is_none() {
+        panic!("no operation '{}' in the OpenAPI spec", operation);
+    }
+
+    pointer.push_str("/responses");
+    let responses = spec.pointer(&pointer).unwrap_or_else(|| {
+        invalid(&pointer, "missing responses");
+    });
+    let key = [
+        status.to_string(),
+        format!("{}XX", status / 100),
+        String::from("default"),
+    ]
+    .into_iter()
+    .find(|key| responses.get(key).is_some())
+    .unwrap_or_else(|| {
+        panic!(
+            "no response {} to '{}' in the OpenAPI spec",
+            status, operation
+        )
+    });
+    pointer = resolve(spec, format!("{}/{}", pointer, key));
+
+    let response = spec.pointer(&pointer).unwrap();
+    let pointer = match (response.get("content"), response.get("schema")) {
+        (Some(content), _) => {
+            let media_type = find_media_type(content, content_type).unwrap_or_else(|| {
+                panic!(
+                    "no {} content in the response {} to '{}' in the OpenAPI spec",
+                    content_type, status, operation
+                )
+            });
+            format!("{}/content/{}/schema", pointer, escape_token(&media_type))
+        }
+        // Swagger 2.0 responses have a single schema.
+        (None, Some(_)) => format!("{}/schema", pointer),
+        (None, None) => panic!(
+            "the response {} to '{}' has no content in the OpenAPI spec",
+            status, operation
+        ),
+    };
+    if spec.pointer(&pointer).is_none() {
+        invalid(&pointer, "missing schema");
+    }
+
+    let draft = draft(spec);
+    let spec = with_nullable_types(spec.clone());
+    validators::described(
+        format!(
+            "a body of the response {} to '{}' ({})",
+            status, operation, content_type
+        ),
+        validators::json_subschema(&spec, &pointer, draft, None, export(&spec, &pointer)),
+    )
+}
+
+/// The draft of the schemas: Swagger 2.0 and OpenAPI 3.0 extend the draft 4,
+/// OpenAPI 3.1 uses the draft 2020-12.
+fn draft(spec: &Value) -> jsonschema::Draft {
+    match spec.get("openapi").and_then(Value::as_str) {
+        Some(version) if !version.starts_with("3.0") => jsonschema::Draft::Draft202012,
+        _ => jsonschema::Draft::Draft4,
+    }
+}
+
+/// Add `null` to the types of the schemas marked with the `nullable`
+/// keyword of OpenAPI 3.0.
+fn with_nullable_types(mut spec: Value) -> Value {
+    match &mut spec {
+        Value::Object(object) => {
+            if object.get("nullable") == Some(&Value::Bool(true)) {
+                if let Some(Value::String(name)) = object.get("type") {
+                    let types = serde_json::json!([name, "null"]);
+                    object.insert(String::from("type"), types);
+                }
+            }
+            for value in object.values_mut() {
+                *value = with_nullable_types(std::mem::take(value));
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                *value = with_nullable_types(std::mem::take(value));
+            }
+        }
+        _ => {}
+    }
+    spec
+}
+
+fn invalid(pointer: &str, message: &str) -> ! {
+    panic!("invalid OpenAPI spec at '#{}': {}", pointer, message)
+}
+
+/// Follow the `$ref`s of the object at the pointer.
+fn resolve(spec: &Value, mut pointer: String) -> String {
+    while let Some(reference) = spec.pointer(&pointer).and_then(|value| value.get("$ref")) {
+        let target = reference
+            .as_str()
+            .and_then(|reference| reference.strip_prefix('#'))
+            .unwrap_or_else(|| invalid(&pointer, "only references within the spec are supported"));
+        if spec.pointer(target).is_none() {
+            invalid(&pointer, &format!("no object at '#{}'", target));
+        }
+        pointer = String::from(target);
+    }
+    pointer
+}
+
+/// Find the path of the specification matching `path`, preferring exact
+/// matches, then the templates with the fewest parameters.
+fn find_path(spec: &Value, path: &str) -> Option<String> {
+    let paths = spec.get("paths")?.as_object()?;
+    if paths.contains_key(path) {
+        return Some(String::from(path));
+    }
+    let segments: Vec<&str> = path.split('/').collect();
+    paths
+        .keys()
+        .filter_map(|template| {
+            let parts: Vec<&str> = template.split('/').collect();
+            if parts.len() != segments.len() {
+                return None;
+            }
+            let mut parameters = 0;
+            for (part, segment) in parts.iter().zip(&segments) {
+                if part.starts_with('{') && part.ends_with('}') && !segment.is_empty() {
+                    parameters += 1;
+                } else if part != segment {
+                    return None;
+                }
+            }
+            Some((parameters, template))
+        })
+        .min_by_key(|(parameters, _)| *parameters)
+        .map(|(_, template)| template.clone())
+}
+
+/// Find the media type of the content matching `content_type`, ignoring
+/// parameters like `charset` and falling back to wildcards like `application/*`.
+fn find_media_type(content: &Value, content_type: &str) -> Option<String> {
+    let content = content.as_object()?;
+    let essence = |media_type: &str| {
+        let essence = media_type.split(';').next().unwrap_or_default();
+        essence.trim().to_ascii_lowercase()
+    };
+    let expected = essence(content_type);
+    let range = match expected.split_once('/') {
+        Some((kind, _)) => format!("{}/*", kind),
+        None => String::from("*/*"),
+    };
+    let media_type = [expected.as_str(), range.as_str(), "*/*"]
+        .into_iter()
+        .find_map(|expected| {
+            content
+                .keys()
+                .find(|media_type| essence(media_type) == expected)
+        })
+        .cloned();
+    media_type
+}
+
+/// The schema at the pointer, along with the reusable schemas it may refer to.
+fn export(spec: &Value, pointer: &str) -> Value {
+    let mut schema = spec.pointer(pointer).unwrap().clone();
+    if let Value::Object(object) = &mut schema {
+        for key in ["components", "definitions"] {
+            if let Some(value) = spec.get(key) {
+                object.entry(key).or_insert_with(|| value.clone());
+            }
+        }
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+    use serde_json::{json, Value};
+    use std::path::Path;
+
+    fn spec() -> Value {
+        json!({
+            "openapi": "3.0.3",
+            "paths": {
+                "/users": {
+                    "get": {
+                        "responses": {
+                            "2XX": {
+                                "content": {
+                                    "application/json; charset=utf-8": {
+                                        "schema": {
+                                            "type": "array",
+                                            "items": { "$ref": "#/components/schemas/User" }
+                                        }
+                                    }
+                                }
+                            },
+                            "default": { "$ref": "#/components/responses/Error" }
+                        }
+                    }
+                },
+                "/users/{id}": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/User" }
+                                    }
+                                }
+                            },
+                            "204": { "description": "no content" }
+                        }
+                    }
+                },
+                "/users/me": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/*": { "schema": { "const": "me" } }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "User": {
+                        "type": "object",
+                        "required": ["id", "name"],
+                        "properties": {
+                            "id": { "type": "integer", "minimum": 1 },
+                            "name": { "type": "string", "nullable": true }
+                        }
+                    }
+                },
+                "responses": {
+                    "Error": {
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "type": "object",
+                                    "required": ["error"]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn response() {
+        let validator = super::response(&spec(), "GET /users/{id}", 200, "application/json");
+
+        assert_eq!(Ok(()), validator.validate(&json!({"id": 5, "name": "n"})));
+        assert_eq!(Ok(()), validator.validate(&json!({"id": 5, "name": null})));
+        let value = json!({"id": 0, "name": "n"});
+        assert!(matches!(
+            validator.validate(&value),
+            Err(Error::InvalidValue(location, _)) if std::ptr::eq(location, &value["id"])
+        ));
+        assert_eq!(
+            "a body of the response 200 to 'GET /users/{id}' (application/json)",
+            validator.describe()
+        );
+    }
+
+    #[test]
+    fn concrete_path() {
+        let spec = spec();
+
+        let validator = super::response(&spec, "GET /users/5", 200, "application/json");
+        assert_eq!(Ok(()), validator.validate(&json!({"id": 5, "name": "n"})));
+
+        let validator = super::response(&spec, "get /users/me", 200, "application/json");
+        assert_eq!(Ok(()), validator.validate(&json!("me")));
+    }
+
+    #[test]
+    fn status_range_and_default() {
+        let spec = spec();
+
+        let validator = super::response(&spec, "GET /users", 201, "application/json");
+        assert_eq!(Ok(()), validator.validate(&json!([{"id": 1, "name": "n"}])));
+        assert!(validator.validate(&json!([{"id": 1}])).is_err());
+
+        let validator = super::response(&spec, "GET /users", 500, "application/json");
+        assert_eq!(Ok(()), validator.validate(&json!({"error": "boom"})));
+        assert!(validator.validate(&json!({})).is_err());
+    }
+
+    #[test]
+    fn swagger() {
+        let spec = json!({
+            "swagger": "2.0",
+            "paths": {
+                "/ping": {
+                    "get": {
+                        "responses": {
+                            "200": { "schema": { "$ref": "#/definitions/Pong" } }
+                        }
+                    }
+                }
+            },
+            "definitions": { "Pong": { "type": "string" } }
+        });
+        let validator = super::response(&spec, "GET /ping", 200, "application/json");
+
+        assert_eq!(Ok(()), validator.validate(&json!("pong")));
+        assert!(validator.validate(&json!(1)).is_err());
+    }
+
+    #[test]
+    fn to_json_schema() {
+        let validator = super::response(&spec(), "GET /users/{id}", 200, "application/json");
+        let schema = validator.to_json_schema();
+
+        assert_eq!(json!("#/components/schemas/User"), schema["$ref"]);
+        assert_eq!(
+            json!("object"),
+            schema["components"]["schemas"]["User"]["type"]
+        );
+    }
+
+    #[test]
+    fn load() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/openapi.json");
+        let spec = super::load(path);
+
+        assert_eq!(json!("3.1.0"), spec["openapi"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no operation 'POST /users/{id}' in the OpenAPI spec")]
+    fn missing_operation() {
+        super::response(&spec(), "POST /users/{id}", 200, "application/json");
+    }
+
+    #[test]
+    #[should_panic(expected = "no path matching '/groups' in the OpenAPI spec")]
+    fn missing_path() {
+        super::response(&spec(), "GET /groups", 200, "application/json");
+    }
+
+    #[test]
+    #[should_panic(expected = "no response 404 to 'GET /users/{id}' in the OpenAPI spec")]
+    fn missing_response() {
+        super::response(&spec(), "GET /users/{id}", 404, "application/json");
+    }
+
+    #[test]
+    #[should_panic(expected = "the response 204 to 'GET /users/{id}' has no content")]
+    fn missing_content() {
+        super::response(&spec(), "GET /users/{id}", 204, "application/json");
+    }
+
+    #[test]
+    #[should_panic(expected = "no text/plain content in the response 200")]
+    fn missing_media_type() {
+        super::response(&spec(), "GET /users/{id}", 200, "text/plain");
+    }
+}
@@ -0,0 +1,107 @@
+use crate::Value;
+
+/// Build the [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) operations
+/// transforming `actual`, located at the JSON `pointer`, into `expected`.
+pub(crate) fn json_patch(pointer: &str, actual: &Value, expected: &Value) -> Vec<Value> {
+    let mut operations = vec![];
+    diff(pointer, actual, expected, &mut operations);
+    operations
+}
+
+fn diff(pointer: &str, actual: &Value, expected: &Value, operations: &mut Vec<Value>) {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                operations.push(serde_json::json!({"op": "remove", "path": child(pointer, key)}));
+            }
+            for (key, expected_value) in expected {
+                match actual.get(key) {
+                    Some(actual_value) => diff(
+                        &child(pointer, key),
+                        actual_value,
+                        expected_value,
+                        operations,
+                    ),
+                    None => operations.push(serde_json::json!({
+                        "op": "add",
+                        "path": child(pointer, key),
+                        "value": expected_value,
+                    })),
+                }
+            }
+        }
+        (Value::Array(actual), Value::Array(expected)) => {
+            for (index, (actual_value, expected_value)) in actual.iter().zip(expected).enumerate() {
+                diff(
+                    &child(pointer, &index.to_string()),
+                    actual_value,
+                    expected_value,
+                    operations,
+                );
+            }
+            // Remove from the end so that the indices stay valid.
+            for index in (expected.len()..actual.len()).rev() {
+                operations.push(serde_json::json!({
+                    "op": "remove",
+                    "path": child(pointer, &index.to_string()),
+                }));
+            }
+            for (index, expected_value) in expected.iter().enumerate().skip(actual.len()) {
+                operations.push(serde_json::json!({
+                    "op": "add",
+                    "path": child(pointer, &index.to_string()),
+                    "value": expected_value,
+                }));
+            }
+        }
+        _ if actual != expected => operations.push(serde_json::json!({
+            "op": "replace",
+            "path": pointer,
+            "value": expected,
+        })),
+        _ => {}
+    }
+}
+
+fn child(pointer: &str, token: &str) -> String {
+    format!("{}/{}", pointer, crate::validators::escape_token(token))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn json_patch() {
+        let actual = serde_json::json!({"id": 1, "tags": ["a", "b", "c"], "old": true, "same": 0});
+        let expected = serde_json::json!({"id": 2, "tags": ["a"], "a/b": null, "same": 0});
+
+        assert_eq!(
+            vec![
+                serde_json::json!({"op": "remove", "path": "/root/old"}),
+                serde_json::json!({"op": "add", "path": "/root/a~1b", "value": null}),
+                serde_json::json!({"op": "replace", "path": "/root/id", "value": 2}),
+                serde_json::json!({"op": "remove", "path": "/root/tags/2"}),
+                serde_json::json!({"op": "remove", "path": "/root/tags/1"}),
+            ],
+            super::json_patch("/root", &actual, &expected)
+        );
+    }
+
+    #[test]
+    fn json_patch_array_growth() {
+        assert_eq!(
+            vec![
+                serde_json::json!({"op": "replace", "path": "/0", "value": 3}),
+                serde_json::json!({"op": "add", "path": "/1", "value": 4}),
+            ],
+            super::json_patch("", &serde_json::json!([1]), &serde_json::json!([3, 4]))
+        );
+    }
+
+    #[test]
+    fn json_patch_root() {
+        assert_eq!(
+            vec![serde_json::json!({"op": "replace", "path": "", "value": [1]})],
+            super::json_patch("", &serde_json::json!({}), &serde_json::json!([1]))
+        );
+    }
+}
@@ -0,0 +1,389 @@
+//! HTTP responses whose JSON body is validated along with their metadata.
+
+/// An HTTP response, as received by a test client, whose JSON body can be
+/// validated with [assert_json_response!](crate::assert_json_response).
+///
+/// Build one from the status, headers and collected body of the response of
+/// any HTTP client: the failures of the assertion report the status and the
+/// headers, which often explain an unexpected body.
+///
+/// ```
+/// use assert_json::{assert_json_response, HttpResponse};
+///
+/// let response = HttpResponse::new(200, r#"{"id": 5}"#)
+///     .header("Content-Type", "application/json; charset=utf-8");
+/// assert_json_response!(response, { "id": 5 });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Create a response with the status and the body, without headers.
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: vec![],
+            body: body.into(),
+        }
+    }
+
+    /// Create a response from its parts, like those of an `http::Response`
+    /// once its body is collected: the headers are pairs of names and values,
+    /// which `&http::HeaderMap` iterates over.
+    ///
+    /// Header values which are not UTF-8 are converted lossily.
+    ///
+    /// ```
+    /// use assert_json::{assert_json_response, HttpResponse};
+    ///
+    /// let headers = [("content-type", b"application/json".as_slice())];
+    /// let response = HttpResponse::from_parts(200, headers, r#"{"id": 5}"#);
+    /// assert_json_response!(response, { "id": 5 });
+    /// ```
+    pub fn from_parts<I, K, V>(status: u16, headers: I, body: impl Into<Vec<u8>>) -> HttpResponse
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        HttpResponse {
+            status,
+            headers: headers
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = String::from_utf8_lossy(value.as_ref()).into_owned();
+                    (String::from(name.as_ref()), value)
+                })
+                .collect(),
+            body: body.into(),
+        }
+    }
+
+    /// Add a header.
+    pub fn header(mut self, name: &str, value: &str) -> HttpResponse {
+        self.headers.push((String::from(name), String::from(value)));
+        self
+    }
+
+    /// The status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The headers, in the order they were added.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// The value of the `Content-Type` header, if any.
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The body.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Whether the content type is JSON, like `application/json` or
+    /// `application/problem+json`.
+    pub(crate) fn is_json(&self) -> bool {
+        self.content_type().is_some_and(|content_type| {
+            let essence = content_type.split(';').next().unwrap_or_default();
+            let essence = essence.trim().to_ascii_lowercase();
+            essence == "application/json" || essence.ends_with("+json")
+        })
+    }
+
+    /// The status and the headers, to give the context of a failure.
+    pub(crate) fn summary(&self) -> String {
+        let mut summary = format!("Response status: {}\n", self.status);
+        for (name, value) in &self.headers {
+            summary.push_str(&format!("{}: {}\n", name, value));
+        }
+        summary
+    }
+}
+
+/// Validate the JSON body of `response` like
+/// [assert_json_response!](crate::assert_json_response), returning it.
+#[cfg(any(feature = "http", feature = "reqwest"))]
+fn assert_response<V>(response: HttpResponse, validator: V) -> HttpResponse
+where
+    V: crate::Validator,
+{
+    let validator = crate::validators::document(validator);
+    if let Some(report) = crate::macros_utils::format_response_errors(&response, &validator) {
+        panic!("{}", report);
+    }
+    response
+}
+
+/// Assertion on the JSON body of the responses of `reqwest`, async and
+/// blocking, and of `http::Response`s with the `http` feature.
+///
+/// The body is read, then validated like
+/// [assert_json_response!](crate::assert_json_response) does: the content
+/// type must be JSON and a failure reports the status and the headers of the
+/// response. The assertion returns the response as an [HttpResponse], to
+/// check the headers for instance.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn test() {
+/// use assert_json::{validators, ResponseJsonAssert};
+///
+/// let response = reqwest::get("http://localhost:8080/users/5").await.unwrap();
+/// response.assert_json(validators::at("/id", validators::eq(5))).await;
+///
+/// let response = reqwest::blocking::get("http://localhost:8080/users/5").unwrap();
+/// response.assert_json(validators::at("/id", validators::eq(5)));
+/// # }
+/// ```
+#[cfg(any(feature = "http", feature = "reqwest"))]
+pub trait ResponseJsonAssert {
+    /// The response once validated, or a future of it for async responses.
+    type Output;
+
+    /// Read the body and assert that it is JSON matching the validator.
+    ///
+    /// Panics if the body can't be read or doesn't match.
+    fn assert_json<V>(self, validator: V) -> Self::Output
+    where
+        V: crate::Validator + 'static;
+}
+
+#[cfg(feature = "http")]
+impl HttpResponse {
+    /// Collect the body of an `http::Response`, like the response of an axum
+    /// handler called with `tower::ServiceExt::oneshot`.
+    ///
+    /// ```
+    /// use assert_json::{assert_json_response, HttpResponse};
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let response = http::Response::builder()
+    ///     .header("Content-Type", "application/json")
+    ///     .body(http_body_util::Full::new(r#"{"id": 5}"#.as_bytes()))
+    ///     .unwrap();
+    ///
+    /// let response = HttpResponse::from_http(response).await.unwrap();
+    /// assert_json_response!(response, { "id": 5 });
+    /// # });
+    /// ```
+    pub async fn from_http<B>(response: http::Response<B>) -> Result<HttpResponse, B::Error>
+    where
+        B: http_body::Body,
+    {
+        let (parts, body) = response.into_parts();
+        let body = http_body_util::BodyExt::collect(body).await?.to_bytes();
+        Ok(HttpResponse::from_parts(
+            parts.status.as_u16(),
+            &parts.headers,
+            body.to_vec(),
+        ))
+    }
+}
+
+#[cfg(feature = "http")]
+impl<B> ResponseJsonAssert for http::Response<B>
+where
+    B: http_body::Body + 'static,
+    B::Error: std::fmt::Display,
+{
+    type Output = std::pin::Pin<Box<dyn std::future::Future<Output = HttpResponse>>>;
+
+    fn assert_json<V>(self, validator: V) -> Self::Output
+    where
+        V: crate::Validator + 'static,
+    {
+        Box::pin(async move {
+            let response = HttpResponse::from_http(self)
+                .await
+                .unwrap_or_else(|err| panic!("failed to read the response body: {}", err));
+            assert_response(response, validator)
+        })
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl ResponseJsonAssert for reqwest::Response {
+    type Output = std::pin::Pin<Box<dyn std::future::Future<Output = HttpResponse>>>;
+
+    fn assert_json<V>(self, validator: V) -> Self::Output
+    where
+        V: crate::Validator + 'static,
+    {
+        Box::pin(async move {
+            let status = self.status().as_u16();
+            let headers = self.headers().clone();
+            let body = self
+                .bytes()
+                .await
+                .unwrap_or_else(|err| panic!("failed to read the response body: {}", err));
+            assert_response(
+                HttpResponse::from_parts(status, &headers, body.to_vec()),
+                validator,
+            )
+        })
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl ResponseJsonAssert for reqwest::blocking::Response {
+    type Output = HttpResponse;
+
+    fn assert_json<V>(self, validator: V) -> Self::Output
+    where
+        V: crate::Validator + 'static,
+    {
+        let status = self.status().as_u16();
+        let headers = self.headers().clone();
+        let body = self
+            .bytes()
+            .unwrap_or_else(|err| panic!("failed to read the response body: {}", err));
+        assert_response(
+            HttpResponse::from_parts(status, &headers, body.to_vec()),
+            validator,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpResponse;
+
+    #[test]
+    fn content_type() {
+        let response = HttpResponse::new(200, "{}").header("content-type", "text/html");
+
+        assert_eq!(Some("text/html"), response.content_type());
+        assert!(!response.is_json());
+        assert_eq!(None, HttpResponse::new(200, "{}").content_type());
+    }
+
+    #[test]
+    fn from_parts() {
+        let headers = std::collections::BTreeMap::from([
+            (String::from("Content-Type"), b"application/json".to_vec()),
+            (String::from("X-Raw"), vec![0x61, 0xff]),
+        ]);
+        let response = HttpResponse::from_parts(201, &headers, b"{}".to_vec());
+
+        assert_eq!(
+            HttpResponse::new(201, "{}")
+                .header("Content-Type", "application/json")
+                .header("X-Raw", "a\u{fffd}"),
+            response
+        );
+    }
+
+    #[test]
+    fn is_json() {
+        let is_json = |content_type| {
+            HttpResponse::new(200, "{}")
+                .header("Content-Type", content_type)
+                .is_json()
+        };
+
+        assert!(is_json("application/json"));
+        assert!(is_json("Application/JSON; charset=utf-8"));
+        assert!(is_json("application/problem+json"));
+        assert!(!is_json("application/jsonl"));
+        assert!(!HttpResponse::new(200, "{}").is_json());
+    }
+
+    #[test]
+    fn summary() {
+        let response = HttpResponse::new(404, "")
+            .header("Content-Type", "application/json")
+            .header("X-Request-Id", "42");
+
+        assert_eq!(
+            "Response status: 404\nContent-Type: application/json\nX-Request-Id: 42\n",
+            response.summary()
+        );
+    }
+
+    /// Serve a single HTTP response, returning the URL of the server.
+    #[cfg(feature = "reqwest")]
+    fn serve(response: &'static str) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        url
+    }
+
+    #[cfg(feature = "reqwest")]
+    const USER: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                        Content-Length: 9\r\nConnection: close\r\n\r\n{\"id\": 5}";
+
+    #[test]
+    #[cfg(feature = "reqwest")]
+    fn reqwest_blocking() {
+        use super::ResponseJsonAssert;
+        use crate::validators;
+
+        let response = reqwest::blocking::get(serve(USER)).unwrap();
+        let response = response.assert_json(validators::at("/id", validators::eq(5)));
+        assert_eq!(Some("application/json"), response.content_type());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "reqwest")]
+    #[should_panic(expected = "Response status: 200\ncontent-type: application/json\n")]
+    async fn reqwest_async() {
+        use super::ResponseJsonAssert;
+        use crate::validators;
+
+        let response = reqwest::get(serve(USER)).await.unwrap();
+        response
+            .assert_json(validators::at("/id", validators::eq(6)))
+            .await;
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "http")]
+    async fn http() {
+        use super::ResponseJsonAssert;
+        use crate::validators;
+
+        let response = http::Response::builder()
+            .status(201)
+            .header("Content-Type", "application/json")
+            .body(http_body_util::Full::new(r#"{"id": 5}"#.as_bytes()))
+            .unwrap();
+        let response = response
+            .assert_json(validators::at("/id", validators::eq(5)))
+            .await;
+        assert_eq!(201, response.status());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "http")]
+    #[should_panic(expected = "Response status: 404\ncontent-type: text/plain\n")]
+    async fn http_content_type() {
+        use super::ResponseJsonAssert;
+
+        let response = http::Response::builder()
+            .status(404)
+            .header("Content-Type", "text/plain")
+            .body(http_body_util::Full::new("not found".as_bytes()))
+            .unwrap();
+        response.assert_json(crate::validators::any()).await;
+    }
+}
@@ -0,0 +1,348 @@
+//! Minimize documents failing a validator.
+//!
+//! Documents found by fuzzing or property-based tests are usually large while
+//! only a small part of them makes a validator fail. Shrinking removes object
+//! keys and array elements, and simplifies values, as long as the validation
+//! keeps failing with the same kind of error at the same place.
+
+use crate::{check, ErrorKind, Validator, Value};
+use serde_json::Map;
+use std::ops::Range;
+
+/// Upper bound of simplification steps, in case of validators which don't
+/// fail consistently.
+const MAX_STEPS: usize = 10_000;
+
+/// Minimize `actual`, which fails `validator`, into a smaller document which
+/// fails with the same kind of error at the same place, or `None` if `actual`
+/// matches.
+///
+/// The place of the error is its JSON pointer, array indices aside since
+/// removing elements moves the failing one.
+///
+/// ```
+/// use assert_json::{shrink, validators};
+///
+/// let actual = serde_json::json!({
+///     "id": 5,
+///     "tags": ["a", "b", 7, "c"],
+///     "owner": {"name": "charlesvdv"},
+/// });
+/// let validator = validators::at("/tags", validators::array_for_each(validators::is_string()));
+///
+/// assert_eq!(Some(serde_json::json!({"tags": [null]})), shrink(&actual, &validator));
+/// ```
+pub fn shrink<V>(actual: &Value, validator: &V) -> Option<Value>
+where
+    V: Validator + ?Sized,
+{
+    let mut shrinker = Shrinker {
+        expected: failure(actual, validator)?,
+        validator,
+        steps: 0,
+    };
+    let mut current = actual.clone();
+    while shrinker.shrink_at(&mut current, &mut vec![]) {}
+    Some(current)
+}
+
+/// The kind of the first error of the validation and its pointer without
+/// array indices, if any.
+fn failure<V>(actual: &Value, validator: &V) -> Option<(ErrorKind, Option<String>)>
+where
+    V: Validator + ?Sized,
+{
+    let errors = check(actual, validator).err()?;
+    let pointer = errors[0].pointer(actual).map(|pointer| {
+        let segments: Vec<&str> = pointer
+            .split('/')
+            .map(|segment| match segment.parse::<usize>() {
+                Ok(_) => "-",
+                Err(_) => segment,
+            })
+            .collect();
+        segments.join("/")
+    });
+    Some((errors[0].kind(), pointer))
+}
+
+/// A step from a value to one of its elements or properties, by position.
+type Path = Vec<usize>;
+
+/// A simplification of the value at a path, applied to the document in place.
+enum Edit {
+    Replace(Value),
+    /// Remove array elements.
+    Remove(Range<usize>),
+    /// Remove the property at the position.
+    RemoveKey(usize),
+}
+
+/// How to revert an edit.
+enum Undo {
+    Replace(Value),
+    Insert(usize, Vec<Value>),
+    InsertKey(usize, String, Value),
+}
+
+struct Shrinker<'v, V: ?Sized> {
+    validator: &'v V,
+    expected: (ErrorKind, Option<String>),
+    steps: usize,
+}
+
+impl<V> Shrinker<'_, V>
+where
+    V: Validator + ?Sized,
+{
+    /// Simplify the value at `path` or one of its descendants, simplest
+    /// first, stopping at the first simplification which keeps failing.
+    /// `false` means none does.
+    fn shrink_at(&mut self, document: &mut Value, path: &mut Path) -> bool {
+        let node = node_mut(document, path);
+        for simple in simpler(node) {
+            if self.attempt(document, path, Edit::Replace(simple)) {
+                return true;
+            }
+        }
+
+        let node = node_mut(document, path);
+        let len = match node {
+            Value::Array(array) => array.len(),
+            Value::Object(object) => object.len(),
+            _ => return false,
+        };
+        if node.is_array() {
+            // Remove halves, then quarters, and so on, so that long arrays
+            // shrink in a few steps.
+            let mut size = len / 2;
+            while size > 0 {
+                for start in (0..len).step_by(size) {
+                    let range = start..(start + size).min(len);
+                    if self.attempt(document, path, Edit::Remove(range)) {
+                        return true;
+                    }
+                }
+                size /= 2;
+            }
+            if len == 1 && self.attempt(document, path, Edit::Remove(0..1)) {
+                return true;
+            }
+        } else {
+            for position in 0..len {
+                if self.attempt(document, path, Edit::RemoveKey(position)) {
+                    return true;
+                }
+            }
+        }
+        for position in 0..len {
+            path.push(position);
+            let shrunk = self.shrink_at(document, path);
+            path.pop();
+            if shrunk {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Apply the edit and keep it if the document still fails the same way.
+    fn attempt(&mut self, document: &mut Value, path: &Path, edit: Edit) -> bool {
+        if self.steps >= MAX_STEPS {
+            return false;
+        }
+        self.steps += 1;
+        let undo = apply(node_mut(document, path), edit);
+        if failure(document, self.validator).as_ref() == Some(&self.expected) {
+            return true;
+        }
+        revert(node_mut(document, path), undo);
+        false
+    }
+}
+
+/// The value at the path.
+fn node_mut<'a>(mut value: &'a mut Value, path: &Path) -> &'a mut Value {
+    for position in path {
+        value = match value {
+            Value::Array(array) => &mut array[*position],
+            Value::Object(object) => object
+                .values_mut()
+                .nth(*position)
+                .expect("position of a property"),
+            _ => unreachable!("path into a scalar"),
+        };
+    }
+    value
+}
+
+fn apply(node: &mut Value, edit: Edit) -> Undo {
+    match (edit, node) {
+        (Edit::Replace(value), node) => Undo::Replace(std::mem::replace(node, value)),
+        (Edit::Remove(range), Value::Array(array)) => {
+            Undo::Insert(range.start, array.drain(range).collect())
+        }
+        (Edit::RemoveKey(position), Value::Object(object)) => {
+            // Removing from the entries keeps the order of the others, with
+            // or without the `preserve_order` feature of `serde_json`.
+            let mut entries: Vec<(String, Value)> = std::mem::take(object).into_iter().collect();
+            let (key, value) = entries.remove(position);
+            *object = entries.into_iter().collect();
+            Undo::InsertKey(position, key, value)
+        }
+        _ => unreachable!("edit of another type"),
+    }
+}
+
+fn revert(node: &mut Value, undo: Undo) {
+    match (undo, node) {
+        (Undo::Replace(value), node) => *node = value,
+        (Undo::Insert(start, values), Value::Array(array)) => {
+            array.splice(start..start, values);
+        }
+        (Undo::InsertKey(position, key, value), Value::Object(object)) => {
+            let mut entries: Vec<(String, Value)> = std::mem::take(object).into_iter().collect();
+            entries.insert(position, (key, value));
+            *object = entries.into_iter().collect();
+        }
+        _ => unreachable!("undo of another type"),
+    }
+}
+
+/// The values simpler than `value` as a whole: values of simpler types and
+/// smaller values of the same type.
+fn simpler(value: &Value) -> Vec<Value> {
+    let mut simpler: Vec<Value> = [
+        Value::Null,
+        Value::Bool(false),
+        Value::from(0),
+        Value::String(String::new()),
+        Value::Array(vec![]),
+        Value::Object(Map::new()),
+    ]
+    .into_iter()
+    .take_while(|simple| rank(simple) < rank(value))
+    .collect();
+    match value {
+        Value::Bool(true) => simpler.push(Value::Bool(false)),
+        Value::Number(number) => {
+            if let Some(integer) = number.as_i64() {
+                if integer != 0 {
+                    simpler.push(Value::from(0));
+                }
+                if integer.abs() > 1 {
+                    simpler.push(Value::from(integer / 2));
+                }
+            } else if let Some(float) = number.as_f64() {
+                simpler.push(Value::from(0));
+                if float.trunc().abs() < 9_007_199_254_740_992.0 {
+                    simpler.push(Value::from(float.trunc() as i64));
+                }
+            } else {
+                simpler.push(Value::from(0));
+            }
+        }
+        Value::String(string) if !string.is_empty() => {
+            let chars: Vec<char> = string.chars().collect();
+            simpler.push(Value::String(String::new()));
+            if chars.len() > 2 {
+                simpler.push(Value::String(chars[..chars.len() / 2].iter().collect()));
+            }
+            simpler.push(Value::String(chars[..chars.len() - 1].iter().collect()));
+        }
+        Value::Array(array) if !array.is_empty() => simpler.push(Value::Array(vec![])),
+        Value::Object(object) if !object.is_empty() => simpler.push(Value::Object(Map::new())),
+        _ => {}
+    }
+    simpler.retain(|simple| simple != value);
+    simpler
+}
+
+/// The order of simplicity of the types.
+fn rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shrink;
+    use crate::{validators, Validator};
+    use serde_json::json;
+
+    #[test]
+    fn matching() {
+        assert_eq!(None, shrink(&json!({"id": 5}), &validators::any()));
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(Some(json!(19)), shrink(&json!(1234), &validators::lt(10)));
+        assert_eq!(
+            Some(json!("abc")),
+            shrink(
+                &json!("abcdefgh"),
+                &validators::custom(|value: &serde_json::Value| {
+                    match value.as_str() {
+                        Some(s) if s.len() >= 3 => Err(String::from("a short string")),
+                        _ => Ok(()),
+                    }
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn preserves_kind() {
+        let validator = validators::at("/user/age", validators::is_number());
+        let actual = json!({"user": {"age": "old", "name": "n"}, "id": 5});
+
+        // Removing /user/age would be a missing pointer instead.
+        assert_eq!(
+            Some(json!({"user": {"age": null}})),
+            shrink(&actual, &validator)
+        );
+    }
+
+    #[test]
+    fn arrays() {
+        let validator =
+            validators::array_for_each(validators::lt(10)).and(validators::array_min_len(1));
+        let actual = json!([1, 2, 30, 4, 51]);
+
+        assert_eq!(Some(json!([12])), shrink(&actual, &validator));
+    }
+
+    #[test]
+    fn large_documents() {
+        use std::time::{Duration, Instant};
+
+        let users: Vec<_> = (0..2000)
+            .map(|id| json!({"id": id, "name": format!("user {}", id), "roles": ["admin", "dev"]}))
+            .collect();
+        let mut actual = json!({"users": users, "total": 2000});
+        actual["users"][1500]["id"] = json!("1500");
+        let validator = validators::at(
+            "/users",
+            validators::array_for_each(validators::object_contains(vec![(
+                "id",
+                Box::new(validators::is_number()) as Box<dyn Validator>,
+            )])),
+        );
+
+        // Candidates used to be copies of the whole document, which ran out
+        // of memory at this size.
+        let start = Instant::now();
+        assert_eq!(
+            Some(json!({"users": [{"id": null}]})),
+            shrink(&actual, &validator)
+        );
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,180 @@
+//! Compare documents with snapshots stored in JSON files.
+//!
+//! A snapshot is written with the actual document the first time it is
+//! asserted, or when the `UPDATE_SNAPSHOTS` environment variable is set. Later
+//! runs validate the actual document against the stored one. When the `CI`
+//! environment variable is set, missing snapshots fail instead of being
+//! written.
+
+use crate::{validators, Validator, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Snapshot value matching any value. It is kept when the snapshot is updated.
+const IGNORE: &str = "[ignore]";
+
+/// Return the snapshot stored at `path`, or write `actual` to it and return
+/// `None` if there is none yet or it must be updated.
+///
+/// Panics if the snapshot cannot be read, parsed or written, or if it is
+/// missing on CI.
+pub(crate) fn load_or_write(path: &Path, actual: &Value) -> Option<Value> {
+    load_or_write_with(
+        path,
+        actual,
+        std::env::var_os("UPDATE_SNAPSHOTS").is_some(),
+        std::env::var_os("CI").is_some(),
+    )
+}
+
+/// [load_or_write], updating the snapshot if `update` is set and failing on
+/// a missing snapshot if `ci` is set.
+fn load_or_write_with(path: &Path, actual: &Value, update: bool, ci: bool) -> Option<Value> {
+    let stored = path.exists().then(|| read(path));
+    if !update && stored.is_some() {
+        return stored;
+    }
+    if !update && ci {
+        panic!(
+            "missing snapshot {} (snapshots are not written when CI is set, \
+             set UPDATE_SNAPSHOTS to write it)",
+            path.display()
+        );
+    }
+
+    let snapshot = match &stored {
+        Some(stored) => redact(actual, stored),
+        None => actual.clone(),
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap_or_else(|err| {
+            panic!(
+                "failed to create snapshot directory {}: {}",
+                parent.display(),
+                err
+            )
+        });
+    }
+    let mut text = serde_json::to_string_pretty(&snapshot).expect("failed to serialize JSON");
+    text.push('\n');
+    std::fs::write(path, text)
+        .unwrap_or_else(|err| panic!("failed to write snapshot {}: {}", path.display(), err));
+    None
+}
+
+fn read(path: &Path) -> Value {
+    let text = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read snapshot {}: {}", path.display(), err));
+    serde_json::from_str(&text)
+        .unwrap_or_else(|err| panic!("failed to parse snapshot {}: {}", path.display(), err))
+}
+
+/// Build the validator matching the documents equal to `snapshot`, except for
+/// its `"[ignore]"` values which match any value.
+pub(crate) fn validator(snapshot: &Value) -> Box<dyn Validator> {
+    match snapshot {
+        Value::String(string) if string == IGNORE => Box::new(validators::any()),
+        Value::Array(array) => Box::new(validators::array(array.iter().map(validator).collect())),
+        Value::Object(object) => Box::new(validators::object_strict(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), validator(value)))
+                .collect::<HashMap<_, _>>(),
+        )),
+        _ => Box::new(validators::eq(snapshot.clone())),
+    }
+}
+
+/// Copy `actual`, keeping the `"[ignore]"` values of the `stored` snapshot.
+fn redact(actual: &Value, stored: &Value) -> Value {
+    match (actual, stored) {
+        (_, Value::String(string)) if string == IGNORE => stored.clone(),
+        (Value::Array(actual), Value::Array(stored)) => Value::Array(
+            actual
+                .iter()
+                .enumerate()
+                .map(|(index, value)| match stored.get(index) {
+                    Some(stored) => redact(value, stored),
+                    None => value.clone(),
+                })
+                .collect(),
+        ),
+        (Value::Object(actual), Value::Object(stored)) => Value::Object(
+            actual
+                .iter()
+                .map(|(key, value)| {
+                    let value = match stored.get(key) {
+                        Some(stored) => redact(value, stored),
+                        None => value.clone(),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        _ => actual.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Validator;
+
+    #[test]
+    fn validator() {
+        let validator = super::validator(&serde_json::json!({
+            "id": "[ignore]",
+            "tags": ["a", "[ignore]"],
+        }));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"id": 5, "tags": ["a", {"b": 1}]}))
+        );
+        assert!(validator
+            .validate(&serde_json::json!({"id": 5, "tags": ["a", "b"], "name": "n"}))
+            .is_err());
+        assert!(validator
+            .validate(&serde_json::json!({"id": 5, "tags": ["b", "b"]}))
+            .is_err());
+    }
+
+    #[test]
+    fn redact() {
+        assert_eq!(
+            serde_json::json!({"id": "[ignore]", "items": [{"at": "[ignore]", "n": 2}, 3]}),
+            super::redact(
+                &serde_json::json!({"id": 6, "items": [{"at": "now", "n": 2}, 3]}),
+                &serde_json::json!({"id": "[ignore]", "items": [{"at": "[ignore]", "n": 1}]}),
+            )
+        );
+    }
+
+    #[test]
+    fn load_or_write() {
+        let path = std::env::temp_dir()
+            .join(format!("assert_json_snapshot_{}", std::process::id()))
+            .join("new.json");
+        let _ = std::fs::remove_file(&path);
+
+        let value = serde_json::json!({"id": 5});
+        assert_eq!(None, super::load_or_write_with(&path, &value, false, false));
+        assert_eq!(
+            Some(value.clone()),
+            super::load_or_write_with(&path, &value, false, true)
+        );
+        let updated = serde_json::json!({"id": 6});
+        assert_eq!(None, super::load_or_write_with(&path, &updated, true, true));
+        assert_eq!(updated, super::read(&path));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing snapshot")]
+    fn missing_on_ci() {
+        let path = std::env::temp_dir()
+            .join(format!("assert_json_snapshot_{}", std::process::id()))
+            .join("missing.json");
+
+        super::load_or_write_with(&path, &serde_json::json!({"id": 5}), false, true);
+    }
+}
@@ -0,0 +1,149 @@
+//! Locate values in the original JSON text, which [Value] does not keep track of.
+
+/// Find the line and column, both starting at 1, of the value at the JSON
+/// `pointer` in the JSON `source`.
+///
+/// The column is counted in characters. `source` is expected to be valid
+/// JSON; `None` is returned if the pointer does not resolve.
+pub(crate) fn line_column(source: &str, pointer: &str) -> Option<(usize, usize)> {
+    let tokens: Vec<String> = if pointer.is_empty() {
+        vec![]
+    } else {
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect()
+    };
+
+    let offset = find(source.as_bytes(), 0, &tokens)?;
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    Some((line, before[line_start..].chars().count() + 1))
+}
+
+/// Find the offset of the value designated by `tokens` in the value at `pos`.
+fn find(source: &[u8], pos: usize, tokens: &[String]) -> Option<usize> {
+    let pos = skip_whitespace(source, pos);
+    let Some((token, rest)) = tokens.split_first() else {
+        return Some(pos);
+    };
+
+    match source.get(pos)? {
+        b'{' => {
+            let mut pos = skip_whitespace(source, pos + 1);
+            while source.get(pos)? == &b'"' {
+                let end = skip_value(source, pos)?;
+                let key: String = serde_json::from_slice(&source[pos..end]).ok()?;
+                let colon = skip_whitespace(source, end);
+                if key == *token {
+                    return find(source, colon + 1, rest);
+                }
+                pos = skip_whitespace(
+                    source,
+                    skip_value(source, skip_whitespace(source, colon + 1))?,
+                );
+                if source.get(pos)? == &b',' {
+                    pos = skip_whitespace(source, pos + 1);
+                }
+            }
+            None
+        }
+        b'[' => {
+            let index: usize = token.parse().ok()?;
+            let mut pos = pos + 1;
+            for _ in 0..index {
+                pos = skip_whitespace(source, skip_value(source, skip_whitespace(source, pos))?);
+                if source.get(pos)? != &b',' {
+                    return None;
+                }
+                pos += 1;
+            }
+            let pos = skip_whitespace(source, pos);
+            if source.get(pos)? == &b']' {
+                return None;
+            }
+            find(source, pos, rest)
+        }
+        _ => None,
+    }
+}
+
+/// Return the offset right after the value starting at `pos`.
+fn skip_value(source: &[u8], pos: usize) -> Option<usize> {
+    match source.get(pos)? {
+        b'"' => {
+            let mut pos = pos + 1;
+            loop {
+                match source.get(pos)? {
+                    b'\\' => pos += 2,
+                    b'"' => return Some(pos + 1),
+                    _ => pos += 1,
+                }
+            }
+        }
+        b'{' | b'[' => {
+            let mut depth = 0;
+            let mut pos = pos;
+            loop {
+                match source.get(pos)? {
+                    b'"' => {
+                        pos = skip_value(source, pos)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(pos + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                pos += 1;
+            }
+        }
+        _ => Some(
+            pos + source[pos..]
+                .iter()
+                .take_while(|c| !matches!(c, b',' | b'}' | b']') && !c.is_ascii_whitespace())
+                .count(),
+        ),
+    }
+}
+
+fn skip_whitespace(source: &[u8], pos: usize) -> usize {
+    pos + source
+        .get(pos..)
+        .unwrap_or_default()
+        .iter()
+        .take_while(|c| c.is_ascii_whitespace())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn line_column() {
+        let source = "{\n  \"a\": [1, {\"b\\\"\": \"x,]}\"}, 3],\n  \"c/d\": null\n}";
+
+        assert_eq!(Some((1, 1)), super::line_column(source, ""));
+        assert_eq!(Some((2, 8)), super::line_column(source, "/a"));
+        assert_eq!(Some((2, 9)), super::line_column(source, "/a/0"));
+        assert_eq!(Some((2, 20)), super::line_column(source, "/a/1/b\""));
+        assert_eq!(Some((2, 29)), super::line_column(source, "/a/2"));
+        assert_eq!(Some((3, 10)), super::line_column(source, "/c~1d"));
+        assert_eq!(None, super::line_column(source, "/a/3"));
+        assert_eq!(None, super::line_column(source, "/missing"));
+        assert_eq!(None, super::line_column(source, "/a/x"));
+    }
+
+    #[test]
+    fn line_column_unicode() {
+        assert_eq!(
+            Some((1, 17)),
+            super::line_column("{\"é\": \"ü\", \"k\": 1}", "/k")
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! "Did you mean" suggestions for missing object keys.
+
+use crate::Value;
+
+/// Suggest the actual keys of `object` which are close to the missing `keys`.
+///
+/// Return an empty string when there is nothing to suggest.
+pub(crate) fn did_you_mean(object: &Value, keys: &[String]) -> String {
+    let Some(object) = object.as_object() else {
+        return String::new();
+    };
+
+    let suggestions: Vec<(&String, &str)> = keys
+        .iter()
+        .filter_map(|key| {
+            closest_key(key, object.keys().map(String::as_str)).map(|found| (key, found))
+        })
+        .collect();
+    match suggestions.as_slice() {
+        [] => String::new(),
+        [(_, found)] if keys.len() == 1 => format!(". Did you mean '{}'?", found),
+        _ => format!(
+            ". Did you mean {}?",
+            suggestions
+                .iter()
+                .map(|(key, found)| format!("'{}' instead of '{}'", found, key))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Find the candidate closest to `key`, ignoring case and `_`/`-` separators.
+///
+/// A third of the characters of `key` may differ, so short keys only match
+/// when they are the same but for case and separators.
+fn closest_key<'a>(key: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let key = normalize(key);
+    let max_distance = key.len() / 3;
+    candidates
+        .map(|candidate| (distance(&key, &normalize(candidate)), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+fn normalize(key: &str) -> Vec<char> {
+    key.chars()
+        .filter(|c| !matches!(c, '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            current.push(
+                (previous[j] + usize::from(ca != cb))
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn closest_key() {
+        let candidates = ["user_id", "name", "created_at"];
+
+        assert_eq!(
+            Some("user_id"),
+            super::closest_key("userId", candidates.into_iter())
+        );
+        assert_eq!(
+            Some("created_at"),
+            super::closest_key("createdAt", candidates.into_iter())
+        );
+        assert_eq!(
+            Some("name"),
+            super::closest_key("nane", candidates.into_iter())
+        );
+        assert_eq!(None, super::closest_key("email", candidates.into_iter()));
+    }
+
+    #[test]
+    fn did_you_mean() {
+        let object = serde_json::json!({"user_id": 1, "name": "n"});
+
+        assert_eq!(
+            ". Did you mean 'user_id'?",
+            super::did_you_mean(&object, &[String::from("userId")])
+        );
+        assert_eq!(
+            ". Did you mean 'user_id' instead of 'userId'?",
+            super::did_you_mean(&object, &[String::from("email"), String::from("userId")])
+        );
+        assert_eq!("", super::did_you_mean(&object, &[String::from("email")]));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Value;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static MAX_VALUE_LEN: AtomicUsize = AtomicUsize::new(1024);
+
+/// Set the maximum length, in bytes, of the values displayed in error messages.
+///
+/// Longer values are replaced by an excerpt around the mismatch. It defaults to
+/// 1024 bytes; use `usize::MAX` to display values in full. The setting is global
+/// to the process.
+pub fn set_max_value_len(len: usize) {
+    MAX_VALUE_LEN.store(len, Ordering::Relaxed);
+}
+
+fn max_value_len() -> usize {
+    MAX_VALUE_LEN.load(Ordering::Relaxed)
+}
+
+/// Display `value`, truncated if it is too long.
+pub(crate) fn display_value(value: &Value) -> String {
+    excerpt(&value.to_string(), 0, max_value_len())
+}
+
+/// Display a string value, truncated if it is too long.
+pub(crate) fn display_str(text: &str) -> String {
+    excerpt(text, 0, max_value_len())
+}
+
+/// Display both texts, truncated around the first position where they differ.
+pub(crate) fn excerpts(expected: &str, actual: &str) -> (String, String) {
+    let position = expected
+        .char_indices()
+        .zip(actual.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(expected.len().min(actual.len()), |((index, _), _)| index);
+    let max = max_value_len();
+    (
+        excerpt(expected, position, max),
+        excerpt(actual, position, max),
+    )
+}
+
+/// Keep at most `max` bytes of `text` around `position`.
+fn excerpt(text: &str, position: usize, max: usize) -> String {
+    if text.len() <= max {
+        return String::from(text);
+    }
+
+    let mut start = position.saturating_sub(max / 2).min(text.len() - max);
+    while !text.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = start + max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!(
+        "{}{}{} ({} more bytes)",
+        if start > 0 { "…" } else { "" },
+        &text[start..end],
+        if end < text.len() { "…" } else { "" },
+        text.len() - (end - start)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn excerpt() {
+        assert_eq!("abcdef", super::excerpt("abcdef", 0, 6));
+        assert_eq!("abcd… (2 more bytes)", super::excerpt("abcdef", 0, 4));
+        assert_eq!("…cdef (2 more bytes)", super::excerpt("abcdef", 5, 4));
+        assert_eq!("…defg… (6 more bytes)", super::excerpt("abcdefghij", 5, 4));
+        assert_eq!("…éé (2 more bytes)", super::excerpt("ééé", 6, 4));
+    }
+
+    #[test]
+    fn excerpts() {
+        let expected = format!("\"{}a{}\"", "x".repeat(2000), "y".repeat(2000));
+        let actual = format!("\"{}b{}\"", "x".repeat(2000), "y".repeat(2000));
+
+        let (expected, actual) = super::excerpts(&expected, &actual);
+        assert!(expected.starts_with('…'));
+        assert!(expected.contains(&format!("{}a{}", "x".repeat(100), "y".repeat(100))));
+        assert!(actual.contains(&format!("{}b{}", "x".repeat(100), "y".repeat(100))));
+        assert!(actual.ends_with("… (2979 more bytes)"));
+    }
+}
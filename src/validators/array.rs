@@ -1,5 +1,6 @@
 use crate::validators;
 use crate::{Error, Validator, Value};
+use std::cmp::Ordering;
 
 /// Match each array element to a specific validator.
 pub fn array(array_validators: Vec<Box<dyn Validator>>) -> impl Validator {
@@ -17,6 +18,77 @@ pub fn array_size(expected_size: usize) -> impl Validator {
     }
 }
 
+/// Match if the array has exactly the expected number of elements.
+///
+/// Unlike [array_size], the error reports the actual length rather than
+/// the content of the array.
+pub fn array_len(expected: usize) -> impl Validator {
+    ArrayLenValidator {
+        min: expected,
+        max: expected,
+    }
+}
+
+/// Match if the array has at least the expected number of elements.
+pub fn array_min_len(min: usize) -> impl Validator {
+    ArrayLenValidator {
+        min,
+        max: usize::MAX,
+    }
+}
+
+/// Match if the array has at most the expected number of elements.
+pub fn array_max_len(max: usize) -> impl Validator {
+    ArrayLenValidator { min: 0, max }
+}
+
+struct ArrayLenValidator {
+    min: usize,
+    max: usize,
+}
+
+impl Validator for ArrayLenValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let len = value
+            .as_array()
+            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?
+            .len();
+
+        if len >= self.min && len <= self.max {
+            return Ok(());
+        }
+
+        Err(Error::InvalidLength(
+            value,
+            format!("{} (actual length {})", self.expectation(), len),
+        ))
+    }
+
+    fn describe(&self) -> String {
+        format!("an {}", self.expectation())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = serde_json::json!({ "type": "array", "minItems": self.min });
+        if self.max != usize::MAX {
+            schema["maxItems"] = Value::from(self.max);
+        }
+        schema
+    }
+}
+
+impl ArrayLenValidator {
+    fn expectation(&self) -> String {
+        if self.min == self.max {
+            format!("array of length {}", self.min)
+        } else if self.max == usize::MAX {
+            format!("array of length >= {}", self.min)
+        } else {
+            format!("array of length <= {}", self.max)
+        }
+    }
+}
+
 /// Match empty array.
 pub fn array_empty() -> impl Validator {
     ArrayValidator { validators: vec![] }
@@ -33,7 +105,7 @@ impl Validator for ArrayValidator {
             .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
 
         if value_vec.len() != self.validators.len() {
-            return Err(Error::InvalidValue(
+            return Err(Error::InvalidLength(
                 value,
                 format!(
                     "expected {} elements got {}",
@@ -46,15 +118,196 @@ impl Validator for ArrayValidator {
         value_vec
             .iter()
             .zip(self.validators.iter())
-            .try_for_each(|(val, validator)| validator.validate(val))
+            .enumerate()
+            .try_for_each(|(index, (val, validator))| {
+                validator
+                    .validate(val)
+                    .map_err(|err| Error::InvalidArrayElement(index, Box::new(err)))
+            })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        // A size mismatch makes the elements impossible to pair.
+        match value.as_array() {
+            Some(value_vec) if value_vec.len() == self.validators.len() => value_vec
+                .iter()
+                .zip(self.validators.iter())
+                .enumerate()
+                .flat_map(|(index, (val, validator))| {
+                    validator
+                        .validate_all(val)
+                        .into_iter()
+                        .map(move |err| Error::InvalidArrayElement(index, Box::new(err)))
+                })
+                .collect(),
+            _ => self.validate(value).err().into_iter().collect(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        if self.validators.is_empty() {
+            String::from("an empty array")
+        } else {
+            format!(
+                "an array of [{}]",
+                validators::join_descriptions(&self.validators, ", ")
+            )
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let len = self.validators.len();
+        let mut schema = serde_json::json!({ "type": "array", "minItems": len, "maxItems": len });
+        if let Some(items) = prefix_items(&self.validators) {
+            schema["prefixItems"] = items;
+        }
+        schema
+    }
+}
+
+/// The `prefixItems` schemas of the validators, unless they all accept any
+/// value.
+fn prefix_items(validators: &[Box<dyn Validator>]) -> Option<Value> {
+    let schemas: Vec<Value> = validators.iter().map(|v| v.to_json_schema()).collect();
+    if schemas.iter().all(validators::is_any_schema) {
+        None
+    } else {
+        Some(Value::Array(schemas))
+    }
+}
+
+/// Match if the first elements match the validators, in order.
+///
+/// The remaining elements are ignored.
+pub fn array_starts_with(array_validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    array_with_rest(array_validators, vec![])
+}
+
+/// Match if the last elements match the validators, in order.
+///
+/// The preceding elements are ignored.
+pub fn array_ends_with(array_validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    array_with_rest(vec![], array_validators)
+}
+
+/// Match if the first elements match `prefix` and the last elements match
+/// `suffix`, whatever the elements in between.
+pub(crate) fn array_with_rest(
+    prefix: Vec<Box<dyn Validator>>,
+    suffix: Vec<Box<dyn Validator>>,
+) -> impl Validator {
+    ArrayRestValidator { prefix, suffix }
+}
+
+struct ArrayRestValidator {
+    prefix: Vec<Box<dyn Validator>>,
+    suffix: Vec<Box<dyn Validator>>,
+}
+
+impl ArrayRestValidator {
+    /// Pair the validators with the index of the element they validate.
+    fn indexed_validators(&self, len: usize) -> impl Iterator<Item = (usize, &Box<dyn Validator>)> {
+        let suffix_start = len - self.suffix.len();
+        self.prefix.iter().enumerate().chain(
+            self.suffix
+                .iter()
+                .enumerate()
+                .map(move |(index, validator)| (suffix_start + index, validator)),
+        )
+    }
+
+    fn check_len<'a>(&self, value: &'a Value) -> Result<&'a Vec<Value>, Error<'a>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
+
+        let min = self.prefix.len() + self.suffix.len();
+        if value_vec.len() < min {
+            return Err(Error::InvalidLength(
+                value,
+                format!(
+                    "array of length >= {} (actual length {})",
+                    min,
+                    value_vec.len()
+                ),
+            ));
+        }
+        Ok(value_vec)
+    }
+}
+
+impl Validator for ArrayRestValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let value_vec = self.check_len(value)?;
+        self.indexed_validators(value_vec.len())
+            .try_for_each(|(index, validator)| {
+                crate::coverage::visit(value, &value_vec[index]);
+                validator
+                    .validate(&value_vec[index])
+                    .map_err(|err| Error::InvalidArrayElement(index, Box::new(err)))
+            })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let value_vec = match self.check_len(value) {
+            Ok(value_vec) => value_vec,
+            Err(err) => return vec![err],
+        };
+        self.indexed_validators(value_vec.len())
+            .flat_map(|(index, validator)| {
+                crate::coverage::visit(value, &value_vec[index]);
+                validator
+                    .validate_all(&value_vec[index])
+                    .into_iter()
+                    .map(move |err| Error::InvalidArrayElement(index, Box::new(err)))
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        match (self.prefix.is_empty(), self.suffix.is_empty()) {
+            (true, true) => String::from("an array"),
+            (false, true) => format!(
+                "an array starting with [{}]",
+                validators::join_descriptions(&self.prefix, ", ")
+            ),
+            (true, false) => format!(
+                "an array ending with [{}]",
+                validators::join_descriptions(&self.suffix, ", ")
+            ),
+            (false, false) => format!(
+                "an array starting with [{}] and ending with [{}]",
+                validators::join_descriptions(&self.prefix, ", "),
+                validators::join_descriptions(&self.suffix, ", ")
+            ),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        // The suffix can't be expressed: the schema only checks the length.
+        let len = self.prefix.len() + self.suffix.len();
+        let mut schema = serde_json::json!({ "type": "array", "minItems": len });
+        if let Some(items) = prefix_items(&self.prefix) {
+            schema["prefixItems"] = items;
+        }
+        schema
     }
 }
 
 /// Match if each element match the validator
+///
+/// The error reports the index of the first element that does not match.
 pub fn array_for_each(validator: impl Validator) -> impl Validator {
     ArrayForEachValidator { validator }
 }
 
+/// Match if each element match the validator.
+///
+/// Same as [array_for_each].
+pub fn array_each(validator: impl Validator) -> impl Validator {
+    array_for_each(validator)
+}
+
 struct ArrayForEachValidator<T>
 where
     T: Validator,
@@ -71,9 +324,543 @@ where
             .as_array()
             .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
 
+        value_vec.iter().enumerate().try_for_each(|(index, val)| {
+            self.validator
+                .validate(val)
+                .map_err(|err| Error::InvalidArrayElement(index, Box::new(err)))
+        })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(value_vec) = value.as_array() else {
+            return vec![Error::InvalidType(value, String::from("array"))];
+        };
+
         value_vec
             .iter()
-            .try_for_each(|val| self.validator.validate(val))
+            .enumerate()
+            .flat_map(|(index, val)| {
+                self.validator
+                    .validate_all(val)
+                    .into_iter()
+                    .map(move |err| Error::InvalidArrayElement(index, Box::new(err)))
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "an array whose elements are each {}",
+            self.validator.describe()
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "type": "array", "items": self.validator.to_json_schema() })
+    }
+}
+
+/// Match if at least one element match the validator.
+///
+/// When no element matches, the error of each element is reported.
+pub fn array_contains(validator: impl Validator) -> impl Validator {
+    ArrayContainsValidator { validator }
+}
+
+struct ArrayContainsValidator<T>
+where
+    T: Validator,
+{
+    validator: T,
+}
+
+impl<T> Validator for ArrayContainsValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
+
+        let mut errors = vec![];
+        for (index, val) in value_vec.iter().enumerate() {
+            match crate::coverage::tentatively(|| self.validator.validate(val), Result::is_ok) {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(Error::InvalidArrayElement(index, Box::new(err))),
+            }
+        }
+        Err(Error::NoMatchingElement(value, errors))
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(value_vec) = value.as_array() else {
+            return vec![Error::InvalidType(value, String::from("array"))];
+        };
+
+        let mut errors = vec![];
+        for (index, val) in value_vec.iter().enumerate() {
+            let element_errors =
+                crate::coverage::tentatively(|| self.validator.validate_all(val), Vec::is_empty);
+            if element_errors.is_empty() {
+                return vec![];
+            }
+            errors.extend(
+                element_errors
+                    .into_iter()
+                    .map(|err| Error::InvalidArrayElement(index, Box::new(err))),
+            );
+        }
+        vec![Error::NoMatchingElement(value, errors)]
+    }
+
+    fn describe(&self) -> String {
+        format!("an array containing {}", self.validator.describe())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "type": "array", "contains": self.validator.to_json_schema() })
+    }
+}
+
+/// Match each array element to a validator, regardless of the order.
+///
+/// Each validator must match a distinct element, meaning the array is
+/// compared as a multiset.
+pub fn array_unordered(array_validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    ArrayUnorderedValidator {
+        validators: array_validators,
+        exact_size: true,
+    }
+}
+
+/// Match if each validator matches a distinct element, regardless of the order.
+///
+/// The array may contain other elements than the ones expected.
+pub fn array_includes_all(array_validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    ArrayUnorderedValidator {
+        validators: array_validators,
+        exact_size: false,
+    }
+}
+
+/// Match if each element matches at least one of the validators.
+pub fn array_subset_of(array_validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    array_for_each(validators::any_of(array_validators))
+}
+
+struct ArrayUnorderedValidator {
+    validators: Vec<Box<dyn Validator>>,
+    exact_size: bool,
+}
+
+impl Validator for ArrayUnorderedValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.validate_all(value).into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(value_vec) = value.as_array() else {
+            return vec![Error::InvalidType(value, String::from("array"))];
+        };
+
+        if self.exact_size && value_vec.len() != self.validators.len() {
+            return vec![Error::InvalidLength(
+                value,
+                format!(
+                    "expected {} elements got {}",
+                    self.validators.len(),
+                    value_vec.len()
+                ),
+            )];
+        }
+
+        let matches: Vec<Vec<bool>> = self
+            .validators
+            .iter()
+            .map(|validator| {
+                value_vec
+                    .iter()
+                    .map(|val| {
+                        crate::coverage::tentatively(|| validator.validate(val), Result::is_ok)
+                            .is_ok()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        unmatched_validators(&matches, value_vec.len())
+            .into_iter()
+            .map(|index| {
+                Error::InvalidValue(
+                    value,
+                    format!(
+                        "elements in any order but validator at index {} has no matching element",
+                        index
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "an array {} [{}] in any order",
+            if self.exact_size { "of" } else { "including" },
+            validators::join_descriptions(&self.validators, ", ")
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let len = self.validators.len();
+        let mut schema = serde_json::json!({ "type": "array", "minItems": len });
+        if self.exact_size {
+            schema["maxItems"] = Value::from(len);
+        }
+        // Distinct elements matching each validator can't be expressed, only
+        // that one element matches each of them.
+        let contains = validators::all_of_schemas(
+            self.validators
+                .iter()
+                .map(|validator| serde_json::json!({ "contains": validator.to_json_schema() })),
+        );
+        validators::all_of_schemas([schema, contains])
+    }
+}
+
+/// Find the validators that cannot be assigned to distinct elements.
+///
+/// `matches[i][j]` is true if validator `i` matches element `j`. This is a
+/// bipartite matching solved with augmenting paths (Kuhn's algorithm).
+fn unmatched_validators(matches: &[Vec<bool>], element_count: usize) -> Vec<usize> {
+    fn try_assign(
+        validator: usize,
+        matches: &[Vec<bool>],
+        visited: &mut [bool],
+        assigned: &mut [Option<usize>],
+    ) -> bool {
+        for element in 0..visited.len() {
+            if matches[validator][element] && !visited[element] {
+                visited[element] = true;
+                let free = match assigned[element] {
+                    None => true,
+                    Some(other) => try_assign(other, matches, visited, assigned),
+                };
+                if free {
+                    assigned[element] = Some(validator);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    let mut assigned = vec![None; element_count];
+    (0..matches.len())
+        .filter(|&validator| {
+            let mut visited = vec![false; element_count];
+            !try_assign(validator, matches, &mut visited, &mut assigned)
+        })
+        .collect()
+}
+
+/// Match if the array does not contain duplicated elements.
+pub fn array_unique() -> impl Validator {
+    ArrayUniqueValidator { pointer: None }
+}
+
+/// Match if the values found at the JSON pointer are unique across the
+/// array elements.
+///
+/// For example, `array_unique_by("/id")` fails if two elements have the same `id`.
+pub fn array_unique_by(pointer: &str) -> impl Validator {
+    ArrayUniqueValidator {
+        pointer: Some(String::from(pointer)),
+    }
+}
+
+struct ArrayUniqueValidator {
+    pointer: Option<String>,
+}
+
+impl Validator for ArrayUniqueValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.validate_all(value).into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(value_vec) = value.as_array() else {
+            return vec![Error::InvalidType(value, String::from("array"))];
+        };
+
+        let mut errors = vec![];
+        let keys: Vec<Option<&Value>> = value_vec
+            .iter()
+            .map(|val| match &self.pointer {
+                None => Some(val),
+                Some(pointer) => {
+                    let key = val.pointer(pointer);
+                    if key.is_none() {
+                        errors.push(Error::InvalidValue(
+                            val,
+                            format!("element with a value at '{}'", pointer),
+                        ));
+                    }
+                    key
+                }
+            })
+            .collect();
+
+        for (index, key) in keys.iter().enumerate() {
+            let Some(key) = *key else {
+                continue;
+            };
+            if let Some(first_index) = keys[..index].iter().position(|other| *other == Some(key)) {
+                errors.push(Error::InvalidValue(
+                    key,
+                    format!(
+                        "unique elements (indices {} and {} are duplicated)",
+                        first_index, index
+                    ),
+                ));
+            }
+        }
+        errors
+    }
+
+    fn describe(&self) -> String {
+        match &self.pointer {
+            None => String::from("an array of unique elements"),
+            Some(pointer) => format!("an array of elements unique by '{}'", pointer),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        match self.pointer {
+            Some(_) => serde_json::json!({ "type": "array" }),
+            None => serde_json::json!({ "type": "array", "uniqueItems": true }),
+        }
+    }
+}
+
+/// Match if the number of elements matching `element_validator` matches `count_validator`.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// // exactly two entries have failed
+/// assert_json!(
+///     r#"["failed", "ok", "failed"]"#,
+///     validators::count_where(validators::eq("failed"), validators::eq(2))
+/// );
+/// ```
+pub fn count_where(
+    element_validator: impl Validator,
+    count_validator: impl Validator,
+) -> impl Validator {
+    CountWhereValidator {
+        element_validator,
+        count_validator,
+    }
+}
+
+struct CountWhereValidator<T, U>
+where
+    T: Validator,
+    U: Validator,
+{
+    element_validator: T,
+    count_validator: U,
+}
+
+impl<T, U> CountWhereValidator<T, U>
+where
+    T: Validator,
+    U: Validator,
+{
+    /// Whether the element is counted: the fields checked by the elements
+    /// which are not counted are not validated.
+    fn matches(&self, element: &Value) -> bool {
+        crate::coverage::tentatively(|| self.element_validator.validate(element), Result::is_ok)
+            .is_ok()
+    }
+}
+
+impl<T, U> Validator for CountWhereValidator<T, U>
+where
+    T: Validator,
+    U: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
+
+        let count = value_vec.iter().filter(|val| self.matches(val)).count();
+
+        // The count only lives in this function so the error can't point to it.
+        let count_value = Value::from(count);
+        self.count_validator.validate(&count_value).map_err(|err| {
+            Error::InvalidValue(
+                value,
+                format!("matching element count to be valid ({})", err),
+            )
+        })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(value_vec) = value.as_array() else {
+            return vec![Error::InvalidType(value, String::from("array"))];
+        };
+
+        let count = value_vec.iter().filter(|val| self.matches(val)).count();
+        let count_value = Value::from(count);
+        self.count_validator
+            .validate_all(&count_value)
+            .into_iter()
+            .map(|err| {
+                Error::InvalidValue(
+                    value,
+                    format!("matching element count to be valid ({})", err),
+                )
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "an array whose count of elements being {} is {}",
+            self.element_validator.describe(),
+            self.count_validator.describe()
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "type": "array" })
+    }
+}
+
+/// Sort direction used by [array_sorted_by].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    Ascending,
+    Descending,
+}
+
+/// Match if the array elements are sorted in ascending order.
+///
+/// Only numbers, strings and bools are comparable, and all elements must be
+/// of the same type.
+pub fn array_sorted() -> impl Validator {
+    array_sorted_by("", Order::Ascending)
+}
+
+/// Match if the array elements are sorted by the value found at the JSON pointer.
+///
+/// An empty pointer compares the elements themselves.
+pub fn array_sorted_by(pointer: &str, order: Order) -> impl Validator {
+    ArraySortedValidator {
+        pointer: String::from(pointer),
+        order,
+    }
+}
+
+struct ArraySortedValidator {
+    pointer: String,
+    order: Order,
+}
+
+impl Validator for ArraySortedValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.validate_all(value).into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(value_vec) = value.as_array() else {
+            return vec![Error::InvalidType(value, String::from("array"))];
+        };
+
+        let mut errors = vec![];
+        let mut keys = vec![];
+        for val in value_vec {
+            match val.pointer(&self.pointer) {
+                Some(key) => keys.push(key),
+                None => errors.push(Error::InvalidValue(
+                    val,
+                    format!("element with a value at '{}'", self.pointer),
+                )),
+            }
+        }
+        // The order of the elements can't be told without all their keys.
+        if !errors.is_empty() {
+            return errors;
+        }
+
+        let order_name = match self.order {
+            Order::Ascending => "ascending",
+            Order::Descending => "descending",
+        };
+        for (index, pair) in keys.windows(2).enumerate() {
+            let Some(ordering) = cmp_values(pair[0], pair[1]) else {
+                errors.push(Error::InvalidValue(
+                    pair[1],
+                    format!("value comparable with {}", pair[0]),
+                ));
+                continue;
+            };
+            let sorted = match self.order {
+                Order::Ascending => ordering != Ordering::Greater,
+                Order::Descending => ordering != Ordering::Less,
+            };
+            if !sorted {
+                errors.push(Error::InvalidValue(
+                    pair[1],
+                    format!(
+                        "elements in {} order (index {} is out of order)",
+                        order_name,
+                        index + 1
+                    ),
+                ));
+            }
+        }
+        errors
+    }
+
+    fn describe(&self) -> String {
+        let order_name = match self.order {
+            Order::Ascending => "ascending",
+            Order::Descending => "descending",
+        };
+        if self.pointer.is_empty() {
+            format!("an array sorted in {} order", order_name)
+        } else {
+            format!(
+                "an array sorted by '{}' in {} order",
+                self.pointer, order_name
+            )
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "type": "array" })
+    }
+}
+
+fn cmp_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => validators::cmp_numbers(a, b),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
     }
 }
 
@@ -109,13 +896,46 @@ mod tests {
         assert_eq!(Ok(()), validator.validate(&serde_json::json!([5, null,])))
     }
 
+    #[test]
+    fn validate_all() {
+        let validator = super::array(vec![
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(2)),
+            Box::new(validators::eq(3)),
+        ]);
+        let value = serde_json::json!([0, 2, 0]);
+
+        let errors = validator.validate_all(&value);
+        assert_eq!(2, errors.len());
+        assert!(std::ptr::eq(&value[0], errors[0].location()));
+        assert!(std::ptr::eq(&value[2], errors[1].location()));
+        assert_eq!(1, validator.validate_all(&serde_json::json!([1])).len());
+    }
+
+    #[test]
+    fn array_for_each_validate_all() {
+        let validator = super::array_for_each(validators::is_string());
+
+        assert_eq!(
+            vec![
+                String::from("Invalid array element at index 1. Invalid type. Expected string but got number."),
+                String::from("Invalid array element at index 2. Invalid type. Expected string but got null."),
+            ],
+            validator
+                .validate_all(&serde_json::json!(["a", 1, null]))
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn different_size() {
         let validator = super::array(vec![]);
 
         assert!(matches!(
             validator.validate(&serde_json::json!([null])),
-            Err(Error::InvalidValue(_, _))
+            Err(Error::InvalidLength(_, _))
         ));
     }
 
@@ -125,10 +945,29 @@ mod tests {
 
         assert!(matches!(
             validator.validate(&serde_json::json!([5])),
-            Err(Error::InvalidType(_, _))
+            Err(Error::InvalidArrayElement(0, err)) if matches!(*err, Error::InvalidType(_, _))
         ));
     }
 
+    #[test]
+    fn array_element_errors() {
+        let validator = super::array(vec![
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(2)),
+        ]);
+
+        assert_eq!(
+            vec![String::from(
+                "Invalid array element at index 1. Invalid value. Expected 2 but got 3."
+            )],
+            validator
+                .validate_all(&serde_json::json!([1, 3]))
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn array_size() {
         let validator = super::array_size(3);
@@ -148,4 +987,318 @@ mod tests {
             validator.validate(&serde_json::json!(["test", "test", "test"]))
         );
     }
+
+    #[test]
+    fn each_failing_index() {
+        let validator = super::array_each(validators::gt(0));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 2, 3])));
+        assert!(matches!(
+            validator.validate(&serde_json::json!([1, 0, 3])),
+            Err(Error::InvalidArrayElement(1, _))
+        ));
+    }
+
+    #[test]
+    fn contains() {
+        let validator = super::array_contains(validators::eq("created"));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!(["other", "created"]))
+        );
+        assert_eq!(
+            Err(String::from(
+                "No array element matched: \
+                Invalid array element at index 0. Invalid value. Expected \"created\" but got \"other\". | \
+                Invalid array element at index 1. Invalid type. Expected string but got number."
+            )),
+            validator
+                .validate(&serde_json::json!(["other", 5]))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn contains_empty() {
+        let validator = super::array_contains(validators::any());
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!([])),
+            Err(Error::NoMatchingElement(_, _))
+        ));
+    }
+
+    #[test]
+    fn unordered() {
+        let validator = super::array_unordered(vec![
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(2)),
+            Box::new(validators::eq(1)),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([2, 1, 1])));
+        assert!(matches!(
+            validator.validate(&serde_json::json!([2, 2, 1])),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn unordered_needs_reassignment() {
+        // The first validator greedily matches the first element but has to
+        // give it up for the second validator.
+        let validator = super::array_unordered(vec![
+            Box::new(validators::any()),
+            Box::new(validators::eq("a")),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(["a", "b"])));
+    }
+
+    #[test]
+    fn includes_all() {
+        let validator = super::array_includes_all(vec![
+            Box::new(validators::eq("b")),
+            Box::new(validators::eq("a")),
+        ]);
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!(["a", "c", "b"]))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!(["a", "c"])),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn subset_of() {
+        let validator = super::array_subset_of(vec![
+            Box::new(validators::eq("a")),
+            Box::new(validators::eq("b")),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(["b", "b"])));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([])));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(["a", "c"])),
+            Err(Error::InvalidArrayElement(1, _))
+        ));
+    }
+
+    #[test]
+    fn len() {
+        let value = serde_json::json!([1, 2, 3]);
+
+        assert_eq!(Ok(()), super::array_len(3).validate(&value));
+        assert_eq!(Ok(()), super::array_min_len(3).validate(&value));
+        assert_eq!(Ok(()), super::array_max_len(3).validate(&value));
+        assert!(super::array_min_len(4).validate(&value).is_err());
+        assert!(super::array_max_len(2).validate(&value).is_err());
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected array of length 2 (actual length 3) but got [1,2,3]."
+            )),
+            super::array_len(2)
+                .validate(&value)
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn unique() {
+        let validator = super::array_unique();
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, "1", 2])));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected unique elements (indices 0 and 2 are duplicated) but got \"a\"."
+            )),
+            validator
+                .validate(&serde_json::json!(["a", "b", "a"]))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn unique_by() {
+        let validator = super::array_unique_by("/id");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!([{"id": 1}, {"id": 2}]))
+        );
+        assert!(validator
+            .validate(&serde_json::json!([{"id": 1}, {"id": 1, "other": true}]))
+            .is_err());
+        assert!(validator
+            .validate(&serde_json::json!([{"id": 1}, {}]))
+            .is_err());
+    }
+
+    #[test]
+    fn sorted() {
+        let validator = super::array_sorted();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!([1, 2.5, 2.5, 4]))
+        );
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([])));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected elements in ascending order (index 2 is out of order) but got \"a\"."
+            )),
+            validator
+                .validate(&serde_json::json!(["a", "b", "a"]))
+                .map_err(|err| err.to_string())
+        );
+        assert!(validator.validate(&serde_json::json!([1, "2"])).is_err());
+    }
+
+    #[test]
+    fn sorted_by() {
+        let validator = super::array_sorted_by("/age", super::Order::Descending);
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!([{"age": 30}, {"age": 20}]))
+        );
+        assert!(validator
+            .validate(&serde_json::json!([{"age": 20}, {"age": 30}]))
+            .is_err());
+    }
+
+    #[test]
+    fn count_where() {
+        let validator = super::count_where(validators::eq("failed"), validators::eq(2));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!(["failed", "ok", "failed"]))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected matching element count to be valid \
+                (Invalid value. Expected 2 but got 1.) but got [\"failed\",\"ok\"]."
+            )),
+            validator
+                .validate(&serde_json::json!(["failed", "ok"]))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn array_with_rest() {
+        let validator = super::array_with_rest(
+            vec![Box::new(validators::eq(1))],
+            vec![Box::new(validators::eq(9))],
+        );
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 9])));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 5, 6, 9])));
+        assert!(matches!(
+            validator.validate(&serde_json::json!([1])),
+            Err(Error::InvalidLength(_, _))
+        ));
+        assert_eq!(
+            Err(String::from(
+                "Invalid array element at index 3. Invalid value. Expected 9 but got 8."
+            )),
+            validator
+                .validate(&serde_json::json!([1, 5, 6, 8]))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn array_starts_with() {
+        let validator = super::array_starts_with(vec![Box::new(validators::eq("a"))]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(["a", "b"])));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(["b", "a"])),
+            Err(Error::InvalidArrayElement(0, _))
+        ));
+    }
+
+    #[test]
+    fn array_ends_with() {
+        let validator = super::array_ends_with(vec![Box::new(validators::eq("a"))]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(["b", "a"])));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(["a", "b"])),
+            Err(Error::InvalidArrayElement(1, _))
+        ));
+    }
+
+    #[test]
+    fn to_json_schema() {
+        use serde_json::json;
+
+        assert_eq!(
+            json!({
+                "type": "array",
+                "minItems": 2,
+                "maxItems": 2,
+                "prefixItems": [{"const": 1}, {"type": "string"}],
+            }),
+            validators::array(vec![
+                Box::new(validators::eq(1)),
+                Box::new(validators::is_string())
+            ])
+            .to_json_schema()
+        );
+        assert_eq!(
+            json!({"type": "array", "minItems": 3, "maxItems": 3}),
+            validators::array_size(3).to_json_schema()
+        );
+        assert_eq!(
+            json!({"type": "array", "items": {"type": "integer"}}),
+            validators::array_for_each(validators::i64(|_| Ok(()))).to_json_schema()
+        );
+        assert_eq!(
+            json!({"type": "array", "contains": {"const": 5}}),
+            validators::array_contains(validators::eq(5)).to_json_schema()
+        );
+        assert_eq!(
+            json!({"type": "array", "uniqueItems": true}),
+            validators::array_unique().to_json_schema()
+        );
+    }
+
+    #[test]
+    fn validate_all_reports_every_element() {
+        let unique = super::array_unique_by("/id");
+        let value = serde_json::json!([{"id": 1}, {"id": 1}, {}, {"id": 1}]);
+        assert_eq!(3, unique.validate_all(&value).len());
+
+        let sorted = super::array_sorted();
+        assert_eq!(
+            2,
+            sorted.validate_all(&serde_json::json!([3, 1, 4, 2])).len()
+        );
+
+        let unordered = super::array_unordered(vec![
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(2)),
+            Box::new(validators::eq(3)),
+        ]);
+        assert_eq!(
+            2,
+            unordered.validate_all(&serde_json::json!([1, 5, 6])).len()
+        );
+
+        let contains = super::array_contains(validators::object_contains(vec![
+            ("a", Box::new(validators::eq(1)) as Box<dyn Validator>),
+            ("b", Box::new(validators::eq(2))),
+        ]));
+        assert!(matches!(
+            &contains.validate_all(&serde_json::json!([{"a": 0, "b": 0}]))[..],
+            [Error::NoMatchingElement(_, errors)] if errors.len() == 2
+        ));
+    }
 }
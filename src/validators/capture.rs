@@ -0,0 +1,149 @@
+use crate::{Error, Validator, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Store of values captured during validation.
+///
+/// Cloning a [Captures] gives another handle to the same store, so captured
+/// values can be retrieved after the assertion or compared in later ones.
+///
+/// ```
+/// use assert_json::assert_json;
+/// use assert_json::validators::Captures;
+///
+/// let captures = Captures::new();
+/// assert_json!(r#"{"order_id": "ord-42"}"#, {
+///     "order_id": captures.capture("order_id"),
+/// });
+/// assert_eq!(Some(serde_json::json!("ord-42")), captures.get("order_id"));
+///
+/// assert_json!(r#"{"order": {"id": "ord-42"}}"#, {
+///     "order": {
+///         "id": captures.eq("order_id"),
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Captures {
+    values: Rc<RefCell<HashMap<String, Value>>>,
+}
+
+impl Captures {
+    pub fn new() -> Captures {
+        Captures::default()
+    }
+
+    /// Match any value and store it under `name`.
+    ///
+    /// A value previously captured with the same name is replaced.
+    pub fn capture(&self, name: &str) -> impl Validator {
+        CaptureValidator {
+            captures: self.clone(),
+            name: String::from(name),
+        }
+    }
+
+    /// Match a value equals to the value captured under `name`.
+    ///
+    /// Fail if nothing was captured under `name` when validating.
+    pub fn eq(&self, name: &str) -> impl Validator {
+        CapturedEqValidator {
+            captures: self.clone(),
+            name: String::from(name),
+        }
+    }
+
+    /// Get the value captured under `name`.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.values.borrow().get(name).cloned()
+    }
+}
+
+struct CaptureValidator {
+    captures: Captures,
+    name: String,
+}
+
+impl Validator for CaptureValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.captures
+            .values
+            .borrow_mut()
+            .insert(self.name.clone(), value.clone());
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        format!("any value, captured as '{}'", self.name)
+    }
+}
+
+struct CapturedEqValidator {
+    captures: Captures,
+    name: String,
+}
+
+impl Validator for CapturedEqValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.captures.values.borrow().get(&self.name) {
+            Some(captured) if captured == value => Ok(()),
+            Some(captured) => Err(Error::InvalidValue(
+                value,
+                format!("captured '{}' ({})", self.name, captured),
+            )),
+            None => Err(Error::InvalidValue(
+                value,
+                format!(
+                    "a value captured as '{}' but nothing was captured",
+                    self.name
+                ),
+            )),
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("the value captured as '{}'", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Captures;
+    use crate::{Error, Validator};
+
+    #[test]
+    fn capture() {
+        let captures = Captures::new();
+        let validator = captures.capture("id");
+
+        assert_eq!(None, captures.get("id"));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+        assert_eq!(Some(serde_json::json!(5)), captures.get("id"));
+    }
+
+    #[test]
+    fn captured_eq() {
+        let captures = Captures::new();
+        let validator = captures.eq("id");
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!(5)),
+            Err(Error::InvalidValue(_, _))
+        ));
+
+        captures
+            .capture("id")
+            .validate(&serde_json::json!(5))
+            .unwrap();
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected captured 'id' (5) but got 6."
+            )),
+            validator
+                .validate(&serde_json::json!(6))
+                .map_err(|err| err.to_string())
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use crate::validators;
+use crate::Validator;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Match if string is a RFC 3339 datetime, like `2021-10-14T08:30:00.123+02:00`.
+pub fn is_datetime() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "date-time" }),
+        validators::described(
+            String::from("a RFC 3339 datetime"),
+            validators::string(|val| {
+                parse_rfc3339(val)
+                    .map(|_| ())
+                    .map_err(|err| format!("RFC 3339 datetime ({})", err))
+            }),
+        ),
+    )
+}
+
+/// Match if string is a RFC 3339 datetime within `tolerance` of the expected time.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+/// use std::time::{Duration, SystemTime};
+///
+/// let expected = SystemTime::UNIX_EPOCH + Duration::from_secs(1634200201);
+/// assert_json!(r#"{"created_at": "2021-10-14T08:30:00Z"}"#, {
+///     "created_at": validators::datetime_near(expected, Duration::from_secs(5)),
+/// });
+/// ```
+pub fn datetime_near(expected: SystemTime, tolerance: Duration) -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "date-time" }),
+        validators::described(
+            format!(
+                "a RFC 3339 datetime within {:?} of {}",
+                tolerance,
+                format_rfc3339(expected)
+            ),
+            validators::string(move |val| {
+                let actual =
+                    parse_rfc3339(val).map_err(|err| format!("RFC 3339 datetime ({})", err))?;
+                let difference = actual
+                    .duration_since(expected)
+                    .or_else(|_| expected.duration_since(actual))
+                    .unwrap_or_default();
+
+                if difference <= tolerance {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "datetime within {:?} of {} (off by {:?})",
+                        tolerance,
+                        format_rfc3339(expected),
+                        difference
+                    ))
+                }
+            }),
+        ),
+    )
+}
+
+/// How a datetime is represented in the JSON input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatetimeFormat {
+    /// A RFC 3339 string. Any offset is accepted as long as it denotes the
+    /// same instant.
+    Rfc3339,
+    /// An integer number of seconds since the Unix epoch.
+    EpochSeconds,
+    /// An integer number of milliseconds since the Unix epoch.
+    EpochMillis,
+}
+
+/// Match if the datetime is the expected time, in the given format.
+///
+/// Epoch formats are compared at their own precision, so the expected time is
+/// truncated to a whole number of seconds or milliseconds.
+///
+/// With the `chrono` and `time` features, `chrono::DateTime` and
+/// `time::OffsetDateTime` can also be used directly as expected values and
+/// are matched as RFC 3339 strings.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+/// use assert_json::validators::DatetimeFormat;
+/// use std::time::{Duration, SystemTime};
+///
+/// let expected = SystemTime::UNIX_EPOCH + Duration::from_secs(1634200200);
+/// assert_json!(r#"{"created_at": "2021-10-14T10:30:00+02:00", "updated_at": 1634200200}"#, {
+///     "created_at": validators::datetime_eq(expected, DatetimeFormat::Rfc3339),
+///     "updated_at": validators::datetime_eq(expected, DatetimeFormat::EpochSeconds),
+/// });
+/// ```
+pub fn datetime_eq<T>(expected: T, format: DatetimeFormat) -> Box<dyn Validator>
+where
+    T: Into<SystemTime>,
+{
+    let expected = expected.into();
+    match format {
+        DatetimeFormat::Rfc3339 => Box::new(validators::described(
+            format!("the RFC 3339 datetime {}", format_rfc3339(expected)),
+            validators::string(move |val| {
+                let actual =
+                    parse_rfc3339(val).map_err(|err| format!("RFC 3339 datetime ({})", err))?;
+                if actual == expected {
+                    Ok(())
+                } else {
+                    Err(format!("datetime {}", format_rfc3339(expected)))
+                }
+            }),
+        )),
+        DatetimeFormat::EpochSeconds => epoch_eq(expected, 1_000_000_000, "seconds"),
+        DatetimeFormat::EpochMillis => epoch_eq(expected, 1_000_000, "milliseconds"),
+    }
+}
+
+fn epoch_eq(expected: SystemTime, nanos_per_unit: i128, unit: &'static str) -> Box<dyn Validator> {
+    let nanos = match expected.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_nanos() as i128,
+        Err(err) => -(err.duration().as_nanos() as i128),
+    };
+    let expected_epoch = nanos.div_euclid(nanos_per_unit);
+    Box::new(validators::described(
+        format!(
+            "{} epoch {} ({})",
+            expected_epoch,
+            unit,
+            format_rfc3339(expected)
+        ),
+        validators::i64(move |actual| {
+            if i128::from(*actual) == expected_epoch {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} epoch {} ({})",
+                    expected_epoch,
+                    unit,
+                    format_rfc3339(expected)
+                ))
+            }
+        }),
+    ))
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for Box<dyn Validator>
+where
+    Tz: chrono::TimeZone,
+{
+    fn from(expected: chrono::DateTime<Tz>) -> Self {
+        datetime_eq(expected, DatetimeFormat::Rfc3339)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Box<dyn Validator> {
+    fn from(expected: time::OffsetDateTime) -> Self {
+        datetime_eq(expected, DatetimeFormat::Rfc3339)
+    }
+}
+
+/// Parse a RFC 3339 datetime.
+fn parse_rfc3339(input: &str) -> Result<SystemTime, String> {
+    let bytes = input.as_bytes();
+    let number = |range: std::ops::Range<usize>| -> Result<i64, String> {
+        let digits = bytes
+            .get(range.clone())
+            .filter(|digits| digits.iter().all(u8::is_ascii_digit))
+            .ok_or_else(|| format!("expected digits at position {}", range.start))?;
+        Ok(digits
+            .iter()
+            .fold(0, |acc, digit| acc * 10 + i64::from(digit - b'0')))
+    };
+    let separator = |position: usize, allowed: &[u8]| -> Result<(), String> {
+        match bytes.get(position) {
+            Some(c) if allowed.contains(c) => Ok(()),
+            _ => Err(format!(
+                "expected {:?} at position {}",
+                allowed[0] as char, position
+            )),
+        }
+    };
+
+    let year = number(0..4)?;
+    separator(4, b"-")?;
+    let month = number(5..7)?;
+    separator(7, b"-")?;
+    let day = number(8..10)?;
+    separator(10, b"Tt ")?;
+    let hour = number(11..13)?;
+    separator(13, b":")?;
+    let minute = number(14..16)?;
+    separator(16, b":")?;
+    let second = number(17..19)?;
+
+    if !(1..=12).contains(&month) {
+        return Err(format!("invalid month {}", month));
+    }
+    if day < 1 || day > days_in_month(year, month) {
+        return Err(format!("invalid day {}", day));
+    }
+    // A second of 60 is allowed for leap seconds.
+    if hour > 23 || minute > 59 || second > 60 {
+        return Err(format!(
+            "invalid time {:02}:{:02}:{:02}",
+            hour, minute, second
+        ));
+    }
+
+    let mut position = 19;
+    let mut nanos = 0;
+    if bytes.get(position) == Some(&b'.') {
+        let start = position + 1;
+        let end = start
+            + bytes[start..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+        if end == start {
+            return Err(format!("expected digits at position {}", start));
+        }
+        // Only keep the nanosecond precision.
+        let digits = &input[start..end.min(start + 9)];
+        nanos = format!("{:0<9}", digits).parse::<u32>().unwrap();
+        position = end;
+    }
+
+    let offset_seconds = match bytes.get(position) {
+        Some(b'Z') | Some(b'z') if position + 1 == bytes.len() => 0,
+        Some(sign @ (b'+' | b'-')) if position + 6 == bytes.len() => {
+            let offset_hour = number(position + 1..position + 3)?;
+            separator(position + 3, b":")?;
+            let offset_minute = number(position + 4..position + 6)?;
+            if offset_hour > 23 || offset_minute > 59 {
+                return Err(format!("invalid offset {}", &input[position..]));
+            }
+            let offset = offset_hour * 3600 + offset_minute * 60;
+            if *sign == b'+' {
+                offset
+            } else {
+                -offset
+            }
+        }
+        _ => return Err(format!("expected timezone offset at position {}", position)),
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second
+        - offset_seconds;
+    let time = if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    };
+    Ok(time + Duration::from_nanos(u64::from(nanos)))
+}
+
+/// Format a time as a RFC 3339 UTC datetime.
+fn format_rfc3339(time: SystemTime) -> String {
+    let (seconds, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(err) => {
+            let duration = err.duration();
+            match duration.subsec_nanos() {
+                0 => (-(duration.as_secs() as i64), 0),
+                nanos => (-(duration.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let time_of_day = seconds.rem_euclid(86400);
+    let fraction = if nanos == 0 {
+        String::new()
+    } else {
+        format!(".{:09}", nanos).trim_end_matches('0').to_string()
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year,
+        month,
+        day,
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60,
+        fraction
+    )
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since 1970-01-01 of a proleptic Gregorian date.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Inverse of [days_from_civil].
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn parse_rfc3339() {
+        assert_eq!(Ok(UNIX_EPOCH), super::parse_rfc3339("1970-01-01T00:00:00Z"));
+        assert_eq!(
+            Ok(UNIX_EPOCH + Duration::from_millis(1634200200123)),
+            super::parse_rfc3339("2021-10-14T10:30:00.123+02:00")
+        );
+        assert_eq!(
+            Ok(UNIX_EPOCH - Duration::from_secs(1)),
+            super::parse_rfc3339("1969-12-31t23:59:59z")
+        );
+        assert!(super::parse_rfc3339("2021-02-29T00:00:00Z").is_err());
+        assert!(super::parse_rfc3339("2021-10-14T10:30:00").is_err());
+        assert!(super::parse_rfc3339("2021-10-14").is_err());
+        assert!(super::parse_rfc3339("2021-10-14T10:30:00.Z").is_err());
+    }
+
+    #[test]
+    fn format_rfc3339() {
+        assert_eq!(
+            "2021-10-14T08:30:00.123Z",
+            super::format_rfc3339(UNIX_EPOCH + Duration::from_millis(1634200200123))
+        );
+        assert_eq!(
+            "1969-12-31T23:59:59.5Z",
+            super::format_rfc3339(UNIX_EPOCH - Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn is_datetime() {
+        let validator = super::is_datetime();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("2021-10-14T08:30:00Z"))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("yesterday")),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn datetime_near() {
+        let expected = UNIX_EPOCH + Duration::from_secs(1634200200);
+        let validator = super::datetime_near(expected, Duration::from_secs(1));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("2021-10-14T08:30:01Z"))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected datetime within 1s of 2021-10-14T08:30:00Z (off by 2s) \
+                but got \"2021-10-14T08:29:58Z\"."
+            )),
+            validator
+                .validate(&serde_json::json!("2021-10-14T08:29:58Z"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn datetime_eq_rfc3339() {
+        let expected = UNIX_EPOCH + Duration::from_millis(1634200200500);
+        let validator = super::datetime_eq(expected, super::DatetimeFormat::Rfc3339);
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("2021-10-14T10:30:00.5+02:00"))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected datetime 2021-10-14T08:30:00.5Z \
+                but got \"2021-10-14T08:30:00Z\"."
+            )),
+            validator
+                .validate(&serde_json::json!("2021-10-14T08:30:00Z"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn datetime_eq_epoch() {
+        let expected = UNIX_EPOCH + Duration::from_millis(1634200200500);
+        let seconds = super::datetime_eq(expected, super::DatetimeFormat::EpochSeconds);
+        let millis = super::datetime_eq(expected, super::DatetimeFormat::EpochMillis);
+
+        assert_eq!(Ok(()), seconds.validate(&serde_json::json!(1634200200)));
+        assert_eq!(
+            Ok(()),
+            millis.validate(&serde_json::json!(1634200200500_i64))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected 1634200200500 epoch milliseconds \
+                (2021-10-14T08:30:00.5Z) but got 1634200200000."
+            )),
+            millis
+                .validate(&serde_json::json!(1634200200000_i64))
+                .map_err(|err| err.to_string())
+        );
+        assert!(matches!(
+            seconds.validate(&serde_json::json!("1634200200")),
+            Err(Error::InvalidType(_, _))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_datetime() {
+        let expected: Box<dyn Validator> = chrono::DateTime::from_timestamp(1634200200, 0)
+            .unwrap()
+            .into();
+
+        assert_eq!(
+            Ok(()),
+            expected.validate(&serde_json::json!("2021-10-14T08:30:00Z"))
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_offset_datetime() {
+        let expected: Box<dyn Validator> = time::OffsetDateTime::from_unix_timestamp(1634200200)
+            .unwrap()
+            .into();
+
+        assert_eq!(
+            Ok(()),
+            expected.validate(&serde_json::json!("2021-10-14T10:30:00+02:00"))
+        );
+    }
+}
@@ -0,0 +1,529 @@
+use crate::validators;
+use crate::{Error, Validator, Value};
+
+/// Match if string is made of hexadecimal digits, in lowercase or uppercase.
+pub fn is_hex() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "pattern": "^[0-9a-fA-F]*$" }),
+        validators::described(
+            String::from("a hexadecimal string"),
+            validators::string(|val| {
+                check_hex(val).map_err(|err| format!("hexadecimal string ({})", err))
+            }),
+        ),
+    )
+}
+
+/// Match if string is made of exactly `expected` hexadecimal digits.
+///
+/// For example, a SHA-256 digest is `hex_len(64)`.
+pub fn hex_len(expected: usize) -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({
+            "type": "string",
+            "pattern": format!("^[0-9a-fA-F]{{{}}}$", expected),
+        }),
+        validators::described(
+            format!("a hexadecimal string of length {}", expected),
+            validators::string(move |val| {
+                check_hex(val).map_err(|err| format!("hexadecimal string ({})", err))?;
+                let len = val.chars().count();
+                if len == expected {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "hexadecimal string of length {} (actual length {})",
+                        expected, len
+                    ))
+                }
+            }),
+        ),
+    )
+}
+
+fn check_hex(val: &str) -> Result<(), String> {
+    match val
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_hexdigit())
+    {
+        Some((position, c)) => Err(format!(
+            "invalid character {:?} at position {}",
+            c, position
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Match if string is base64 encoded, with the standard alphabet.
+///
+/// Padding is optional.
+pub fn is_base64() -> impl Validator {
+    validators::described(
+        String::from("a base64 string"),
+        validators::string(|val| {
+            decode_base64(val, false)
+                .map(|_| ())
+                .map_err(|err| format!("base64 string ({})", err))
+        }),
+    )
+}
+
+/// Match if string is base64 encoded and the decoded UTF-8 text match the validator.
+///
+/// The decoded text is validated as a JSON string.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#""aGVsbG8gd29ybGQ=""#, validators::base64_decoded(validators::starts_with("hello")));
+/// ```
+pub fn base64_decoded(validator: impl Validator) -> impl Validator {
+    DecodedValidator {
+        decode: |val: &str| {
+            let bytes =
+                decode_base64(val, false).map_err(|err| format!("base64 string ({})", err))?;
+            String::from_utf8(bytes)
+                .map(Value::String)
+                .map_err(|_| String::from("base64 encoded UTF-8 text"))
+        },
+        description: "base64 decoded text",
+        validator,
+    }
+}
+
+/// Match if string is base64 encoded and the decoded bytes match the validator.
+///
+/// The decoded bytes are validated as a JSON array of numbers, so binary
+/// payloads which are not UTF-8 text can be checked too.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#""iVBORw==""#,
+///     validators::base64_bytes(validators::eq(vec![0x89, 0x50, 0x4e, 0x47]))
+/// );
+/// ```
+pub fn base64_bytes(validator: impl Validator) -> impl Validator {
+    DecodedValidator {
+        decode: |val: &str| {
+            let bytes =
+                decode_base64(val, false).map_err(|err| format!("base64 string ({})", err))?;
+            Ok(Value::Array(bytes.into_iter().map(Value::from).collect()))
+        },
+        description: "base64 decoded bytes",
+        validator,
+    }
+}
+
+/// Match if string is base64 encoded and the decoded JSON document match the validator.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"payload": "eyJpZCI6IDV9"}"#,
+///     { "payload": validators::base64_json(validators::at("/id", validators::eq(5))) }
+/// );
+/// ```
+pub fn base64_json(validator: impl Validator) -> impl Validator {
+    DecodedValidator {
+        decode: |val: &str| {
+            let bytes =
+                decode_base64(val, false).map_err(|err| format!("base64 string ({})", err))?;
+            serde_json::from_slice(&bytes).map_err(|err| format!("base64 encoded JSON ({})", err))
+        },
+        description: "base64 decoded JSON",
+        validator,
+    }
+}
+
+/// Match if string is a JSON document matching the validator.
+///
+/// Errors report the JSON pointer of the failing node inside the embedded document.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"body": "{\"id\": 5}"}"#,
+///     { "body": validators::json_string(validators::at("/id", validators::eq(5))) }
+/// );
+/// ```
+pub fn json_string(validator: impl Validator) -> impl Validator {
+    DecodedValidator {
+        decode: |val: &str| {
+            serde_json::from_str(val).map_err(|err| format!("JSON string ({})", err))
+        },
+        description: "embedded JSON",
+        validator,
+    }
+}
+
+/// Match if string is a JSON Web Token made of a JSON header and JSON claims.
+///
+/// The signature is not verified.
+#[cfg(feature = "jwt")]
+pub fn is_jwt() -> impl Validator {
+    // Random segments are hardly JSON: the example, an unsecured JWT of
+    // `{"alg":"none"}` and `{}`, is what generation picks.
+    validators::with_schema(
+        serde_json::json!({
+            "type": "string",
+            "pattern": r"^[A-Za-z0-9_-]*={0,2}\.[A-Za-z0-9_-]*={0,2}\.[A-Za-z0-9_-]*={0,2}$",
+            "examples": ["eyJhbGciOiJub25lIn0.e30."],
+        }),
+        validators::described(
+            String::from("a JWT"),
+            validators::string(|val| decode_jwt(val).map(|_| ())),
+        ),
+    )
+}
+
+/// Match if string is a JSON Web Token whose header match the validator.
+#[cfg(feature = "jwt")]
+pub fn jwt_header(validator: impl Validator) -> impl Validator {
+    DecodedValidator {
+        decode: |val: &str| decode_jwt(val).map(|(header, _)| header),
+        description: "JWT header",
+        validator,
+    }
+}
+
+/// Match if string is a JSON Web Token whose claims match the validator.
+///
+/// The signature is not verified.
+///
+/// ```
+/// use assert_json::{assert_json, validators, Validator};
+///
+/// let token = "eyJhbGciOiJub25lIn0.eyJzdWIiOiJ1c2VyLTEiLCJhZG1pbiI6dHJ1ZX0.";
+/// assert_json!(
+///     serde_json::json!({ "access_token": token }),
+///     {
+///         "access_token": validators::jwt_claims(validators::object_contains(vec![
+///             ("sub", Box::new(validators::eq("user-1")) as Box<dyn Validator>),
+///             ("admin", Box::new(validators::eq(true))),
+///         ])),
+///     }
+/// );
+/// ```
+#[cfg(feature = "jwt")]
+pub fn jwt_claims(validator: impl Validator) -> impl Validator {
+    DecodedValidator {
+        decode: |val: &str| decode_jwt(val).map(|(_, claims)| claims),
+        description: "JWT claims",
+        validator,
+    }
+}
+
+/// Split a JWT and decode its header and claims.
+#[cfg(feature = "jwt")]
+fn decode_jwt(val: &str) -> Result<(Value, Value), String> {
+    let segments: Vec<&str> = val.split('.').collect();
+    if segments.len() != 3 {
+        return Err(String::from("JWT made of three segments"));
+    }
+
+    let decode_segment = |segment: &str, name: &str| -> Result<Value, String> {
+        let bytes = decode_base64(segment, true)
+            .map_err(|err| format!("JWT with a base64url {} ({})", name, err))?;
+        match serde_json::from_slice(&bytes) {
+            Ok(value @ Value::Object(_)) => Ok(value),
+            _ => Err(format!("JWT with a JSON object {}", name)),
+        }
+    };
+    let header = decode_segment(segments[0], "header")?;
+    let claims = decode_segment(segments[1], "claims")?;
+    decode_base64(segments[2], true)
+        .map_err(|err| format!("JWT with a base64url signature ({})", err))?;
+    Ok((header, claims))
+}
+
+/// Decode a string value and validate the decoded value.
+struct DecodedValidator<F, T>
+where
+    F: Fn(&str) -> Result<Value, String>,
+    T: Validator,
+{
+    decode: F,
+    description: &'static str,
+    validator: T,
+}
+
+impl<F, T> Validator for DecodedValidator<F, T>
+where
+    F: Fn(&str) -> Result<Value, String>,
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let string_value = value
+            .as_str()
+            .ok_or_else(|| Error::InvalidType(value, String::from("string")))?;
+        let decoded = (self.decode)(string_value).map_err(|msg| Error::InvalidValue(value, msg))?;
+
+        // The decoded value only lives in this function so the error can't point to it.
+        self.validator.validate(&decoded).map_err(|err| {
+            let msg = match err.pointer(&decoded) {
+                Some(pointer) if !pointer.is_empty() => format!("at {}: {}", pointer, err),
+                _ => err.to_string(),
+            };
+            Error::InvalidValue(value, format!("{} to be valid ({})", self.description, msg))
+        })
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "a string whose {} is {}",
+            self.description,
+            self.validator.describe()
+        )
+    }
+}
+
+/// Decode base64 with the standard alphabet, or the URL-safe one if `url_safe` is set.
+///
+/// Padding is optional.
+pub(crate) fn decode_base64(input: &str, url_safe: bool) -> Result<Vec<u8>, String> {
+    let (c62, c63) = if url_safe { (b'-', b'_') } else { (b'+', b'/') };
+    let data = input.trim_end_matches('=');
+    if input.len() - data.len() > 2 {
+        return Err(String::from("too much padding"));
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for (position, c) in data.bytes().enumerate() {
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            c if c == c62 => 62,
+            c if c == c63 => 63,
+            _ => return Err(format!("invalid character at position {}", position)),
+        };
+        buffer = (buffer << 6) | u32::from(sextet);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    if data.len() % 4 == 1 {
+        return Err(String::from("invalid length"));
+    }
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(String::from("invalid trailing bits"));
+    }
+    if input.len() != data.len() && !input.len().is_multiple_of(4) {
+        return Err(String::from("invalid padding"));
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validators;
+    use crate::{Error, Validator};
+
+    #[test]
+    fn is_hex() {
+        let validator = super::is_hex();
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("00ff9aAF")));
+        assert_eq!(
+            serde_json::json!({ "type": "string", "pattern": "^[0-9a-fA-F]*$" }),
+            validator.to_json_schema()
+        );
+        assert!(validator.generate().is_some());
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected hexadecimal string (invalid character 'g' at position 3) \
+                but got \"00fg\"."
+            )),
+            validator
+                .validate(&serde_json::json!("00fg"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn hex_len() {
+        let validator = super::hex_len(4);
+        assert_eq!(
+            serde_json::json!({ "type": "string", "pattern": "^[0-9a-fA-F]{4}$" }),
+            validator.to_json_schema()
+        );
+        assert_eq!(Some(serde_json::json!("0000")), validator.generate());
+        for seed in 0..16 {
+            assert!(validator.generate_seeded(seed).is_some());
+        }
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("beef")));
+        assert!(validator.validate(&serde_json::json!("bee")).is_err());
+        assert!(validator.validate(&serde_json::json!("beer")).is_err());
+    }
+
+    #[test]
+    fn decode_base64() {
+        assert_eq!(
+            Ok(b"hello".to_vec()),
+            super::decode_base64("aGVsbG8=", false)
+        );
+        assert_eq!(
+            Ok(b"hello".to_vec()),
+            super::decode_base64("aGVsbG8", false)
+        );
+        assert_eq!(Ok(vec![0xfb, 0xff]), super::decode_base64("+/8=", false));
+        assert_eq!(Ok(vec![0xfb, 0xff]), super::decode_base64("-_8", true));
+        assert_eq!(Ok(vec![]), super::decode_base64("", false));
+        assert!(super::decode_base64("-_8", false).is_err());
+        assert!(super::decode_base64("aGVsbG8===", false).is_err());
+        assert!(super::decode_base64("aGVsb", false).is_err());
+        assert!(super::decode_base64("aGVsbG==", false).is_err());
+    }
+
+    #[test]
+    fn is_base64() {
+        let validator = super::is_base64();
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("aGVsbG8=")));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected base64 string (invalid character at position 4) but got \"aGVs*G8=\"."
+            )),
+            validator
+                .validate(&serde_json::json!("aGVs*G8="))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn base64_decoded() {
+        let validator = super::base64_decoded(validators::eq("hello"));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("aGVsbG8=")));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected base64 decoded text to be valid \
+                (Invalid value. Expected \"hello\" but got \"world\".) but got \"d29ybGQ=\"."
+            )),
+            validator
+                .validate(&serde_json::json!("d29ybGQ="))
+                .map_err(|err| err.to_string())
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("/w==")),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn base64_bytes() {
+        let validator = super::base64_bytes(validators::eq(vec![0xff, 0x00, 0xfe]));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("/wD+")));
+        assert!(matches!(
+            validator.validate(&serde_json::json!("/w==")),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert_eq!(
+            Ok(()),
+            super::base64_bytes(validators::array_size(2)).validate(&serde_json::json!("/wA="))
+        );
+    }
+
+    #[test]
+    fn base64_json() {
+        let validator = super::base64_json(validators::at("/id", validators::eq(5)));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("eyJpZCI6IDV9"))
+        );
+        assert!(validator.validate(&serde_json::json!("aGVsbG8=")).is_err());
+    }
+
+    #[test]
+    fn json_string() {
+        let validator = super::json_string(validators::object_contains(vec![(
+            "items",
+            Box::new(validators::array_each(validators::gt(0))) as Box<dyn Validator>,
+        )]));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!(r#"{"items": [1, 2]}"#))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected embedded JSON to be valid (at /items/1: \
+                Invalid array element at index 1. Invalid value. Expected number > 0 but got 0.) \
+                but got \"{\\\"items\\\": [1, 0]}\"."
+            )),
+            validator
+                .validate(&serde_json::json!(r#"{"items": [1, 0]}"#))
+                .map_err(|err| err.to_string())
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("{")),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[cfg(feature = "jwt")]
+    const TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+        SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+
+    #[test]
+    #[cfg(feature = "jwt")]
+    fn is_jwt() {
+        let validator = super::is_jwt();
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(TOKEN)));
+        assert_eq!(
+            Some(&serde_json::json!(
+                r"^[A-Za-z0-9_-]*={0,2}\.[A-Za-z0-9_-]*={0,2}\.[A-Za-z0-9_-]*={0,2}$"
+            )),
+            validator.to_json_schema().get("pattern")
+        );
+        assert_eq!(
+            Some(serde_json::json!("eyJhbGciOiJub25lIn0.e30.")),
+            validator.generate()
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected JWT made of three segments but got \"abc.def\"."
+            )),
+            validator
+                .validate(&serde_json::json!("abc.def"))
+                .map_err(|err| err.to_string())
+        );
+        assert!(validator
+            .validate(&serde_json::json!("aGVsbG8.aGVsbG8."))
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "jwt")]
+    fn jwt_header_and_claims() {
+        let value = serde_json::json!(TOKEN);
+
+        assert_eq!(
+            Ok(()),
+            super::jwt_header(validators::at("/alg", validators::eq("HS256"))).validate(&value)
+        );
+        assert_eq!(
+            Ok(()),
+            super::jwt_claims(validators::at("/name", validators::eq("John Doe"))).validate(&value)
+        );
+        assert!(matches!(
+            super::jwt_claims(validators::at("/name", validators::eq("Jane Doe"))).validate(&value),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+}
@@ -0,0 +1,453 @@
+use crate::validators;
+use crate::Validator;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Match if string is a hyphenated UUID, like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///
+/// Both lowercase and uppercase hexadecimal digits are accepted.
+pub fn is_uuid() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "uuid" }),
+        validators::described(
+            String::from("a UUID"),
+            validators::string(|val| parse_uuid(val).map(|_| ())),
+        ),
+    )
+}
+
+/// Match if string is a hyphenated UUID of the expected version.
+pub fn is_uuid_version(version: u8) -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "uuid" }),
+        validators::described(
+            format!("a UUID of version {}", version),
+            validators::string(move |val| {
+                let actual = parse_uuid(val)?;
+                if actual == version {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "UUID version {} (actual version {})",
+                        version, actual
+                    ))
+                }
+            }),
+        ),
+    )
+}
+
+/// How a UUID is represented in the JSON input.
+#[cfg(feature = "uuid")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UuidFormat {
+    /// Like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Hyphenated,
+    /// Like `67e5504410b1426f9247bb680e5fe0c8`.
+    Simple,
+    /// Like `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Urn,
+    /// Like `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    Braced,
+}
+
+/// Match if string is the expected UUID, in the given format.
+///
+/// Hexadecimal digits are compared case-insensitively. A [uuid::Uuid] can also
+/// be used directly as an expected value, which matches its hyphenated form.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+/// use assert_json::validators::UuidFormat;
+///
+/// let id = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// assert_json!(r#"{"id": "67e55044-10b1-426f-9247-bb680e5fe0c8", "ref": "67e5504410b1426f9247bb680e5fe0c8"}"#, {
+///     "id": id,
+///     "ref": validators::uuid_eq(id, UuidFormat::Simple),
+/// });
+/// ```
+#[cfg(feature = "uuid")]
+pub fn uuid_eq(expected: uuid::Uuid, format: UuidFormat) -> impl Validator {
+    let expected = match format {
+        UuidFormat::Hyphenated => expected.hyphenated().to_string(),
+        UuidFormat::Simple => expected.simple().to_string(),
+        UuidFormat::Urn => expected.urn().to_string(),
+        UuidFormat::Braced => expected.braced().to_string(),
+    };
+    validators::described(
+        format!("the UUID {}", expected),
+        validators::string(move |val| {
+            if val.eq_ignore_ascii_case(&expected) {
+                Ok(())
+            } else {
+                Err(format!("UUID {}", expected))
+            }
+        }),
+    )
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Box<dyn Validator> {
+    fn from(expected: uuid::Uuid) -> Self {
+        Box::new(uuid_eq(expected, UuidFormat::Hyphenated))
+    }
+}
+
+/// Check the UUID format and return its version.
+fn parse_uuid(val: &str) -> Result<u8, String> {
+    let is_uuid = val.len() == 36
+        && val.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        });
+
+    if is_uuid {
+        Ok(val[14..15]
+            .parse::<u8>()
+            .unwrap_or_else(|_| u8::from_str_radix(&val[14..15], 16).expect("hexadecimal digit")))
+    } else {
+        Err(String::from("UUID"))
+    }
+}
+
+/// Match if string looks like an email address.
+///
+/// This check is lenient: the string needs a non-empty local part, a single `@`
+/// and a domain containing a dot, without any whitespace. Use [is_email_strict]
+/// to follow the RFC 5322 address syntax.
+pub fn is_email() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "email" }),
+        validators::described(
+            String::from("an email address"),
+            validators::string(|val| {
+                let is_email = match val.split_once('@') {
+                    Some((local, domain)) => {
+                        !local.is_empty()
+                            && !domain.contains('@')
+                            && domain.contains('.')
+                            && !domain.starts_with('.')
+                            && !domain.ends_with('.')
+                            && !val.chars().any(char::is_whitespace)
+                    }
+                    None => false,
+                };
+
+                if is_email {
+                    Ok(())
+                } else {
+                    Err(String::from("email address"))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string is an email address following the RFC 5322 `addr-spec` syntax.
+///
+/// The local part is either a dot-atom or a quoted string and the domain is
+/// either a hostname or a domain literal like `[192.168.0.1]`. Comments and
+/// folding whitespace are not supported.
+pub fn is_email_strict() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "email" }),
+        validators::described(
+            String::from("an RFC 5322 email address"),
+            validators::string(|val| {
+                check_email_strict(val).map_err(|err| format!("RFC 5322 email address ({})", err))
+            }),
+        ),
+    )
+}
+
+fn check_email_strict(val: &str) -> Result<(), &'static str> {
+    let at = val.rfind('@').ok_or("missing '@'")?;
+    let (local, domain) = (&val[..at], &val[at + 1..]);
+
+    if val.len() > 254 {
+        return Err("address is longer than 254 characters");
+    }
+    if local.is_empty() || local.len() > 64 {
+        return Err("local part should have between 1 and 64 characters");
+    }
+
+    if local.starts_with('"') {
+        let content = local
+            .strip_prefix('"')
+            .and_then(|local| local.strip_suffix('"'))
+            .ok_or("unterminated quoted local part")?;
+        let mut chars = content.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next().ok_or("unterminated escape in local part")?;
+                }
+                '"' => return Err("unescaped quote in local part"),
+                c if c.is_ascii_control() => return Err("control character in local part"),
+                _ => {}
+            }
+        }
+    } else {
+        let is_atext = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c);
+        if local
+            .split('.')
+            .any(|atom| atom.is_empty() || !atom.chars().all(is_atext))
+        {
+            return Err("invalid local part");
+        }
+    }
+
+    if let Some(literal) = domain.strip_prefix('[') {
+        let literal = literal
+            .strip_suffix(']')
+            .ok_or("unterminated domain literal")?;
+        let is_address = literal.parse::<Ipv4Addr>().is_ok()
+            || literal
+                .strip_prefix("IPv6:")
+                .is_some_and(|ipv6| ipv6.parse::<Ipv6Addr>().is_ok());
+        return if is_address {
+            Ok(())
+        } else {
+            Err("invalid domain literal")
+        };
+    }
+
+    let is_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if domain.split('.').all(is_label) {
+        Ok(())
+    } else {
+        Err("invalid domain")
+    }
+}
+
+/// Match if string is an IPv4 address, like `192.168.0.1`.
+pub fn is_ipv4() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "ipv4" }),
+        validators::described(
+            String::from("an IPv4 address"),
+            validators::string(|val| {
+                val.parse::<Ipv4Addr>()
+                    .map(|_| ())
+                    .map_err(|err| format!("IPv4 address ({})", err))
+            }),
+        ),
+    )
+}
+
+/// Match if string is an IPv6 address, like `::1`.
+pub fn is_ipv6() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "ipv6" }),
+        validators::described(
+            String::from("an IPv6 address"),
+            validators::string(|val| {
+                val.parse::<Ipv6Addr>()
+                    .map(|_| ())
+                    .map_err(|err| format!("IPv6 address ({})", err))
+            }),
+        ),
+    )
+}
+
+/// Match if string is either an IPv4 or an IPv6 address.
+pub fn is_ip() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "anyOf": [{ "format": "ipv4" }, { "format": "ipv6" }] }),
+        validators::described(
+            String::from("an IP address"),
+            validators::string(|val| {
+                val.parse::<IpAddr>()
+                    .map(|_| ())
+                    .map_err(|err| format!("IP address ({})", err))
+            }),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+
+    #[test]
+    fn is_uuid() {
+        let validator = super::is_uuid();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+        );
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("67E55044-10B1-426F-9247-BB680E5FE0C8"))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("67e55044-10b1-426f-9247-bb680e5fe0c")),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert!(validator
+            .validate(&serde_json::json!("67e55044x10b1-426f-9247-bb680e5fe0c8"))
+            .is_err());
+        assert!(validator
+            .validate(&serde_json::json!("67e55044-10b1-426f-9247-bb680e5fe0cg"))
+            .is_err());
+    }
+
+    #[test]
+    fn is_uuid_version() {
+        let validator = super::is_uuid_version(4);
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected UUID version 4 (actual version 1) \
+                but got \"c232ab00-9414-11ec-b3c8-9f6bdeced846\"."
+            )),
+            validator
+                .validate(&serde_json::json!("c232ab00-9414-11ec-b3c8-9f6bdeced846"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn is_email() {
+        let validator = super::is_email();
+
+        for valid in [
+            "user@example.com",
+            "first.last+tag@sub.example.org",
+            "\"quoted\"@example.com",
+        ] {
+            assert_eq!(
+                Ok(()),
+                validator.validate(&serde_json::json!(valid)),
+                "{}",
+                valid
+            );
+        }
+        for invalid in [
+            "user",
+            "@example.com",
+            "user@localhost",
+            "a b@example.com",
+            "a@b@c.com",
+            "user@.com",
+        ] {
+            assert!(
+                matches!(
+                    validator.validate(&serde_json::json!(invalid)),
+                    Err(Error::InvalidValue(_, _))
+                ),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn is_email_strict() {
+        let validator = super::is_email_strict();
+
+        for valid in [
+            "user@example.com",
+            "user@localhost",
+            "first.last+tag@sub.example.org",
+            "\"john doe\"@example.com",
+            "user@[192.168.0.1]",
+            "user@[IPv6:::1]",
+        ] {
+            assert_eq!(
+                Ok(()),
+                validator.validate(&serde_json::json!(valid)),
+                "{}",
+                valid
+            );
+        }
+        for invalid in [
+            "user",
+            ".user@example.com",
+            "us..er@example.com",
+            "us er@example.com",
+            "user@-example.com",
+            "user@example..com",
+            "user@[300.0.0.1]",
+            "\"unterminated@example.com",
+        ] {
+            assert!(
+                validator.validate(&serde_json::json!(invalid)).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn is_ip() {
+        let ipv4 = serde_json::json!("192.168.0.1");
+        let ipv6 = serde_json::json!("2001:db8::1");
+
+        assert_eq!(Ok(()), super::is_ipv4().validate(&ipv4));
+        assert!(super::is_ipv4().validate(&ipv6).is_err());
+        assert_eq!(Ok(()), super::is_ipv6().validate(&ipv6));
+        assert!(super::is_ipv6().validate(&ipv4).is_err());
+        assert_eq!(Ok(()), super::is_ip().validate(&ipv4));
+        assert_eq!(Ok(()), super::is_ip().validate(&ipv6));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected IPv4 address (invalid IPv4 address syntax) but got \"256.0.0.1\"."
+            )),
+            super::is_ipv4()
+                .validate(&serde_json::json!("256.0.0.1"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_eq() {
+        use super::UuidFormat;
+
+        let expected = uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+
+        assert_eq!(
+            Ok(()),
+            super::uuid_eq(expected, UuidFormat::Hyphenated)
+                .validate(&serde_json::json!("67E55044-10B1-426F-9247-BB680E5FE0C8"))
+        );
+        assert_eq!(
+            Ok(()),
+            super::uuid_eq(expected, UuidFormat::Urn).validate(&serde_json::json!(
+                "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
+            ))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected UUID 67e5504410b1426f9247bb680e5fe0c8 \
+                but got \"67e55044-10b1-426f-9247-bb680e5fe0c8\"."
+            )),
+            super::uuid_eq(expected, UuidFormat::Simple)
+                .validate(&serde_json::json!("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_into_validator() {
+        let expected: Box<dyn Validator> =
+            uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8).into();
+
+        assert_eq!(
+            Ok(()),
+            expected.validate(&serde_json::json!("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+        );
+    }
+}
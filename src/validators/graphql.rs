@@ -0,0 +1,344 @@
+use crate::{Error, Validator, Value};
+
+/// Match if the `data` of a GraphQL response match the validator.
+///
+/// Errors are located under `/data`, like `/data/user/name`. When `data` is
+/// null because the operation failed, the failure reports the GraphQL errors
+/// instead. Partial responses, with both data and errors, are accepted:
+/// combine with [graphql_no_errors] to reject them.
+///
+/// ```
+/// use assert_json::{assert_json, validators, Validator};
+///
+/// assert_json!(
+///     r#"{"data": {"user": {"id": "1", "name": "charlesvdv"}}}"#,
+///     validators::graphql_data(validators::at("/user/id", validators::eq("1")))
+///         .and(validators::graphql_no_errors())
+/// );
+/// ```
+pub fn graphql_data(validator: impl Validator) -> impl Validator {
+    GraphqlDataValidator { validator }
+}
+
+/// Match if a GraphQL response has no `errors`.
+///
+/// Each error is reported at its location in `errors`, with its message, its
+/// path in the operation, like `user.friends[0].name`, and its code.
+pub fn graphql_no_errors() -> impl Validator {
+    GraphqlNoErrorsValidator
+}
+
+/// Match if a GraphQL response has an error whose `extensions.code` is `code`.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{
+///         "data": {"user": null},
+///         "errors": [{
+///             "message": "not found",
+///             "path": ["user"],
+///             "extensions": {"code": "NOT_FOUND"}
+///         }]
+///     }"#,
+///     validators::graphql_error_with("NOT_FOUND")
+/// );
+/// ```
+pub fn graphql_error_with(code: &str) -> impl Validator {
+    GraphqlErrorValidator {
+        code: String::from(code),
+    }
+}
+
+/// The `errors` of a GraphQL response, empty if there are none.
+fn errors<'a>(value: &'a Value) -> Result<&'a [Value], Error<'a>> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
+    match object.get("errors") {
+        None | Some(Value::Null) => Ok(&[]),
+        Some(Value::Array(errors)) => Ok(errors),
+        Some(errors) => Err(Error::InvalidType(errors, String::from("array"))),
+    }
+}
+
+/// Summarize a GraphQL error, like `"not found" at user.friends[0] (NOT_FOUND)`.
+fn summary(error: &Value) -> String {
+    let mut summary = match error.get("message") {
+        Some(Value::String(message)) => format!("{:?}", message),
+        _ => error.to_string(),
+    };
+    if let Some(Value::Array(path)) = error.get("path") {
+        let mut operation_path = String::new();
+        for segment in path {
+            match segment {
+                Value::String(field) if operation_path.is_empty() => operation_path.push_str(field),
+                Value::String(field) => {
+                    operation_path.push('.');
+                    operation_path.push_str(field);
+                }
+                index => operation_path.push_str(&format!("[{}]", index)),
+            }
+        }
+        summary.push_str(&format!(" at {}", operation_path));
+    }
+    if let Some(code) = code(error) {
+        summary.push_str(&format!(" ({})", code));
+    }
+    summary
+}
+
+fn code(error: &Value) -> Option<&str> {
+    error.pointer("/extensions/code").and_then(Value::as_str)
+}
+
+struct GraphqlDataValidator<T>
+where
+    T: Validator,
+{
+    validator: T,
+}
+
+impl<T> Validator for GraphqlDataValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let errors = errors(value)?;
+        match value.get("data") {
+            None => Err(Error::MissingObjectKey(value, String::from("data"))),
+            Some(data @ Value::Null) if !errors.is_empty() => {
+                let summaries: Vec<String> = errors.iter().map(summary).collect();
+                Err(Error::InvalidValue(
+                    data,
+                    format!(
+                        "GraphQL data (the operation failed with {})",
+                        summaries.join(", ")
+                    ),
+                ))
+            }
+            Some(data) => self.validator.validate(data),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        match value.get("data") {
+            Some(data) if !data.is_null() && errors(value).is_ok() => {
+                self.validator.validate_all(data)
+            }
+            _ => self.validate(value).err().into_iter().collect(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("a GraphQL response with data {}", self.validator.describe())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": { "data": self.validator.to_json_schema() },
+            "required": ["data"],
+        })
+    }
+}
+
+struct GraphqlNoErrorsValidator;
+
+impl Validator for GraphqlNoErrorsValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.validate_all(value).into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        match errors(value) {
+            Ok(errors) => errors
+                .iter()
+                .map(|error| {
+                    Error::InvalidValue(error, format!("no GraphQL error ({})", summary(error)))
+                })
+                .collect(),
+            Err(err) => vec![err],
+        }
+    }
+
+    fn describe(&self) -> String {
+        String::from("a GraphQL response without errors")
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": { "errors": { "type": ["array", "null"], "maxItems": 0 } },
+        })
+    }
+}
+
+struct GraphqlErrorValidator {
+    code: String,
+}
+
+impl Validator for GraphqlErrorValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let errors = errors(value)?;
+        if errors.iter().any(|error| code(error) == Some(&self.code)) {
+            return Ok(());
+        }
+
+        let location = value.get("errors").unwrap_or(value);
+        let actual = if errors.is_empty() {
+            String::from("no errors")
+        } else {
+            let summaries: Vec<String> = errors.iter().map(summary).collect();
+            summaries.join(", ")
+        };
+        Err(Error::InvalidValue(
+            location,
+            format!("a GraphQL error with code '{}' ({})", self.code, actual),
+        ))
+    }
+
+    fn describe(&self) -> String {
+        format!("a GraphQL response with an error with code '{}'", self.code)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "errors": {
+                    "type": "array",
+                    "contains": {
+                        "type": "object",
+                        "properties": {
+                            "extensions": {
+                                "type": "object",
+                                "properties": { "code": { "const": self.code } },
+                                "required": ["code"],
+                            },
+                        },
+                        "required": ["extensions"],
+                    },
+                },
+            },
+            "required": ["errors"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validators;
+    use crate::{Error, Validator};
+    use serde_json::json;
+
+    #[test]
+    fn data() {
+        let validator = super::graphql_data(validators::at("/user/id", validators::eq("1")));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"data": {"user": {"id": "1"}}}))
+        );
+        let value = json!({"data": {"user": {"id": "2"}}});
+        assert!(matches!(
+            validator.validate(&value),
+            Err(err) if std::ptr::eq(err.location(), &value["data"]["user"]["id"])
+        ));
+        assert_eq!(
+            Err(Error::MissingObjectKey(&json!({}), String::from("data"))),
+            validator.validate(&json!({}))
+        );
+        assert_eq!(
+            Err(Error::InvalidType(&json!([]), String::from("object"))),
+            validator.validate(&json!([]))
+        );
+    }
+
+    #[test]
+    fn data_of_failed_operation() {
+        let validator = super::graphql_data(validators::any());
+        let value = json!({
+            "data": null,
+            "errors": [{"message": "boom", "path": ["user", "friends", 0, "name"]}],
+        });
+
+        assert_eq!(
+            Err(Error::InvalidValue(
+                &value["data"],
+                String::from(
+                    r#"GraphQL data (the operation failed with "boom" at user.friends[0].name)"#
+                )
+            )),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_errors() {
+        let validator = super::graphql_no_errors();
+
+        assert_eq!(Ok(()), validator.validate(&json!({"data": {}})));
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"data": {}, "errors": []}))
+        );
+        let value = json!({
+            "data": null,
+            "errors": [
+                {"message": "not found", "path": ["user"], "extensions": {"code": "NOT_FOUND"}},
+                {"message": "denied"},
+            ],
+        });
+        assert_eq!(
+            vec![
+                Error::InvalidValue(
+                    &value["errors"][0],
+                    String::from(r#"no GraphQL error ("not found" at user (NOT_FOUND))"#)
+                ),
+                Error::InvalidValue(
+                    &value["errors"][1],
+                    String::from(r#"no GraphQL error ("denied")"#)
+                ),
+            ],
+            validator.validate_all(&value)
+        );
+        assert!(std::ptr::eq(
+            &value["errors"][0],
+            validator.validate(&value).unwrap_err().location()
+        ));
+    }
+
+    #[test]
+    fn error_with() {
+        let validator = super::graphql_error_with("NOT_FOUND");
+        let value = json!({
+            "errors": [{"message": "denied", "extensions": {"code": "FORBIDDEN"}}],
+        });
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({
+                "errors": [{"message": "not found", "extensions": {"code": "NOT_FOUND"}}],
+            }))
+        );
+        assert_eq!(
+            Err(Error::InvalidValue(
+                &value["errors"],
+                String::from(r#"a GraphQL error with code 'NOT_FOUND' ("denied" (FORBIDDEN))"#)
+            )),
+            validator.validate(&value)
+        );
+        assert_eq!(
+            Err(Error::InvalidValue(
+                &json!({"data": {}}),
+                String::from("a GraphQL error with code 'NOT_FOUND' (no errors)")
+            )),
+            validator.validate(&json!({"data": {}}))
+        );
+    }
+}
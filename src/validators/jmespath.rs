@@ -0,0 +1,95 @@
+use crate::{Error, Validator, Value};
+
+/// Match if the result of the JMESPath expression match the validator.
+///
+/// The expression is evaluated against the whole value, which makes aggregate
+/// assertions possible. Panics if the expression is not a valid JMESPath.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"items": [1, 2, 3, 4]}"#,
+///     validators::jmespath("length(items) > `3`", validators::eq(true))
+/// );
+/// ```
+pub fn jmespath(expression: &str, validator: impl Validator) -> impl Validator {
+    JmesPathValidator {
+        expression: ::jmespath::compile(expression).expect("invalid JMESPath expression"),
+        validator,
+    }
+}
+
+struct JmesPathValidator<T>
+where
+    T: Validator,
+{
+    expression: ::jmespath::Expression<'static>,
+    validator: T,
+}
+
+impl<T> Validator for JmesPathValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let result = self.expression.search(value).map_err(|err| {
+            Error::InvalidValue(
+                value,
+                format!("'{}' to be evaluated ({})", self.expression, err),
+            )
+        })?;
+        let result = serde_json::to_value(&*result).expect("JMESPath result is valid JSON");
+
+        // The result only lives in this function so the error can't point to it.
+        self.validator.validate(&result).map_err(|err| {
+            Error::InvalidValue(
+                value,
+                format!("'{}' to be valid ({})", self.expression, err),
+            )
+        })
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "a value for which '{}' is {}",
+            self.expression,
+            self.validator.describe()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validators;
+    use crate::{Error, Validator};
+
+    #[test]
+    fn jmespath() {
+        let validator = super::jmespath("sum(items[*].price)", validators::eq(30.0));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"items": [{"price": 10}, {"price": 20}]}))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected 'sum(items[*].price)' to be valid \
+                (Invalid value. Expected 30.0 but got 20.0.) but got {\"items\":[{\"price\":20}]}."
+            )),
+            validator
+                .validate(&serde_json::json!({"items": [{"price": 20}]}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn jmespath_evaluation_error() {
+        let validator = super::jmespath("sum(items)", validators::any());
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"items": "not a list"})),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+}
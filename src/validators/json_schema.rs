@@ -0,0 +1,627 @@
+use crate::{Error, Validator, Value};
+use jsonschema::error::{TypeKind, ValidationError, ValidationErrorKind};
+#[cfg(feature = "openapi")]
+use jsonschema::{Draft, Registry};
+
+/// URI of the document whose subschema is compiled by [json_subschema].
+#[cfg(feature = "openapi")]
+const ROOT_URI: &str = "urn:assert-json:root";
+
+/// Match if the value is valid against the JSON Schema.
+///
+/// The schema is compiled with the [jsonschema] crate and violations are
+/// reported like the other validators, at the path of the failing node. The
+/// draft is read from `$schema`, 2020-12 being the default, and `$ref`s are
+/// resolved within the schema, like `#/$defs/user`. Annotations, like
+/// `format` or `description`, are ignored.
+///
+/// Panics if the schema is not valid or uses a `$ref` to another document.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// let schema = serde_json::json!({
+///     "type": "object",
+///     "required": ["id", "name"],
+///     "properties": {
+///         "id": { "type": "integer", "minimum": 1 },
+///         "name": { "type": "string", "minLength": 1 },
+///         "tags": { "type": "array", "items": { "$ref": "#/$defs/tag" } },
+///     },
+///     "$defs": { "tag": { "type": "string", "pattern": "^[a-z]+$" } },
+/// });
+///
+/// assert_json!(
+///     r#"{"id": 5, "name": "charlesvdv", "tags": ["admin"]}"#,
+///     validators::json_schema(schema)
+/// );
+/// ```
+pub fn json_schema(schema: Value) -> impl Validator {
+    let title = schema
+        .get("title")
+        .and_then(Value::as_str)
+        .map(String::from);
+    let validator = jsonschema::options()
+        .should_validate_formats(false)
+        .build(&schema)
+        .unwrap_or_else(|err| invalid(&err));
+    JsonSchemaValidator {
+        title,
+        validator,
+        schema,
+    }
+}
+
+/// Compile the subschema of `root` at the JSON pointer with the draft,
+/// `$ref` being resolved against `root`. `schema` is the document returned by
+/// [to_json_schema](Validator::to_json_schema).
+#[cfg(feature = "openapi")]
+pub(crate) fn json_subschema(
+    root: &Value,
+    pointer: &str,
+    draft: Draft,
+    title: Option<String>,
+    schema: Value,
+) -> impl Validator {
+    let registry = Registry::new()
+        .draft(draft)
+        .add(ROOT_URI, draft.create_resource(root.clone()))
+        .and_then(|registry| registry.prepare())
+        .unwrap_or_else(|err| panic!("invalid JSON Schema: {}", err));
+    let reference = serde_json::json!({
+        "$ref": format!("{}#{}", ROOT_URI, encode_fragment(pointer)),
+    });
+    let validator = jsonschema::options()
+        .with_draft(draft)
+        .with_registry(&registry)
+        .should_validate_formats(false)
+        .build(&reference)
+        .unwrap_or_else(|err| invalid(&err));
+    JsonSchemaValidator {
+        title,
+        validator,
+        schema,
+    }
+}
+
+struct JsonSchemaValidator {
+    title: Option<String>,
+    validator: jsonschema::Validator,
+    schema: Value,
+}
+
+impl Validator for JsonSchemaValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.validator.iter_errors(value).next() {
+            Some(err) => Err(convert(value, &err)),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        self.validator
+            .iter_errors(value)
+            .map(|err| convert(value, &err))
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        match &self.title {
+            Some(title) => format!("a value matching the JSON Schema '{}'", title),
+            None => String::from("a value matching a JSON Schema"),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.schema.clone()
+    }
+}
+
+fn invalid(err: &ValidationError<'_>) -> ! {
+    panic!("invalid JSON Schema at '#{}': {}", err.instance_path(), err)
+}
+
+/// Percent-encode a JSON pointer to be used as the fragment of a URI.
+#[cfg(feature = "openapi")]
+fn encode_fragment(pointer: &str) -> String {
+    let mut fragment = String::new();
+    for byte in pointer.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/!$&'()*+,;=:@".contains(&byte) {
+            fragment.push(char::from(byte));
+        } else {
+            fragment.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    fragment
+}
+
+/// Convert an error of the schema into an error at the failing node of
+/// `root`, wrapped like the array validators do along its path.
+fn convert<'a>(root: &'a Value, err: &ValidationError<'_>) -> Error<'a> {
+    let mut node = root;
+    let mut indices = vec![];
+    for token in err.instance_path().as_str().split('/').skip(1) {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        node = match node {
+            Value::Array(array) => {
+                let Some(element) = token.parse().ok().and_then(|index: usize| {
+                    indices.push(index);
+                    array.get(index)
+                }) else {
+                    break;
+                };
+                element
+            }
+            Value::Object(object) => match object.get(&token) {
+                Some(value) => value,
+                None => break,
+            },
+            _ => break,
+        };
+    }
+    let error = error_at(root, node, err.kind());
+    indices.into_iter().rev().fold(error, |error, index| {
+        Error::InvalidArrayElement(index, Box::new(error))
+    })
+}
+
+fn error_at<'a>(root: &'a Value, node: &'a Value, kind: &ValidationErrorKind) -> Error<'a> {
+    let expected = match kind {
+        ValidationErrorKind::Type { kind } => {
+            let types: Vec<&str> = match kind {
+                TypeKind::Single(single) => vec![single.as_str()],
+                TypeKind::Multiple(types) => types.iter().map(|kind| kind.as_str()).collect(),
+            };
+            return Error::InvalidType(node, types.join(" or "));
+        }
+        ValidationErrorKind::Required { property } => {
+            let key = property.as_str().map_or(property.to_string(), String::from);
+            return Error::MissingObjectKey(node, key);
+        }
+        ValidationErrorKind::AdditionalProperties { unexpected }
+        | ValidationErrorKind::UnevaluatedProperties { unexpected } => {
+            return match (unexpected.as_slice(), node) {
+                ([key], Value::Object(object)) if object.contains_key(key) => {
+                    Error::UnexpectedObjectKey(&object[key], key.clone())
+                }
+                (keys, _) => Error::UnexpectedObjectKeys(node, keys.to_vec()),
+            };
+        }
+        ValidationErrorKind::PropertyNames { error } => {
+            let key = error.instance().as_str().unwrap_or_default().to_owned();
+            return Error::InvalidObjectKey(node, key, describe(error.kind()));
+        }
+        ValidationErrorKind::Constant { expected_value } => {
+            return Error::ValueMismatch(node, expected_value.clone());
+        }
+        ValidationErrorKind::Not { .. } => return Error::UnexpectedMatch(node),
+        ValidationErrorKind::AnyOf { context } | ValidationErrorKind::OneOfNotValid { context } => {
+            let errors = context
+                .iter()
+                .filter_map(|errors| errors.first())
+                .map(|err| convert(root, err))
+                .collect();
+            return Error::NoMatchingAlternative(node, errors);
+        }
+        ValidationErrorKind::MaxItems { .. }
+        | ValidationErrorKind::MinItems { .. }
+        | ValidationErrorKind::MaxLength { .. }
+        | ValidationErrorKind::MinLength { .. }
+        | ValidationErrorKind::MaxProperties { .. }
+        | ValidationErrorKind::MinProperties { .. } => {
+            return Error::InvalidLength(node, describe(kind));
+        }
+        kind => describe(kind),
+    };
+    Error::InvalidValue(node, expected)
+}
+
+/// Describe the values accepted by the keyword which failed.
+fn describe(kind: &ValidationErrorKind) -> String {
+    match kind {
+        ValidationErrorKind::AdditionalItems { limit } => {
+            format!("an array of at most {} items", limit)
+        }
+        ValidationErrorKind::AnyOf { .. } => String::from("a value matching any of the subschemas"),
+        ValidationErrorKind::OneOfMultipleValid { .. }
+        | ValidationErrorKind::OneOfNotValid { .. } => {
+            String::from("a value matching exactly one of the subschemas")
+        }
+        ValidationErrorKind::Constant { expected_value } => expected_value.to_string(),
+        ValidationErrorKind::Contains => {
+            String::from("an array with an element matching the subschema")
+        }
+        ValidationErrorKind::ContentEncoding { content_encoding } => {
+            format!("a string encoded in {}", content_encoding)
+        }
+        ValidationErrorKind::ContentMediaType { content_media_type } => {
+            format!("a string of the media type {}", content_media_type)
+        }
+        ValidationErrorKind::Custom { message, .. } => message.clone(),
+        ValidationErrorKind::Enum { options } => format!("one of {}", options),
+        ValidationErrorKind::ExclusiveMaximum { limit } => format!("a number lower than {}", limit),
+        ValidationErrorKind::ExclusiveMinimum { limit } => {
+            format!("a number greater than {}", limit)
+        }
+        ValidationErrorKind::Maximum { limit } => {
+            format!("a number lower than or equal to {}", limit)
+        }
+        ValidationErrorKind::Minimum { limit } => {
+            format!("a number greater than or equal to {}", limit)
+        }
+        ValidationErrorKind::MultipleOf { multiple_of } => format!("a multiple of {}", multiple_of),
+        ValidationErrorKind::FalseSchema => String::from("no value (false schema)"),
+        ValidationErrorKind::Format { format } => format!("a string of the format '{}'", format),
+        ValidationErrorKind::FromUtf8 { .. } => String::from("a string encoding UTF-8 text"),
+        ValidationErrorKind::MaxItems { limit } => format!("an array of at most {} items", limit),
+        ValidationErrorKind::MinItems { limit } => {
+            format!("an array of at least {} items", limit)
+        }
+        ValidationErrorKind::MaxLength { limit } => {
+            format!("a string of at most {} characters", limit)
+        }
+        ValidationErrorKind::MinLength { limit } => {
+            format!("a string of at least {} characters", limit)
+        }
+        ValidationErrorKind::MaxProperties { limit } => {
+            format!("an object of at most {} keys", limit)
+        }
+        ValidationErrorKind::MinProperties { limit } => {
+            format!("an object of at least {} keys", limit)
+        }
+        ValidationErrorKind::Not { schema } => format!("a value not matching {}", schema),
+        ValidationErrorKind::Pattern { pattern } => {
+            format!("a string matching '{}'", pattern)
+        }
+        ValidationErrorKind::UnevaluatedItems { unexpected } => {
+            format!("an array without the items {}", unexpected.join(", "))
+        }
+        ValidationErrorKind::UniqueItems => String::from("an array of unique items"),
+        kind => format!("a value matching the '{}' keyword", kind.keyword()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+    use serde_json::json;
+
+    #[test]
+    fn types() {
+        let validator = super::json_schema(json!({"type": ["integer", "null"]}));
+
+        assert_eq!(Ok(()), validator.validate(&json!(1)));
+        assert_eq!(Ok(()), validator.validate(&json!(1.0)));
+        assert_eq!(Ok(()), validator.validate(&json!(null)));
+        let value = json!(1.5);
+        let err = validator.validate(&value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid type. Expected null or integer but got number."
+        );
+    }
+
+    #[test]
+    fn boolean_schemas() {
+        assert_eq!(Ok(()), super::json_schema(json!(true)).validate(&json!(1)));
+        assert!(matches!(
+            super::json_schema(json!(false)).validate(&json!(1)),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn keywords_apply_to_their_type() {
+        let validator = super::json_schema(json!({
+            "minimum": 2,
+            "minLength": 2,
+            "minItems": 2,
+            "required": ["a"],
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!(true)));
+        assert_eq!(Ok(()), validator.validate(&json!(3)));
+        assert_eq!(Ok(()), validator.validate(&json!("ab")));
+        assert_eq!(Ok(()), validator.validate(&json!([1, 2])));
+        assert_eq!(Ok(()), validator.validate(&json!({"a": 1})));
+        assert!(validator.validate(&json!(1)).is_err());
+        assert!(validator.validate(&json!("a")).is_err());
+        assert!(validator.validate(&json!([1])).is_err());
+        assert!(matches!(
+            validator.validate(&json!({})),
+            Err(Error::MissingObjectKey(_, _))
+        ));
+    }
+
+    #[test]
+    fn numbers() {
+        let validator = super::json_schema(json!({
+            "exclusiveMinimum": 0,
+            "maximum": 10,
+            "multipleOf": 2.5,
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!(5)));
+        assert_eq!(Ok(()), validator.validate(&json!(10)));
+        assert!(validator.validate(&json!(0)).is_err());
+        assert!(validator.validate(&json!(12.5)).is_err());
+        assert!(validator.validate(&json!(3)).is_err());
+    }
+
+    #[test]
+    fn enum_and_const() {
+        let validator = super::json_schema(json!({"enum": ["a", 1]}));
+        assert_eq!(Ok(()), validator.validate(&json!(1)));
+        assert!(validator.validate(&json!("b")).is_err());
+
+        let validator = super::json_schema(json!({"const": {"a": [1]}}));
+        assert_eq!(Ok(()), validator.validate(&json!({"a": [1]})));
+        assert!(validator.validate(&json!({"a": [2]})).is_err());
+    }
+
+    #[test]
+    fn pattern() {
+        let validator = super::json_schema(json!({"pattern": "[0-9]+"}));
+
+        assert_eq!(Ok(()), validator.validate(&json!("ord-12")));
+        assert!(validator.validate(&json!("ord")).is_err());
+    }
+
+    #[test]
+    fn properties() {
+        let validator = super::json_schema(json!({
+            "properties": { "id": { "type": "integer" } },
+            "patternProperties": { "^x-": { "type": "string" } },
+            "additionalProperties": false,
+        }));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"id": 1, "x-trace": "t"}))
+        );
+        let value = json!({"id": "1", "x-trace": 2, "other": 3});
+        let errors = validator.validate_all(&value);
+        assert_eq!(3, errors.len());
+        assert_eq!(Some(String::from("/id")), errors[0].pointer(&value));
+        assert!(matches!(errors[2], Error::UnexpectedObjectKey(_, _)));
+        assert_eq!(Some(String::from("/other")), errors[2].pointer(&value));
+    }
+
+    #[test]
+    fn additional_properties_schema() {
+        let validator = super::json_schema(json!({
+            "properties": { "id": true },
+            "additionalProperties": { "type": "string" },
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!({"id": 1, "name": "n"})));
+        assert!(validator.validate(&json!({"id": 1, "name": 2})).is_err());
+    }
+
+    #[test]
+    fn property_names_and_dependencies() {
+        let validator = super::json_schema(json!({
+            "propertyNames": { "maxLength": 3 },
+            "dependentRequired": { "a": ["b"] },
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!({"a": 1, "b": 2})));
+        assert!(matches!(
+            validator.validate(&json!({"long": 1})),
+            Err(Error::InvalidObjectKey(_, _, _))
+        ));
+        assert!(matches!(
+            validator.validate(&json!({"a": 1})),
+            Err(Error::MissingObjectKey(_, _))
+        ));
+    }
+
+    #[test]
+    fn items() {
+        let validator = super::json_schema(json!({
+            "prefixItems": [{ "type": "string" }],
+            "items": { "type": "integer" },
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!(["a", 1, 2])));
+        let value = json!(["a", 1, "b"]);
+        let err = validator.validate(&value).unwrap_err();
+        assert!(matches!(err, Error::InvalidArrayElement(2, _)));
+        assert_eq!(Some(String::from("/2")), err.pointer(&value));
+    }
+
+    #[test]
+    fn tuple_items() {
+        let validator = super::json_schema(json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "items": [{ "type": "string" }],
+            "additionalItems": false,
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!(["a"])));
+        assert!(validator.validate(&json!(["a", 1])).is_err());
+    }
+
+    #[test]
+    fn contains() {
+        let validator =
+            super::json_schema(json!({"contains": { "const": 1 }, "uniqueItems": true}));
+        assert_eq!(Ok(()), validator.validate(&json!([1, 2])));
+        assert!(matches!(
+            validator.validate(&json!([2, 3])),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert!(validator.validate(&json!([1, 1])).is_err());
+
+        let validator = super::json_schema(
+            json!({"contains": { "type": "string" }, "minContains": 2, "maxContains": 3}),
+        );
+        assert_eq!(Ok(()), validator.validate(&json!(["a", "b", 1])));
+        assert!(validator.validate(&json!(["a", 1])).is_err());
+        assert!(validator.validate(&json!(["a", "b", "c", "d"])).is_err());
+    }
+
+    #[test]
+    fn applicators() {
+        let validator = super::json_schema(json!({
+            "oneOf": [{ "type": "integer" }, { "minimum": 2 }],
+            "not": { "const": 5 },
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!(1)));
+        assert_eq!(Ok(()), validator.validate(&json!(2.5)));
+        assert!(matches!(
+            validator.validate(&json!(3)),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            validator.validate(&json!(0.5)),
+            Err(Error::NoMatchingAlternative(_, _))
+        ));
+
+        let validator = super::json_schema(json!({
+            "if": { "properties": { "type": { "const": "error" } } },
+            "then": { "required": ["message"] },
+            "else": { "required": ["result"] },
+        }));
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"type": "error", "message": "m"}))
+        );
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"type": "ok", "result": 1}))
+        );
+        assert!(validator
+            .validate(&json!({"type": "error", "result": 1}))
+            .is_err());
+    }
+
+    #[test]
+    fn recursive_reference() {
+        let validator = super::json_schema(json!({
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "required": ["value"],
+                    "properties": {
+                        "value": { "type": "integer" },
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } },
+                    },
+                },
+            },
+            "$ref": "#/$defs/node",
+        }));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"value": 1, "children": [{"value": 2, "children": []}]}))
+        );
+        let value = json!({"value": 1, "children": [{"value": 2, "children": [{"value": "3"}]}]});
+        let err = validator.validate(&value).unwrap_err();
+        assert_eq!(
+            Some(String::from("/children/0/children/0/value")),
+            err.pointer(&value)
+        );
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            "a value matching the JSON Schema 'User'",
+            super::json_schema(json!({"title": "User"})).describe()
+        );
+        assert_eq!(
+            "a value matching a JSON Schema",
+            super::json_schema(json!({})).describe()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid JSON Schema at '#/properties/a/minLength': -1 is less than the minimum of 0"
+    )]
+    fn invalid_schema() {
+        super::json_schema(json!({"properties": {"a": {"minLength": -1}}}));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Resource 'https://example.com/schema.json' is not present in a registry"
+    )]
+    fn remote_reference() {
+        super::json_schema(json!({"$ref": "https://example.com/schema.json"}));
+    }
+
+    #[test]
+    fn from_validator() {
+        let validator = crate::validators::object(std::collections::HashMap::from([
+            (
+                String::from("age"),
+                Box::new(crate::validators::between(18, 120)) as Box<dyn Validator>,
+            ),
+            (
+                String::from("tags"),
+                Box::new(crate::validators::array_for_each(
+                    crate::validators::starts_with("#"),
+                )),
+            ),
+        ]));
+        let validator = super::json_schema(validator.to_json_schema());
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"age": 30, "tags": ["#a"]}))
+        );
+        assert!(validator.validate(&json!({"age": 12, "tags": []})).is_err());
+        assert!(validator
+            .validate(&json!({"age": 30, "tags": ["a"]}))
+            .is_err());
+        assert!(validator.validate(&json!({"tags": []})).is_err());
+    }
+
+    #[test]
+    fn unevaluated_properties() {
+        let validator = super::json_schema(json!({
+            "allOf": [{ "properties": { "id": true } }],
+            "unevaluatedProperties": false,
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!({"id": 1})));
+        let value = json!({"id": 1, "other": 2});
+        let err = validator.validate(&value).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedObjectKey(_, _)));
+        assert_eq!(Some(String::from("/other")), err.pointer(&value));
+    }
+
+    #[test]
+    fn integers_equal_floats() {
+        let validator = super::json_schema(json!({"const": 1}));
+        assert_eq!(Ok(()), validator.validate(&json!(1.0)));
+
+        let validator = super::json_schema(json!({"enum": [1.0, "a"]}));
+        assert_eq!(Ok(()), validator.validate(&json!(1)));
+
+        let validator = super::json_schema(json!({"uniqueItems": true}));
+        assert!(validator.validate(&json!([1, 1.0])).is_err());
+    }
+
+    #[test]
+    fn draft4_exclusive_minimum() {
+        let validator = super::json_schema(json!({
+            "$schema": "http://json-schema.org/draft-04/schema#",
+            "minimum": 0,
+            "exclusiveMinimum": true,
+        }));
+
+        assert_eq!(Ok(()), validator.validate(&json!(1)));
+        assert!(validator.validate(&json!(0)).is_err());
+    }
+
+    #[test]
+    fn send() {
+        fn assert_send(_: &impl Send) {}
+
+        assert_send(&super::json_schema(json!({"type": "string"})));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Error, Validator, Value};
+use serde_json_path::JsonPath;
+
+/// Match if all the nodes selected by the JSONPath expression match the validator.
+///
+/// Fail if the expression selects no node. Panics if the expression is not
+/// a valid JSONPath.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"items": [{"id": 1}, {"id": 2}]}"#,
+///     validators::jsonpath("$.items[*].id", validators::gt(0))
+/// );
+/// ```
+pub fn jsonpath(path: &str, validator: impl Validator) -> impl Validator {
+    JsonPathValidator {
+        path: JsonPath::parse(path).expect("invalid JSONPath expression"),
+        expression: String::from(path),
+        validator,
+        all: true,
+    }
+}
+
+/// Match if at least one of the nodes selected by the JSONPath expression
+/// match the validator.
+///
+/// Panics if the expression is not a valid JSONPath.
+pub fn jsonpath_any(path: &str, validator: impl Validator) -> impl Validator {
+    JsonPathValidator {
+        path: JsonPath::parse(path).expect("invalid JSONPath expression"),
+        expression: String::from(path),
+        validator,
+        all: false,
+    }
+}
+
+struct JsonPathValidator<T>
+where
+    T: Validator,
+{
+    path: JsonPath,
+    expression: String,
+    validator: T,
+    all: bool,
+}
+
+impl<T> Validator for JsonPathValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let nodes = self.path.query(value).all();
+        if nodes.is_empty() {
+            return Err(Error::InvalidValue(
+                value,
+                format!("at least one node selected by '{}'", self.expression),
+            ));
+        }
+
+        if self.all {
+            return nodes
+                .into_iter()
+                .try_for_each(|node| self.validator.validate(node));
+        }
+
+        let mut errors = vec![];
+        for node in nodes {
+            match self.validator.validate(node) {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(err),
+            }
+        }
+        Err(Error::NoMatchingAlternative(value, errors))
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "a value whose {} nodes selected by '{}' are {}",
+            if self.all { "all the" } else { "some of the" },
+            self.expression,
+            self.validator.describe()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validators;
+    use crate::{Error, Validator};
+
+    #[test]
+    fn all() {
+        let validator = super::jsonpath("$.items[*].id", validators::gt(0));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"items": [{"id": 1}, {"id": 2}]}))
+        );
+
+        let value = serde_json::json!({"items": [{"id": 1}, {"id": 0}]});
+        let error = validator.validate(&value).unwrap_err();
+        assert!(std::ptr::eq(&value["items"][1]["id"], error.location()));
+    }
+
+    #[test]
+    fn any() {
+        let validator = super::jsonpath_any("$.items[*].id", validators::eq(2));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"items": [{"id": 1}, {"id": 2}]}))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"items": [{"id": 1}, {"id": 3}]})),
+            Err(Error::NoMatchingAlternative(_, _))
+        ));
+    }
+
+    #[test]
+    fn no_node_selected() {
+        let validator = super::jsonpath("$.missing", validators::any());
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!({})),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+}
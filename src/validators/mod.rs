@@ -1,13 +1,52 @@
 use crate::{get_value_type_id, Error, Validator, Value};
-use std::fmt::Debug;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 
 mod array;
+mod capture;
+mod datetime;
+mod encoding;
+mod format;
+mod graphql;
+#[cfg(feature = "jmespath")]
+mod jmespath;
+#[cfg(feature = "jsonschema")]
+mod json_schema;
+#[cfg(feature = "jsonpath")]
+mod jsonpath;
+mod number;
 mod object;
+mod pointer;
 mod primitive;
+#[cfg(feature = "semver")]
+mod semver;
+mod string;
+#[cfg(feature = "url")]
+mod url;
 
+#[cfg(feature = "jmespath")]
+pub use self::jmespath::*;
+#[cfg(feature = "semver")]
+pub use self::semver::*;
+#[cfg(feature = "url")]
+pub use self::url::*;
 pub use array::*;
+pub use capture::*;
+pub use datetime::*;
+pub use encoding::*;
+pub use format::*;
+pub use graphql::*;
+#[cfg(feature = "jsonschema")]
+pub use json_schema::*;
+#[cfg(feature = "jsonpath")]
+pub use jsonpath::*;
+pub use number::*;
 pub use object::*;
+pub use pointer::*;
 pub use primitive::*;
+pub use string::*;
 
 /// Match any value.
 ///
@@ -22,26 +61,71 @@ impl Validator for AnyValidator {
     fn validate<'a>(&self, _: &'a Value) -> Result<(), Error<'a>> {
         Ok(())
     }
+
+    fn describe(&self) -> String {
+        String::from("any value")
+    }
+}
+
+/// Match if the predicate returns `Ok`.
+///
+/// The error message returned by the predicate is reported as an invalid value.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!("4", validators::custom(|value| match value.as_u64() {
+///     Some(v) if v % 2 == 0 => Ok(()),
+///     _ => Err(String::from("an even number")),
+/// }));
+/// ```
+pub fn custom<F>(predicate: F) -> impl Validator
+where
+    F: Fn(&Value) -> Result<(), String> + 'static,
+{
+    CustomValidator { predicate }
+}
+
+struct CustomValidator<F>
+where
+    F: Fn(&Value) -> Result<(), String>,
+{
+    predicate: F,
+}
+
+impl<F> Validator for CustomValidator<F>
+where
+    F: Fn(&Value) -> Result<(), String>,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        (self.predicate)(value).map_err(|msg| Error::InvalidValue(value, msg))
+    }
+
+    fn describe(&self) -> String {
+        String::from("a value matching a custom predicate")
+    }
 }
 
 /// Match a value equals the expected value.
+///
+/// 128-bit integers are compared with [eq_i128] and [eq_u128].
 pub fn eq<T>(expected: T) -> impl Validator
 where
-    T: Into<Value> + Clone + Debug + 'static,
+    T: Into<Value> + Clone + 'static,
 {
     EqValidator { expected }
 }
 
 struct EqValidator<T>
 where
-    T: Into<Value> + Clone + Debug,
+    T: Into<Value> + Clone,
 {
     expected: T,
 }
 
 impl<T> Validator for EqValidator<T>
 where
-    T: Into<Value> + Clone + Debug,
+    T: Into<Value> + Clone,
 {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
         let expected_val = self.expected.clone().into();
@@ -51,10 +135,723 @@ where
 
         if value == &expected_val {
             Ok(())
+        } else if expected_val.is_array() || expected_val.is_object() {
+            Err(Error::ValueMismatch(value, expected_val))
+        } else {
+            Err(Error::InvalidValue(value, expected_val.to_string()))
+        }
+    }
+
+    fn describe(&self) -> String {
+        crate::truncate::display_value(&self.expected.clone().into())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "const": self.expected.clone().into() })
+    }
+}
+
+/// Match a number equals the expected 128-bit integer.
+///
+/// JSON numbers only hold 64-bit integers: an expected value out of that
+/// range matches no number, and the failure tells so.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!("18446744073709551615", validators::eq_i128(u64::MAX as i128));
+/// ```
+pub fn eq_i128(expected: i128) -> impl Validator {
+    WideIntegerValidator {
+        expected: serde_json::Number::from_i128(expected),
+        display: expected.to_string(),
+    }
+}
+
+/// Match a number equals the expected 128-bit unsigned integer.
+///
+/// As for [eq_i128], an expected value out of the 64-bit range matches no
+/// number.
+pub fn eq_u128(expected: u128) -> impl Validator {
+    WideIntegerValidator {
+        expected: serde_json::Number::from_u128(expected),
+        display: expected.to_string(),
+    }
+}
+
+struct WideIntegerValidator {
+    /// The expected number, `None` if it is out of the JSON number range.
+    expected: Option<serde_json::Number>,
+    display: String,
+}
+
+impl Validator for WideIntegerValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let Value::Number(actual) = value else {
+            return Err(Error::InvalidType(value, String::from("number")));
+        };
+        match &self.expected {
+            Some(expected) if expected == actual => Ok(()),
+            Some(_) => Err(Error::InvalidValue(value, self.display.clone())),
+            None => Err(Error::InvalidValue(
+                value,
+                format!("{} (out of the JSON number range)", self.display),
+            )),
+        }
+    }
+
+    fn describe(&self) -> String {
+        self.display.clone()
+    }
+
+    fn to_json_schema(&self) -> Value {
+        match &self.expected {
+            Some(expected) => serde_json::json!({ "const": expected }),
+            None => Value::Bool(false),
+        }
+    }
+}
+
+/// Match a value equals the serialized expected value.
+///
+/// The expected value is serialized with serde when the validator is built,
+/// which allows reusing domain types as expectations. Panics if `expected`
+/// can't be serialized to JSON.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// #[derive(serde::Serialize)]
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// let user = User { name: String::from("Jane"), age: 42 };
+/// assert_json!(r#"{"name": "Jane", "age": 42}"#, validators::eq_serialize(user));
+/// ```
+pub fn eq_serialize<T>(expected: T) -> impl Validator
+where
+    T: Serialize,
+{
+    eq(serde_json::to_value(expected).expect("failed to serialize expected value"))
+}
+
+/// Match a value equals the JSON document stored in the file at `path`.
+///
+/// The file is read and parsed on first use, then cached. Relative paths are
+/// resolved against the current directory, which is the package root under
+/// `cargo test`. Panics if the file cannot be read or parsed.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"user": {"id": 5, "name": "charlesvdv", "roles": ["admin", "user"]}}"#,
+///     { "user": validators::eq_fixture("tests/fixtures/expected_user.json") }
+/// );
+/// ```
+pub fn eq_fixture(path: impl AsRef<Path>) -> impl Validator {
+    EqFixtureValidator {
+        path: path.as_ref().to_path_buf(),
+    }
+}
+
+struct EqFixtureValidator {
+    path: PathBuf,
+}
+
+impl Validator for EqFixtureValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        eq(crate::fixture::load(&self.path)).validate(value)
+    }
+
+    fn describe(&self) -> String {
+        format!("the document of {}", self.path.display())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "const": crate::fixture::load(&self.path) })
+    }
+}
+
+/// Match if the value can be deserialized into `T`.
+///
+/// Fields which are not known to `T` are ignored, unless `T` itself uses
+/// `#[serde(deny_unknown_fields)]`. Use [matches_struct_strict] to reject them
+/// regardless.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// #[derive(serde::Deserialize)]
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// assert_json!(
+///     r#"{"name": "Jane", "age": 42, "extra": true}"#,
+///     validators::matches_struct::<User>()
+/// );
+/// ```
+pub fn matches_struct<T>() -> impl Validator
+where
+    T: DeserializeOwned,
+{
+    MatchesStructValidator::<T> {
+        strict: false,
+        target: PhantomData,
+    }
+}
+
+/// Match if the value can be deserialized into `T` without ignoring any field.
+///
+/// This behaves as if `T` had `#[serde(deny_unknown_fields)]`, including in
+/// nested structures.
+pub fn matches_struct_strict<T>() -> impl Validator
+where
+    T: DeserializeOwned,
+{
+    MatchesStructValidator::<T> {
+        strict: true,
+        target: PhantomData,
+    }
+}
+
+struct MatchesStructValidator<T> {
+    strict: bool,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<T> Validator for MatchesStructValidator<T>
+where
+    T: DeserializeOwned,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let type_name = std::any::type_name::<T>();
+        let mut unknown_fields = Vec::new();
+        serde_ignored::deserialize::<_, _, T>(value, |path| unknown_fields.push(path.to_string()))
+            .map_err(|err| {
+                Error::InvalidValue(
+                    value,
+                    format!("value deserializable into {} ({})", type_name, err),
+                )
+            })?;
+
+        if self.strict && !unknown_fields.is_empty() {
+            return Err(Error::InvalidValue(
+                value,
+                format!(
+                    "value deserializable into {} without unknown fields {}",
+                    type_name,
+                    crate::join_keys(&unknown_fields)
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "a value deserializable into {}{}",
+            std::any::type_name::<T>(),
+            if self.strict {
+                " without unknown fields"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// Match a value equals to any of the candidates.
+pub fn one_of<I, T>(candidates: I) -> impl Validator
+where
+    I: IntoIterator<Item = T>,
+    T: Into<Value>,
+{
+    OneOfValidator {
+        candidates: candidates.into_iter().map(Into::into).collect(),
+    }
+}
+
+struct OneOfValidator {
+    candidates: Vec<Value>,
+}
+
+impl Validator for OneOfValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        if self.candidates.contains(value) {
+            Ok(())
+        } else {
+            Err(Error::InvalidValue(value, self.describe()))
+        }
+    }
+
+    fn describe(&self) -> String {
+        let candidates = self
+            .candidates
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("one of [{}]", candidates)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "enum": self.candidates })
+    }
+}
+
+/// Match if at least one of the validators matches.
+///
+/// When none of them matches, the errors of all the validators are reported.
+pub fn any_of(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    AnyOfValidator { validators }
+}
+
+struct AnyOfValidator {
+    validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for AnyOfValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let mut errors = vec![];
+        for validator in &self.validators {
+            match crate::coverage::tentatively(|| validator.validate(value), Result::is_ok) {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(err),
+            }
+        }
+        Err(Error::NoMatchingAlternative(value, errors))
+    }
+
+    fn describe(&self) -> String {
+        join_descriptions(&self.validators, " or ")
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let schemas: Vec<Value> = self.validators.iter().map(|v| v.to_json_schema()).collect();
+        if schemas.iter().any(is_any_schema) {
+            return serde_json::json!({});
+        }
+        serde_json::json!({ "anyOf": schemas })
+    }
+}
+
+/// Match if all the validators match.
+///
+/// Fail with the error of the first validator that does not match.
+pub fn all_of(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    AllOfValidator { validators }
+}
+
+struct AllOfValidator {
+    validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for AllOfValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(value))
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        self.validators
+            .iter()
+            .flat_map(|validator| validator.validate_all(value))
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        join_descriptions(&self.validators, " and ")
+    }
+
+    fn to_json_schema(&self) -> Value {
+        all_of_schemas(self.validators.iter().map(|v| v.to_json_schema()))
+    }
+}
+
+/// Match if the validator does not match.
+pub fn not(validator: impl Validator) -> impl Validator {
+    NotValidator { validator }
+}
+
+struct NotValidator<T>
+where
+    T: Validator,
+{
+    validator: T,
+}
+
+impl<T> Validator for NotValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match crate::coverage::tentatively(|| self.validator.validate(value), |_| false) {
+            Ok(()) => Err(Error::UnexpectedMatch(value)),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        // The negation fails as a whole, once.
+        let errors = crate::coverage::tentatively(|| self.validator.validate_all(value), |_| false);
+        if errors.is_empty() {
+            vec![Error::UnexpectedMatch(value)]
+        } else {
+            vec![]
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.validator.describe())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({ "not": self.validator.to_json_schema() })
+    }
+}
+
+/// Match if null or if the validator matches.
+pub fn null_or(validator: impl Validator) -> impl Validator {
+    NullOrValidator { validator }
+}
+
+struct NullOrValidator<T>
+where
+    T: Validator,
+{
+    validator: T,
+}
+
+impl<T> Validator for NullOrValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        if value.is_null() {
+            Ok(())
+        } else {
+            self.validator.validate(value)
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        if value.is_null() {
+            vec![]
+        } else {
+            self.validator.validate_all(value)
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("null or {}", self.validator.describe())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let schema = self.validator.to_json_schema();
+        if is_any_schema(&schema) {
+            return schema;
+        }
+        serde_json::json!({ "anyOf": [{ "type": "null" }, schema] })
+    }
+}
+
+/// Prefix the errors of the validator with a human-readable label.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#"{"age": 26}"#, {
+///     "age": validators::labeled("customer must be an adult", validators::ge(18)),
+/// });
+/// ```
+pub fn labeled(label: &str, validator: impl Validator) -> impl Validator {
+    LabeledValidator {
+        label: String::from(label),
+        validator,
+    }
+}
+
+struct LabeledValidator<T>
+where
+    T: Validator,
+{
+    label: String,
+    validator: T,
+}
+
+impl<T> Validator for LabeledValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validator
+            .validate(value)
+            .map_err(|err| Error::Labeled(self.label.clone(), Box::new(err)))
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        self.validator
+            .validate_all(value)
+            .into_iter()
+            .map(|err| Error::Labeled(self.label.clone(), Box::new(err)))
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        format!("{}: {}", self.label, self.validator.describe())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = self.validator.to_json_schema();
+        if let Value::Object(object) = &mut schema {
+            object.insert(String::from("description"), Value::from(self.label.clone()));
+        }
+        schema
+    }
+}
+
+/// Match `then_validator` if `condition` matches, else match `else_validator`.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+/// use std::collections::HashMap;
+///
+/// let is_error = validators::object(HashMap::from([(
+///     String::from("type"),
+///     Box::new(validators::eq("error")) as Box<dyn assert_json::Validator>,
+/// )]));
+/// let has_message = validators::object(HashMap::from([(
+///     String::from("message"),
+///     Box::new(validators::is_string()) as Box<dyn assert_json::Validator>,
+/// )]));
+///
+/// assert_json!(
+///     r#"{"type": "error", "message": "boom"}"#,
+///     validators::when(is_error, has_message, validators::any())
+/// );
+/// ```
+pub fn when(
+    condition: impl Validator,
+    then_validator: impl Validator,
+    else_validator: impl Validator,
+) -> impl Validator {
+    WhenValidator {
+        condition,
+        then_validator,
+        else_validator,
+    }
+}
+
+struct WhenValidator<T, U, V>
+where
+    T: Validator,
+    U: Validator,
+    V: Validator,
+{
+    condition: T,
+    then_validator: U,
+    else_validator: V,
+}
+
+impl<T, U, V> Validator for WhenValidator<T, U, V>
+where
+    T: Validator,
+    U: Validator,
+    V: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        if crate::coverage::tentatively(|| self.condition.validate(value), Result::is_ok).is_ok() {
+            self.then_validator.validate(value)
         } else {
-            Err(Error::InvalidValue(value, format!("{:?}", self.expected)))
+            self.else_validator.validate(value)
+        }
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        if crate::coverage::tentatively(|| self.condition.validate(value), Result::is_ok).is_ok() {
+            self.then_validator.validate_all(value)
+        } else {
+            self.else_validator.validate_all(value)
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{} if {}, else {}",
+            self.then_validator.describe(),
+            self.condition.describe(),
+            self.else_validator.describe()
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({
+            "if": self.condition.to_json_schema(),
+            "then": self.then_validator.to_json_schema(),
+            "else": self.else_validator.to_json_schema(),
+        })
+    }
+}
+
+/// Match if the validator matches every node of the JSON tree, the root included.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// // no value anywhere is null
+/// assert_json!(
+///     r#"{"a": [1, {"b": "c"}]}"#,
+///     validators::recursively(validators::not(validators::null()))
+/// );
+/// ```
+pub fn recursively(validator: impl Validator) -> impl Validator {
+    RecursiveValidator { validator }
+}
+
+struct RecursiveValidator<T>
+where
+    T: Validator,
+{
+    validator: T,
+}
+
+impl<T> Validator for RecursiveValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validator.validate(value)?;
+        match value {
+            Value::Array(arr) => arr.iter().try_for_each(|val| self.validate(val)),
+            Value::Object(obj) => obj.values().try_for_each(|val| self.validate(val)),
+            _ => Ok(()),
         }
     }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let mut errors = self.validator.validate_all(value);
+        match value {
+            Value::Array(arr) => errors.extend(arr.iter().flat_map(|val| self.validate_all(val))),
+            Value::Object(obj) => {
+                errors.extend(obj.values().flat_map(|val| self.validate_all(val)))
+            }
+            _ => {}
+        }
+        errors
+    }
+
+    fn describe(&self) -> String {
+        format!("a value whose nodes are each {}", self.validator.describe())
+    }
+}
+
+/// Give a validator the description of the expectation it implements.
+pub(crate) fn described(description: String, validator: impl Validator) -> impl Validator {
+    DescribedValidator {
+        description,
+        validator,
+    }
+}
+
+struct DescribedValidator<T>
+where
+    T: Validator,
+{
+    description: String,
+    validator: T,
+}
+
+impl<T> Validator for DescribedValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validator.validate(value)
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        self.validator.validate_all(value)
+    }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.validator.to_json_schema()
+    }
+}
+
+/// Give a validator the JSON Schema of the expectation it implements.
+pub(crate) fn with_schema(schema: Value, validator: impl Validator) -> impl Validator {
+    SchemaValidator { schema, validator }
+}
+
+struct SchemaValidator<T>
+where
+    T: Validator,
+{
+    schema: Value,
+    validator: T,
+}
+
+impl<T> Validator for SchemaValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validator.validate(value)
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        self.validator.validate_all(value)
+    }
+
+    fn describe(&self) -> String {
+        self.validator.describe()
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.schema.clone()
+    }
+}
+
+/// Whether the schema accepts any value.
+pub(crate) fn is_any_schema(schema: &Value) -> bool {
+    match schema {
+        Value::Bool(accept) => *accept,
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+/// Combine schemas which must all match, leaving out the ones accepting any
+/// value.
+pub(crate) fn all_of_schemas(schemas: impl IntoIterator<Item = Value>) -> Value {
+    let mut schemas: Vec<Value> = schemas
+        .into_iter()
+        .filter(|schema| !is_any_schema(schema))
+        .collect();
+    match schemas.len() {
+        0 => serde_json::json!({}),
+        1 => schemas.remove(0),
+        _ => serde_json::json!({ "allOf": schemas }),
+    }
+}
+
+/// Join the descriptions of the validators.
+pub(crate) fn join_descriptions(validators: &[Box<dyn Validator>], separator: &str) -> String {
+    validators
+        .iter()
+        .map(|validator| validator.describe())
+        .collect::<Vec<_>>()
+        .join(separator)
 }
 
 #[cfg(test)]
@@ -75,6 +872,52 @@ mod tests {
         assert_eq!(Ok(()), validator.validate(&serde_json::json!("test")))
     }
 
+    #[test]
+    fn eq_wide_integers() {
+        assert_eq!(
+            Ok(()),
+            super::eq_u128(u64::MAX as u128).validate(&serde_json::json!(u64::MAX))
+        );
+        assert_eq!(
+            Ok(()),
+            super::eq_i128(i64::MIN as i128).validate(&serde_json::json!(i64::MIN))
+        );
+        assert_eq!(
+            Err(String::from("Invalid value. Expected 6 but got 5.")),
+            super::eq_i128(6)
+                .validate(&serde_json::json!(5))
+                .map_err(|err| err.to_string())
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected 340282366920938463463374607431768211455 \
+                (out of the JSON number range) but got 5."
+            )),
+            super::eq_u128(u128::MAX)
+                .validate(&serde_json::json!(5))
+                .map_err(|err| err.to_string())
+        );
+        assert!(matches!(
+            super::eq_i128(5).validate(&serde_json::json!("5")),
+            Err(Error::InvalidType(_, _))
+        ));
+    }
+
+    #[test]
+    fn eq_number() {
+        let validator = super::eq(serde_json::Number::from_u128(u64::MAX as u128).unwrap());
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(u64::MAX)));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected 18446744073709551615 but got 5."
+            )),
+            validator
+                .validate(&serde_json::json!(5))
+                .map_err(|err| err.to_string())
+        );
+    }
+
     #[test]
     fn eq_string_fail() {
         let validator = super::eq(String::from("test"));
@@ -84,4 +927,288 @@ mod tests {
             Err(Error::InvalidValue(_, _))
         ));
     }
+
+    #[test]
+    fn eq_fixture() {
+        let validator = super::eq_fixture("tests/fixtures/expected_user.json");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({
+                "id": 5,
+                "name": "charlesvdv",
+                "roles": ["admin", "user"],
+            }))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"id": 5})),
+            Err(Error::ValueMismatch(_, _))
+        ));
+    }
+
+    #[test]
+    fn eq_serialize() {
+        #[derive(serde::Serialize)]
+        struct Item {
+            id: u64,
+            tags: Vec<&'static str>,
+        }
+
+        let validator = super::eq_serialize(Item {
+            id: 1,
+            tags: vec!["a"],
+        });
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"id": 1, "tags": ["a"]}))
+        );
+        assert_eq!(
+            Err(String::from(
+                r#"Invalid value. Expected {"id":1,"tags":["a"]} but got {"id":2,"tags":["a"]}."#
+            )),
+            validator
+                .validate(&serde_json::json!({"id": 2, "tags": ["a"]}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[derive(serde::Deserialize)]
+    #[allow(dead_code)]
+    struct User {
+        name: String,
+        roles: Vec<String>,
+    }
+
+    #[test]
+    fn matches_struct() {
+        let validator = super::matches_struct::<User>();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"name": "jane", "roles": [], "extra": 1}))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected value deserializable into \
+                assert_json::validators::tests::User (invalid type: integer `5`, expected a string) \
+                but got {\"name\":5,\"roles\":[]}."
+            )),
+            validator
+                .validate(&serde_json::json!({"name": 5, "roles": []}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn matches_struct_strict() {
+        let validator = super::matches_struct_strict::<User>();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"name": "jane", "roles": ["admin"]}))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected value deserializable into \
+                assert_json::validators::tests::User without unknown fields 'extra' \
+                but got {\"extra\":1,\"name\":\"jane\",\"roles\":[]}."
+            )),
+            validator
+                .validate(&serde_json::json!({"name": "jane", "roles": [], "extra": 1}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn one_of() {
+        let validator = super::one_of(["pending", "active", "done"]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("active")));
+        assert_eq!(
+            Err(String::from(
+                r#"Invalid value. Expected one of ["pending", "active", "done"] but got "unknown"."#
+            )),
+            validator
+                .validate(&serde_json::json!("unknown"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn any_of() {
+        let validator = super::any_of(vec![
+            Box::new(super::is_string()),
+            Box::new(super::u64(|_| Ok(()))),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("id")));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+        assert_eq!(
+            Err(String::from(
+                "None of the alternatives matched: \
+                Invalid type. Expected string but got number. | \
+                Invalid type. Expected u64 but got number."
+            )),
+            validator
+                .validate(&serde_json::json!(-1))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn all_of() {
+        let validator = super::all_of(vec![
+            Box::new(super::is_string()),
+            Box::new(super::str_min_len(1)),
+            Box::new(super::starts_with("ord-")),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("ord-1")));
+        assert!(matches!(
+            validator.validate(&serde_json::json!("")),
+            Err(Error::InvalidLength(_, _))
+        ));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(1)),
+            Err(Error::InvalidType(_, _))
+        ));
+    }
+
+    #[test]
+    fn not() {
+        let validator = super::not(super::eq("deleted"));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("active")));
+        assert_eq!(
+            Err(String::from(
+                r#"Unexpected match. Expected value to not match but "deleted" matched."#
+            )),
+            validator
+                .validate(&serde_json::json!("deleted"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn null_or() {
+        let validator = super::null_or(super::eq(5));
+
+        assert_eq!(Ok(()), validator.validate(&Value::Null));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(4)),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn custom() {
+        let validator = super::custom(|value| {
+            if value.is_string() {
+                Ok(())
+            } else {
+                Err(String::from("a string"))
+            }
+        });
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("test")));
+        assert!(matches!(
+            validator.validate(&Value::Null),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn labeled() {
+        let validator = super::labeled("customer must be an adult", super::ge(18));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(18)));
+        assert_eq!(
+            Err(String::from(
+                "customer must be an adult: Invalid value. Expected number >= 18 but got 17."
+            )),
+            validator
+                .validate(&serde_json::json!(17))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn when() {
+        let validator = super::when(super::is_string(), super::str_min_len(1), super::gt(0));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("a")));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(1)));
+        assert!(validator.validate(&serde_json::json!("")).is_err());
+        assert!(validator.validate(&serde_json::json!(0)).is_err());
+    }
+
+    #[test]
+    fn recursively() {
+        let validator = super::recursively(super::not(super::null()));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"a": [1, {"b": "c"}]}))
+        );
+
+        let value = serde_json::json!({"a": [1, {"b": null}]});
+        let error = validator.validate(&value).unwrap_err();
+        assert!(std::ptr::eq(&value["a"][1]["b"], error.location()));
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!("\"test\"", super::eq("test").describe());
+        assert_eq!("one of [1, 2]", super::one_of([1, 2]).describe());
+        assert_eq!(
+            "a string or null",
+            super::any_of(vec![Box::new(super::is_string()), Box::new(super::null())]).describe()
+        );
+        assert_eq!(
+            "not a number >= 5 and a number < 10",
+            super::not(super::ge(5).and(super::lt(10))).describe()
+        );
+        assert_eq!(
+            "adult: null or a number >= 18",
+            super::labeled("adult", super::null_or(super::ge(18))).describe()
+        );
+        assert_eq!(
+            "a value matching a custom predicate",
+            super::custom(|_| Ok(())).describe()
+        );
+    }
+
+    #[test]
+    fn to_json_schema() {
+        use serde_json::json;
+
+        assert_eq!(json!({}), super::any().to_json_schema());
+        assert_eq!(json!({"const": "test"}), super::eq("test").to_json_schema());
+        assert_eq!(
+            json!({"enum": [1, 2]}),
+            super::one_of([1, 2]).to_json_schema()
+        );
+        assert_eq!(
+            json!({"anyOf": [{"type": "string"}, {"type": "null"}]}),
+            super::any_of(vec![Box::new(super::is_string()), Box::new(super::null())])
+                .to_json_schema()
+        );
+        assert_eq!(
+            json!({"not": {"allOf": [
+                {"type": "number", "minimum": 5},
+                {"type": "number", "exclusiveMaximum": 10},
+            ]}}),
+            super::not(super::ge(5).and(super::lt(10))).to_json_schema()
+        );
+        assert_eq!(
+            json!({
+                "anyOf": [{"type": "null"}, {"type": "number", "minimum": 18}],
+                "description": "adult",
+            }),
+            super::labeled("adult", super::null_or(super::ge(18))).to_json_schema()
+        );
+        assert_eq!(json!({}), super::custom(|_| Ok(())).to_json_schema());
+    }
 }
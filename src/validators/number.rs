@@ -0,0 +1,449 @@
+use crate::validators;
+use crate::Validator;
+use serde_json::Number;
+use std::cmp::Ordering;
+
+/// Match if number is strictly greater than the expected value.
+///
+/// # Panics
+///
+/// Panics if the expected value is a float which is not finite.
+pub fn gt<T>(expected: T) -> impl Validator
+where
+    T: Numeric,
+{
+    compare(expected, ">", "exclusiveMinimum", |ord| {
+        ord == Ordering::Greater
+    })
+}
+
+/// Match if number is greater than or equal to the expected value.
+///
+/// # Panics
+///
+/// Panics if the expected value is a float which is not finite.
+pub fn ge<T>(expected: T) -> impl Validator
+where
+    T: Numeric,
+{
+    compare(expected, ">=", "minimum", |ord| ord != Ordering::Less)
+}
+
+/// Match if number is strictly lower than the expected value.
+///
+/// # Panics
+///
+/// Panics if the expected value is a float which is not finite.
+pub fn lt<T>(expected: T) -> impl Validator
+where
+    T: Numeric,
+{
+    compare(expected, "<", "exclusiveMaximum", |ord| {
+        ord == Ordering::Less
+    })
+}
+
+/// Match if number is lower than or equal to the expected value.
+///
+/// # Panics
+///
+/// Panics if the expected value is a float which is not finite.
+pub fn le<T>(expected: T) -> impl Validator
+where
+    T: Numeric,
+{
+    compare(expected, "<=", "maximum", |ord| ord != Ordering::Greater)
+}
+
+/// Match if number is within the inclusive interval `[low, high]`.
+///
+/// # Panics
+///
+/// Panics if a bound is a float which is not finite.
+pub fn between<T, U>(low: T, high: U) -> impl Validator
+where
+    T: Numeric,
+    U: Numeric,
+{
+    interval(low, high, true)
+}
+
+/// Match if number is within the exclusive interval `]low, high[`.
+///
+/// # Panics
+///
+/// Panics if a bound is a float which is not finite.
+pub fn between_exclusive<T, U>(low: T, high: U) -> impl Validator
+where
+    T: Numeric,
+    U: Numeric,
+{
+    interval(low, high, false)
+}
+
+/// Match if number is within `epsilon` of the expected value.
+pub fn near(expected: f64, epsilon: f64) -> impl Validator {
+    validators::described(
+        format!("a number within {} of {}", epsilon, expected),
+        validators::number(move |val| match val.as_f64() {
+            Some(val) if (val - expected).abs() <= epsilon => Ok(()),
+            _ => Err(format!("number within {} of {}", epsilon, expected)),
+        }),
+    )
+}
+
+/// Match if number is within `ratio` times the expected value of the expected value.
+///
+/// For example, a `ratio` of `0.01` accepts values that differ from the expected
+/// value by at most 1%.
+pub fn near_relative(expected: f64, ratio: f64) -> impl Validator {
+    validators::described(
+        format!("a number within {}% of {}", ratio * 100.0, expected),
+        validators::number(move |val| match val.as_f64() {
+            Some(val) if (val - expected).abs() <= (ratio * expected).abs() => Ok(()),
+            _ => Err(format!("number within {}% of {}", ratio * 100.0, expected)),
+        }),
+    )
+}
+
+/// Match if number is a multiple of the expected divisor.
+///
+/// As in JSON Schema `multipleOf`, the number is valid if its division by
+/// `divisor` is an integer.
+///
+/// # Panics
+///
+/// Panics if `divisor` is not strictly positive, or a float which is not
+/// finite.
+pub fn multiple_of<T>(divisor: T) -> impl Validator
+where
+    T: Numeric,
+{
+    let divisor = Numeric::into_number(divisor);
+    assert!(
+        divisor.as_f64().is_some_and(|d| d > 0.0),
+        "multiple_of divisor should be strictly positive"
+    );
+    let schema = serde_json::json!({ "type": "number", "multipleOf": divisor });
+    let validator = validators::described(
+        format!("a multiple of {}", divisor),
+        validators::number(move |val| {
+            let is_multiple = match (as_i128(val), as_i128(&divisor)) {
+                (Some(val), Some(divisor)) => val % divisor == 0,
+                _ => match (val.as_f64(), divisor.as_f64()) {
+                    (Some(val), Some(divisor)) => {
+                        let quotient = val / divisor;
+                        (quotient - quotient.round()).abs()
+                            <= f64::EPSILON * quotient.abs().max(1.0)
+                    }
+                    _ => false,
+                },
+            };
+
+            if is_multiple {
+                Ok(())
+            } else {
+                Err(format!("multiple of {}", divisor))
+            }
+        }),
+    );
+    validators::with_schema(schema, validator)
+}
+
+fn interval<T, U>(low: T, high: U, inclusive: bool) -> impl Validator
+where
+    T: Numeric,
+    U: Numeric,
+{
+    let low = Numeric::into_number(low);
+    let high = Numeric::into_number(high);
+    let schema = if inclusive {
+        serde_json::json!({ "type": "number", "minimum": low, "maximum": high })
+    } else {
+        serde_json::json!({ "type": "number", "exclusiveMinimum": low, "exclusiveMaximum": high })
+    };
+    let validator = validators::described(
+        if inclusive {
+            format!("a number in [{}, {}]", low, high)
+        } else {
+            format!("a number in ]{}, {}[", low, high)
+        },
+        validators::number(move |val| {
+            let above_low = cmp_numbers(val, &low).map(|ord| match ord {
+                Ordering::Greater => true,
+                Ordering::Equal => inclusive,
+                Ordering::Less => false,
+            });
+            let below_high = cmp_numbers(val, &high).map(|ord| match ord {
+                Ordering::Less => true,
+                Ordering::Equal => inclusive,
+                Ordering::Greater => false,
+            });
+
+            if above_low == Some(true) && below_high == Some(true) {
+                Ok(())
+            } else if inclusive {
+                Err(format!("number in [{}, {}]", low, high))
+            } else {
+                Err(format!("number in ]{}, {}[", low, high))
+            }
+        }),
+    );
+    validators::with_schema(schema, validator)
+}
+
+/// Compare numbers with the operator, named `keyword` in JSON Schema.
+fn compare<T, F>(expected: T, operator: &'static str, keyword: &str, accept: F) -> impl Validator
+where
+    T: Numeric,
+    F: Fn(Ordering) -> bool + 'static,
+{
+    let expected = Numeric::into_number(expected);
+    validators::with_schema(
+        serde_json::json!({ "type": "number", keyword: expected }),
+        validators::described(
+            format!("a number {} {}", operator, expected),
+            validators::number(move |val| match cmp_numbers(val, &expected) {
+                Some(ord) if accept(ord) => Ok(()),
+                _ => Err(format!("number {} {}", operator, expected)),
+            }),
+        ),
+    )
+}
+
+/// A number the numeric validators compare with: a primitive integer or
+/// float, or a JSON [Number].
+///
+/// ```compile_fail
+/// assert_json::validators::gt("abc");
+/// ```
+pub trait Numeric {
+    /// Convert into a JSON number.
+    ///
+    /// Panics if the value is a float which is not finite.
+    fn into_number(self) -> Number;
+}
+
+macro_rules! impl_numeric_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Numeric for $ty {
+                fn into_number(self) -> Number {
+                    Number::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Numeric for f32 {
+    fn into_number(self) -> Number {
+        f64::from(self).into_number()
+    }
+}
+
+impl Numeric for f64 {
+    fn into_number(self) -> Number {
+        match Number::from_f64(self) {
+            Some(num) => num,
+            None => panic!("expected a finite number but got {}", self),
+        }
+    }
+}
+
+impl Numeric for Number {
+    fn into_number(self) -> Number {
+        self
+    }
+}
+
+impl Numeric for &Number {
+    fn into_number(self) -> Number {
+        self.clone()
+    }
+}
+
+/// Compare two numbers regardless of their internal representation.
+///
+/// Integers are compared exactly; as soon as one of them is a float,
+/// both are compared as floats.
+pub(crate) fn cmp_numbers(a: &Number, b: &Number) -> Option<Ordering> {
+    match (as_i128(a), as_i128(b)) {
+        (Some(a), Some(b)) => Some(a.cmp(&b)),
+        _ => a.as_f64()?.partial_cmp(&b.as_f64()?),
+    }
+}
+
+fn as_i128(num: &Number) -> Option<i128> {
+    num.as_i64()
+        .map(i128::from)
+        .or_else(|| num.as_u64().map(i128::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+
+    #[test]
+    fn gt() {
+        let validator = super::gt(0);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(1)));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(0.5)));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(0)),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn ge() {
+        let validator = super::ge(10);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(10)));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(10.0)));
+        assert!(validator.validate(&serde_json::json!(9.9)).is_err());
+    }
+
+    #[test]
+    fn lt() {
+        let validator = super::lt(100.0);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(99)));
+        assert!(validator.validate(&serde_json::json!(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn le() {
+        let validator = super::le(5);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(-5)));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected number <= 5 but got 6."
+            )),
+            validator
+                .validate(&serde_json::json!(6))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn json_number() {
+        let limit = serde_json::json!({ "limit": 10 });
+        let validator = super::le(limit["limit"].as_number().unwrap());
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(10)));
+        assert!(validator.validate(&serde_json::json!(11)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a finite number but got NaN")]
+    fn not_finite() {
+        super::gt(f64::NAN);
+    }
+
+    #[test]
+    fn between() {
+        let validator = super::between(1, 5.5);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(1)));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5.5)));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected number in [1, 5.5] but got 7."
+            )),
+            validator
+                .validate(&serde_json::json!(7))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn between_exclusive() {
+        let validator = super::between_exclusive(1, 5);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(3)));
+        assert!(validator.validate(&serde_json::json!(1)).is_err());
+        assert!(validator.validate(&serde_json::json!(5)).is_err());
+    }
+
+    #[test]
+    fn near() {
+        let validator = super::near(0.3, 1e-9);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(0.1 + 0.2)));
+        assert!(matches!(
+            validator.validate(&serde_json::json!(0.31)),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn near_relative() {
+        let validator = super::near_relative(200.0, 0.01);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(198)));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected number within 1% of 200 but got 197.5."
+            )),
+            validator
+                .validate(&serde_json::json!(197.5))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn multiple_of() {
+        let validator = super::multiple_of(10);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(30)));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(-20)));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(40.0)));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected multiple of 10 but got 25."
+            )),
+            validator
+                .validate(&serde_json::json!(25))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn multiple_of_float() {
+        let validator = super::multiple_of(0.1);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(0.3)));
+        assert!(validator.validate(&serde_json::json!(0.35)).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiple_of_zero() {
+        super::multiple_of(0);
+    }
+
+    #[test]
+    fn compare_invalid_type() {
+        let validator = super::gt(0);
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!("1")),
+            Err(Error::InvalidType(_, _))
+        ));
+    }
+
+    #[test]
+    fn cmp_numbers_mixed_representations() {
+        use std::cmp::Ordering;
+
+        let big = serde_json::Number::from(u64::MAX);
+        let negative = serde_json::Number::from(-1);
+
+        assert_eq!(Some(Ordering::Greater), super::cmp_numbers(&big, &negative));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{Error, Validator, Value};
+use serde_json::Map;
 use std::collections::HashMap;
 
 /// Match if each key/value pair matches
@@ -12,7 +13,39 @@ pub fn object(key_validators: HashMap<String, Box<dyn Validator>>) -> impl Valid
     }
 }
 
+/// Match if each listed key/value pair matches.
+///
+/// Same as [object] but takes a list of pairs, which is easier to write
+/// outside of the [assert_json!](crate::assert_json) macro.
+///
+/// ```
+/// use assert_json::{assert_json, validators, Validator};
+///
+/// assert_json!(
+///     r#"{"id": 5, "name": "charlesvdv", "created_at": "2021-10-14"}"#,
+///     validators::object_contains(vec![
+///         ("id", Box::new(validators::eq(5)) as Box<dyn Validator>),
+///         ("name", Box::new(validators::is_string())),
+///     ])
+/// );
+/// ```
+pub fn object_contains<I, K>(key_validators: I) -> impl Validator
+where
+    I: IntoIterator<Item = (K, Box<dyn Validator>)>,
+    K: Into<String>,
+{
+    ObjectValidator {
+        key_validators: key_validators
+            .into_iter()
+            .map(|(key, validator)| (key.into(), validator))
+            .collect(),
+        strict: false,
+    }
+}
+
 /// Match if each key/value pairs matches. Fail if a key is missing in the validators.
+///
+/// All the missing keys and all the unexpected keys are reported at once.
 pub fn object_strict(key_validators: HashMap<String, Box<dyn Validator>>) -> impl Validator {
     ObjectValidator {
         key_validators,
@@ -33,33 +66,509 @@ struct ObjectValidator {
     strict: bool,
 }
 
+impl ObjectValidator {
+    /// Report the missing keys and, in strict mode, the unexpected keys.
+    fn check_keys<'a>(&self, value: &'a Value, object: &'a Map<String, Value>) -> Vec<Error<'a>> {
+        let mut errors = vec![];
+
+        let mut missing_keys: Vec<String> = self
+            .key_validators
+            .keys()
+            .filter(|key| !object.contains_key(*key))
+            .cloned()
+            .collect();
+        missing_keys.sort();
+        match missing_keys.len() {
+            0 => {}
+            1 => errors.push(Error::MissingObjectKey(value, missing_keys.remove(0))),
+            _ => errors.push(Error::MissingObjectKeys(value, missing_keys)),
+        }
+
+        if self.strict {
+            // Make sure there is no other keys than the one defined in the validator
+            // if we are in strict mode.
+            let mut unexpected_keys: Vec<(&String, &Value)> = object
+                .iter()
+                .filter(|(key, _)| !self.key_validators.contains_key(*key))
+                .collect();
+            match unexpected_keys.len() {
+                0 => {}
+                1 => {
+                    let (key, inner_value) = unexpected_keys.remove(0);
+                    errors.push(Error::UnexpectedObjectKey(inner_value, key.clone()));
+                }
+                _ => errors.push(Error::UnexpectedObjectKeys(
+                    value,
+                    unexpected_keys
+                        .into_iter()
+                        .map(|(key, _)| key.clone())
+                        .collect(),
+                )),
+            }
+        }
+
+        errors
+    }
+
+    fn sorted_keys(&self) -> Vec<&String> {
+        let mut keys: Vec<&String> = self.key_validators.keys().collect();
+        keys.sort();
+        keys
+    }
+}
+
 impl Validator for ObjectValidator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
         let object = value
             .as_object()
             .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
 
-        for (key, validator) in &self.key_validators {
-            let inner_value = object
-                .get(key)
-                .ok_or_else(|| Error::MissingObjectKey(value, key.clone()))?;
+        if let Some(err) = self.check_keys(value, object).into_iter().next() {
+            return Err(err);
+        }
+
+        for key in self.sorted_keys() {
+            crate::coverage::visit(value, &object[key]);
+            self.key_validators[key].validate(&object[key])?
+        }
+
+        Ok(())
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(object) = value.as_object() else {
+            return vec![Error::InvalidType(value, String::from("object"))];
+        };
 
-            validator.validate(inner_value)?
+        let mut errors = self.check_keys(value, object);
+        for key in self.sorted_keys() {
+            if let Some(inner_value) = object.get(key) {
+                crate::coverage::visit(value, inner_value);
+                errors.extend(self.key_validators[key].validate_all(inner_value));
+            }
         }
+        errors
+    }
 
+    fn describe(&self) -> String {
+        let pairs: Vec<String> = self
+            .sorted_keys()
+            .into_iter()
+            .map(|key| format!("'{}' being {}", key, self.key_validators[key].describe()))
+            .collect();
+        match (pairs.is_empty(), self.strict) {
+            (true, true) => String::from("an empty object"),
+            (true, false) => String::from("an object"),
+            (false, true) => format!("an object with exactly {}", pairs.join(", ")),
+            (false, false) => format!("an object with {}", pairs.join(", ")),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let keys = self.sorted_keys();
+        let properties: Map<String, Value> = keys
+            .iter()
+            .map(|key| {
+                (
+                    String::clone(key),
+                    self.key_validators[*key].to_json_schema(),
+                )
+            })
+            .collect();
+        let mut schema = serde_json::json!({ "type": "object" });
+        if !properties.is_empty() {
+            schema["properties"] = Value::Object(properties);
+            schema["required"] = serde_json::json!(keys);
+        }
         if self.strict {
-            // Make sure there is no other keys than the one defined in the validator
-            // if we are in strict mode.
-            for (key, value) in object {
-                self.key_validators
-                    .get(key)
-                    .ok_or_else(|| Error::UnexpectedObjectKey(value, key.clone()))
-                    .map(|_| ())?
+            schema["additionalProperties"] = Value::Bool(false);
+        }
+        schema
+    }
+}
+
+/// Match if the object contains the key, whatever its value.
+pub fn has_key(key: &str) -> impl Validator {
+    KeyPresenceValidator {
+        key: String::from(key),
+        present: true,
+    }
+}
+
+/// Match if the object does not contain the key.
+pub fn lacks_key(key: &str) -> impl Validator {
+    KeyPresenceValidator {
+        key: String::from(key),
+        present: false,
+    }
+}
+
+struct KeyPresenceValidator {
+    key: String,
+    present: bool,
+}
+
+impl Validator for KeyPresenceValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
+
+        match (object.get(&self.key), self.present) {
+            (None, true) => Err(Error::MissingObjectKey(value, self.key.clone())),
+            (Some(inner_value), false) => {
+                Err(Error::UnexpectedObjectKey(inner_value, self.key.clone()))
             }
+            _ => Ok(()),
         }
+    }
+
+    fn describe(&self) -> String {
+        if self.present {
+            format!("an object with key '{}'", self.key)
+        } else {
+            format!("an object without key '{}'", self.key)
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        if self.present {
+            serde_json::json!({ "type": "object", "required": [self.key] })
+        } else {
+            serde_json::json!({ "type": "object", "not": { "required": [self.key] } })
+        }
+    }
+}
 
+/// Match if the object has exactly the expected number of keys.
+pub fn object_len(expected: usize) -> impl Validator {
+    ObjectLenValidator {
+        min: expected,
+        max: expected,
+    }
+}
+
+/// Match if the object has at least the expected number of keys.
+pub fn object_min_len(min: usize) -> impl Validator {
+    ObjectLenValidator {
+        min,
+        max: usize::MAX,
+    }
+}
+
+/// Match if the object has at most the expected number of keys.
+pub fn object_max_len(max: usize) -> impl Validator {
+    ObjectLenValidator { min: 0, max }
+}
+
+struct ObjectLenValidator {
+    min: usize,
+    max: usize,
+}
+
+impl Validator for ObjectLenValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
+
+        if object.len() >= self.min && object.len() <= self.max {
+            return Ok(());
+        }
+
+        let keys: Vec<String> = object.keys().cloned().collect();
+        Err(Error::InvalidLength(
+            value,
+            format!(
+                "{} (actual keys: {})",
+                self.expectation(),
+                crate::join_keys(&keys)
+            ),
+        ))
+    }
+
+    fn describe(&self) -> String {
+        format!("an {}", self.expectation())
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = serde_json::json!({ "type": "object", "minProperties": self.min });
+        if self.max != usize::MAX {
+            schema["maxProperties"] = Value::from(self.max);
+        }
+        schema
+    }
+}
+
+impl ObjectLenValidator {
+    fn expectation(&self) -> String {
+        if self.min == self.max {
+            format!("object with {} keys", self.min)
+        } else if self.max == usize::MAX {
+            format!("object with >= {} keys", self.min)
+        } else {
+            format!("object with <= {} keys", self.max)
+        }
+    }
+}
+
+/// Match if every value of the object match the validator, whatever the key.
+pub fn object_values(validator: impl Validator) -> impl Validator {
+    ObjectKeysMatchingValidator {
+        keys: String::from("values"),
+        key_pattern: None,
+        matcher: |_: &str| true,
+        validator,
+    }
+}
+
+/// Match if every key of the object match `key_validator` and every value
+/// match `value_validator`.
+///
+/// Keys are validated as JSON strings.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"en": "hello", "fr": "bonjour"}"#,
+///     validators::map_of(validators::str_len(2), validators::is_string())
+/// );
+/// ```
+pub fn map_of(key_validator: impl Validator, value_validator: impl Validator) -> impl Validator {
+    MapOfValidator {
+        key_validator,
+        value_validator,
+    }
+}
+
+struct MapOfValidator<T, U>
+where
+    T: Validator,
+    U: Validator,
+{
+    key_validator: T,
+    value_validator: U,
+}
+
+impl<T, U> Validator for MapOfValidator<T, U>
+where
+    T: Validator,
+    U: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
+
+        for (key, inner_value) in object {
+            // The key is not a JSON value of the input so the error must be
+            // converted to point to the object itself.
+            let key_value = Value::String(key.clone());
+            if let Err(err) = self.key_validator.validate(&key_value) {
+                return Err(Error::InvalidObjectKey(value, key.clone(), err.to_string()));
+            }
+            crate::coverage::visit(value, inner_value);
+
+            self.value_validator
+                .validate(inner_value)
+                .map_err(|err| Error::InvalidObjectValue(key.clone(), Box::new(err)))?;
+        }
         Ok(())
     }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(object) = value.as_object() else {
+            return vec![Error::InvalidType(value, String::from("object"))];
+        };
+
+        let mut errors = vec![];
+        for (key, inner_value) in object {
+            let key_value = Value::String(key.clone());
+            if let Err(err) = self.key_validator.validate(&key_value) {
+                errors.push(Error::InvalidObjectKey(value, key.clone(), err.to_string()));
+            }
+            crate::coverage::visit(value, inner_value);
+            errors.extend(
+                self.value_validator
+                    .validate_all(inner_value)
+                    .into_iter()
+                    .map(|err| Error::InvalidObjectValue(key.clone(), Box::new(err))),
+            );
+        }
+        errors
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "an object whose keys are each {} and whose values are each {}",
+            self.key_validator.describe(),
+            self.value_validator.describe()
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        serde_json::json!({
+            "type": "object",
+            "propertyNames": self.key_validator.to_json_schema(),
+            "additionalProperties": self.value_validator.to_json_schema(),
+        })
+    }
+}
+
+/// Match if the values of all the keys matching the wildcard pattern match the validator.
+///
+/// `*` matches any sequence of characters and `?` matches a single character.
+/// Keys not matching the pattern are ignored.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"metric_cpu": 3, "metric_mem": 12, "host": "a"}"#,
+///     validators::object_keys_matching("metric_*", validators::gt(0))
+/// );
+/// ```
+pub fn object_keys_matching(pattern: &str, validator: impl Validator) -> impl Validator {
+    let pattern = String::from(pattern);
+    ObjectKeysMatchingValidator {
+        keys: format!("values at keys matching '{}'", pattern),
+        key_pattern: Some(wildcard_regex(&pattern)),
+        matcher: move |key: &str| wildcard_match(&pattern, key),
+        validator,
+    }
+}
+
+/// Match if the values of all the keys matching the regular expression match the validator.
+///
+/// Keys not matching the pattern are ignored. Panics if the pattern is not a
+/// valid regular expression.
+#[cfg(feature = "regex")]
+pub fn object_keys_matching_regex(pattern: &str, validator: impl Validator) -> impl Validator {
+    let re = regex::Regex::new(pattern).expect("invalid regular expression");
+    ObjectKeysMatchingValidator {
+        keys: format!("values at keys matching {}", pattern),
+        key_pattern: Some(String::from(pattern)),
+        matcher: move |key: &str| re.is_match(key),
+        validator,
+    }
+}
+
+struct ObjectKeysMatchingValidator<F, T>
+where
+    F: Fn(&str) -> bool,
+    T: Validator,
+{
+    /// Description of the values which are validated
+    keys: String,
+    /// Regular expression of the keys which are validated, all of them if
+    /// `None`.
+    key_pattern: Option<String>,
+    matcher: F,
+    validator: T,
+}
+
+impl<F, T> Validator for ObjectKeysMatchingValidator<F, T>
+where
+    F: Fn(&str) -> bool,
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
+
+        object
+            .iter()
+            .filter(|(key, _)| (self.matcher)(key))
+            .try_for_each(|(key, inner_value)| {
+                crate::coverage::visit(value, inner_value);
+                self.validator
+                    .validate(inner_value)
+                    .map_err(|err| Error::InvalidObjectValue(key.clone(), Box::new(err)))
+            })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        let Some(object) = value.as_object() else {
+            return vec![Error::InvalidType(value, String::from("object"))];
+        };
+
+        object
+            .iter()
+            .filter(|(key, _)| (self.matcher)(key))
+            .flat_map(|(key, inner_value)| {
+                crate::coverage::visit(value, inner_value);
+                self.validator
+                    .validate_all(inner_value)
+                    .into_iter()
+                    .map(|err| Error::InvalidObjectValue(key.clone(), Box::new(err)))
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "an object whose {} are each {}",
+            self.keys,
+            self.validator.describe()
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let schema = self.validator.to_json_schema();
+        match &self.key_pattern {
+            Some(pattern) => serde_json::json!({
+                "type": "object",
+                "patternProperties": { pattern.clone(): schema },
+            }),
+            None => serde_json::json!({ "type": "object", "additionalProperties": schema }),
+        }
+    }
+}
+
+/// Convert a wildcard pattern into a regular expression.
+fn wildcard_regex(pattern: &str) -> String {
+    let mut regex = String::from("^");
+    for c in pattern.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&super::string::escape_regex(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// Match `text` against a pattern where `*` matches any sequence of
+/// characters and `?` matches exactly one character.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text position it
+    // was matched against, used to backtrack.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 #[cfg(test)]
@@ -99,4 +608,293 @@ mod tests {
             Err(Error::MissingObjectKey(_, _))
         ));
     }
+
+    #[test]
+    fn validate_all() {
+        let validator = super::object_strict(HashMap::from([
+            (
+                String::from("a"),
+                Box::new(validators::eq(1)) as Box<dyn Validator>,
+            ),
+            (String::from("b"), Box::new(validators::eq(2))),
+            (String::from("missing"), Box::new(validators::any())),
+        ]));
+
+        assert_eq!(
+            vec![
+                String::from("Missing key 'missing' in object"),
+                String::from("Key 'extra' is not expected in object"),
+                String::from("Invalid value. Expected 1 but got 0."),
+                String::from("Invalid value. Expected 2 but got 0."),
+            ],
+            validator
+                .validate_all(&serde_json::json!({"a": 0, "b": 0, "extra": true}))
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let validator = super::object_contains(vec![
+            ("key", Box::new(validators::eq("val")) as Box<dyn Validator>),
+            ("other", Box::new(validators::any())),
+        ]);
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"key": "val", "other": 1, "extra": true}))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"key": "val"})),
+            Err(Error::MissingObjectKey(_, _))
+        ));
+    }
+
+    #[test]
+    fn all_missing_keys() {
+        let mut key_validators: HashMap<String, Box<dyn Validator>> = HashMap::new();
+        key_validators.insert(String::from("b"), Box::new(validators::any()));
+        key_validators.insert(String::from("a"), Box::new(validators::any()));
+        key_validators.insert(String::from("c"), Box::new(validators::any()));
+
+        let validator = super::object(key_validators);
+        assert_eq!(
+            Err(String::from("Missing keys 'a', 'b' in object")),
+            validator
+                .validate(&serde_json::json!({"c": null}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn strict_unexpected_keys() {
+        let mut key_validators: HashMap<String, Box<dyn Validator>> = HashMap::new();
+        key_validators.insert(String::from("key"), Box::new(validators::any()));
+
+        let validator = super::object_strict(key_validators);
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!({"key": 1})));
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"key": 1, "extra": 2})),
+            Err(Error::UnexpectedObjectKey(_, _))
+        ));
+        assert_eq!(
+            Err(String::from(
+                "Keys 'extra', 'other' are not expected in object"
+            )),
+            validator
+                .validate(&serde_json::json!({"key": 1, "extra": 2, "other": 3}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn wildcard_match() {
+        assert!(super::wildcard_match("metric_*", "metric_cpu"));
+        assert!(super::wildcard_match("metric_*", "metric_"));
+        assert!(super::wildcard_match("*_?", "a_b_c"));
+        assert!(super::wildcard_match("*", ""));
+        assert!(!super::wildcard_match("metric_*", "host"));
+        assert!(!super::wildcard_match("a?c", "abbc"));
+    }
+
+    #[test]
+    fn keys_matching() {
+        let validator = super::object_keys_matching("metric_*", validators::gt(0));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"metric_a": 1, "host": -1}))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid object value at key 'metric_b'. Invalid value. Expected number > 0 but got 0."
+            )),
+            validator
+                .validate(&serde_json::json!({"metric_a": 1, "metric_b": 0}))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn keys_matching_regex() {
+        let validator = super::object_keys_matching_regex("^metric_.*$", validators::gt(0));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"metric_a": 1, "host": -1}))
+        );
+        assert!(validator
+            .validate(&serde_json::json!({"metric_a": 0}))
+            .is_err());
+    }
+
+    #[test]
+    fn values() {
+        let validator = super::object_values(validators::str_min_len(1));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"en": "hello", "fr": "bonjour"}))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"en": "hello", "fr": ""})),
+            Err(Error::InvalidObjectValue(key, _)) if key == "fr"
+        ));
+    }
+
+    #[test]
+    fn len() {
+        let value = serde_json::json!({"a": 1, "b": 2});
+
+        assert_eq!(Ok(()), super::object_len(2).validate(&value));
+        assert_eq!(Ok(()), super::object_min_len(1).validate(&value));
+        assert_eq!(Ok(()), super::object_max_len(2).validate(&value));
+        assert!(super::object_max_len(1).validate(&value).is_err());
+        assert_eq!(
+            Err(String::from(
+                r#"Invalid value. Expected object with >= 3 keys (actual keys: 'a', 'b') but got {"a":1,"b":2}."#
+            )),
+            super::object_min_len(3)
+                .validate(&value)
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn has_key() {
+        let validator = super::has_key("name");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"name": null}))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({})),
+            Err(Error::MissingObjectKey(_, _))
+        ));
+    }
+
+    #[test]
+    fn lacks_key() {
+        let validator = super::lacks_key("password");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"name": "a"}))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"password": "secret"})),
+            Err(Error::UnexpectedObjectKey(_, _))
+        ));
+    }
+
+    #[test]
+    fn map_of() {
+        let validator = super::map_of(validators::starts_with("id_"), validators::gt(0));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"id_a": 1, "id_b": 2}))
+        );
+        assert_eq!(
+            Err(String::from(
+                r#"Invalid object key 'b'. Invalid value. Expected string starting with "id_" but got "b"."#
+            )),
+            validator
+                .validate(&serde_json::json!({"id_a": 1, "b": 2}))
+                .map_err(|err| err.to_string())
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"id_a": 0})),
+            Err(Error::InvalidObjectValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn describe() {
+        let validator = super::object_contains(vec![
+            (
+                "name",
+                Box::new(validators::is_string()) as Box<dyn Validator>,
+            ),
+            ("id", Box::new(validators::gt(0))),
+        ]);
+        assert_eq!(
+            "an object with 'id' being a number > 0, 'name' being a string",
+            validator.describe()
+        );
+        assert_eq!("an empty object", super::object_empty().describe());
+        assert_eq!(
+            "an object whose values at keys matching 'id_*' are each a string",
+            super::object_keys_matching("id_*", validators::is_string()).describe()
+        );
+    }
+
+    #[test]
+    fn to_json_schema() {
+        use serde_json::json;
+
+        let validator = validators::object(HashMap::from([
+            (
+                String::from("id"),
+                Box::new(validators::u64(|_| Ok(()))) as Box<dyn Validator>,
+            ),
+            (String::from("name"), Box::new(validators::is_string())),
+        ]));
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer", "minimum": 0},
+                    "name": {"type": "string"},
+                },
+                "required": ["id", "name"],
+            }),
+            validator.to_json_schema()
+        );
+        assert_eq!(
+            json!({"type": "object", "additionalProperties": false}),
+            validators::object_strict(HashMap::new()).to_json_schema()
+        );
+        assert_eq!(
+            json!({"type": "object", "patternProperties": {"^user_.*$": {"type": "string"}}}),
+            validators::object_keys_matching("user_*", validators::is_string()).to_json_schema()
+        );
+    }
+
+    #[test]
+    fn map_of_validate_all() {
+        let validator = super::map_of(validators::str_len(2), validators::is_string());
+
+        assert_eq!(
+            vec![
+                String::from(
+                    "Invalid object value at key 'de'. Invalid type. Expected string but got number."
+                ),
+                String::from(
+                    "Invalid object value at key 'fr'. Invalid type. Expected string but got bool."
+                ),
+            ],
+            validator
+                .validate_all(&serde_json::json!({"de": 1, "en": "hello", "fr": true}))
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn object_values_validate_all() {
+        let validator = super::object_values(validators::gt(0));
+
+        assert_eq!(
+            2,
+            validator
+                .validate_all(&serde_json::json!({"a": 0, "b": 1, "c": -1}))
+                .len()
+        );
+    }
 }
@@ -0,0 +1,345 @@
+use crate::{Error, Validator, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+thread_local! {
+    /// Copy of the root of the document being validated, see [document], set
+    /// by [with_document] for the duration of the validation.
+    static DOCUMENT: RefCell<Option<Rc<Value>>> = const { RefCell::new(None) };
+}
+
+/// Match if the value found at the JSON pointer match the validator.
+///
+/// Fail if there is no value at the pointer.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"data": {"items": [{"id": 5}]}}"#,
+///     validators::at("/data/items/0/id", validators::eq(5))
+/// );
+/// ```
+pub fn at(pointer: &str, validator: impl Validator) -> impl Validator {
+    AtValidator {
+        pointer: String::from(pointer),
+        validator,
+    }
+}
+
+struct AtValidator<T>
+where
+    T: Validator,
+{
+    pointer: String,
+    validator: T,
+}
+
+impl<T> Validator for AtValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let target = resolve(value, &self.pointer)?;
+        crate::coverage::visit_pointer(value, &self.pointer);
+        self.validator.validate(target)
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        match resolve(value, &self.pointer) {
+            Ok(target) => {
+                crate::coverage::visit_pointer(value, &self.pointer);
+                self.validator.validate_all(target)
+            }
+            Err(err) => vec![err],
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "a value with {} at '{}'",
+            self.validator.describe(),
+            self.pointer
+        )
+    }
+
+    fn to_json_schema(&self) -> Value {
+        // Numeric tokens are taken as array indices.
+        let tokens: Vec<String> = self
+            .pointer
+            .split('/')
+            .skip(1)
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect();
+        tokens
+            .iter()
+            .rev()
+            .fold(
+                self.validator.to_json_schema(),
+                |inner, token| match token.parse::<usize>() {
+                    Ok(index) => {
+                        let mut items = vec![Value::Object(Default::default()); index];
+                        items.push(inner);
+                        serde_json::json!({
+                            "type": "array",
+                            "prefixItems": items,
+                            "minItems": index + 1,
+                        })
+                    }
+                    Err(_) => serde_json::json!({
+                        "type": "object",
+                        "properties": { token.as_str(): inner },
+                        "required": [token],
+                    }),
+                },
+            )
+    }
+}
+
+/// Treat the validated value as the root document for the inner validator.
+///
+/// Validators that refer to other parts of the document, like [same_as], resolve
+/// their JSON pointers against this root. [assert_json!](crate::assert_json)
+/// already wraps its validator with it.
+pub fn document(validator: impl Validator) -> impl Validator {
+    DocumentValidator { validator }
+}
+
+struct DocumentValidator<T>
+where
+    T: Validator,
+{
+    validator: T,
+}
+
+impl<T> Validator for DocumentValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        with_document(value, || self.validator.validate(value))
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Vec<Error<'a>> {
+        with_document(value, || self.validator.validate_all(value))
+    }
+
+    fn describe(&self) -> String {
+        self.validator.describe()
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.validator.to_json_schema()
+    }
+}
+
+/// Run `f` with `value` as the document root for [same_as].
+pub(crate) fn with_document<R>(value: &Value, f: impl FnOnce() -> R) -> R {
+    /// Restore the previous document, even on panic.
+    struct Guard(Option<Rc<Value>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            DOCUMENT.with(|doc| *doc.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _guard = Guard(DOCUMENT.with(|doc| doc.replace(Some(Rc::new(value.clone())))));
+    f()
+}
+
+/// Match if the value equals the value found at the JSON pointer of the document.
+///
+/// The pointer is resolved from the root of the document, see [document].
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(
+///     r#"{"data": {"owner_id": 5}, "meta": {"requested_by": 5}}"#,
+///     {
+///         "data": {
+///             "owner_id": validators::same_as("/meta/requested_by"),
+///         }
+///     }
+/// );
+/// ```
+pub fn same_as(pointer: &str) -> impl Validator {
+    SameAsValidator {
+        pointer: String::from(pointer),
+    }
+}
+
+struct SameAsValidator {
+    pointer: String,
+}
+
+impl Validator for SameAsValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let root = DOCUMENT.with(|doc| doc.borrow().clone()).ok_or_else(|| {
+            Error::InvalidValue(
+                value,
+                format!(
+                    "a document to resolve '{}' (wrap the validator with validators::document)",
+                    self.pointer
+                ),
+            )
+        })?;
+        let other = root
+            .pointer(&self.pointer)
+            .ok_or_else(|| Error::MissingPointer(value, self.pointer.clone()))?;
+
+        if other == value {
+            Ok(())
+        } else {
+            Err(Error::InvalidValue(
+                value,
+                format!("same value as '{}' ({})", self.pointer, other),
+            ))
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("the same value as '{}'", self.pointer)
+    }
+}
+
+/// Resolve a JSON pointer.
+///
+/// When the pointer does not exist, the error points to the deepest
+/// existing parent.
+pub(crate) fn resolve<'a>(value: &'a Value, pointer: &str) -> Result<&'a Value, Error<'a>> {
+    value.pointer(pointer).ok_or_else(|| {
+        let mut parent = value;
+        let mut prefix = String::new();
+        for token in pointer.split('/').skip(1) {
+            prefix.push('/');
+            prefix.push_str(token);
+            match value.pointer(&prefix) {
+                Some(val) => parent = val,
+                None => break,
+            }
+        }
+        Error::MissingPointer(parent, String::from(pointer))
+    })
+}
+
+/// Escape a key to be used as a JSON pointer token.
+pub(crate) fn escape_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Find the JSON pointer of `target` inside `root`.
+///
+/// `target` is looked up by address so it has to be a reference into `root`.
+pub(crate) fn pointer_of(root: &Value, target: &Value) -> Option<String> {
+    if std::ptr::eq(root, target) {
+        return Some(String::new());
+    }
+
+    match root {
+        Value::Array(arr) => arr.iter().enumerate().find_map(|(index, val)| {
+            pointer_of(val, target).map(|pointer| format!("/{}{}", index, pointer))
+        }),
+        Value::Object(obj) => obj.iter().find_map(|(key, val)| {
+            pointer_of(val, target).map(|pointer| format!("/{}{}", escape_token(key), pointer))
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validators;
+    use crate::{Error, Validator};
+
+    #[test]
+    fn at() {
+        let validator = super::at("/data/items/1/id", validators::eq(6));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"data": {"items": [{"id": 5}, {"id": 6}]}}))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"data": {"items": [{"id": 5}, {"id": 7}]}})),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn at_missing() {
+        let validator = super::at("/data/items/1/id", validators::any());
+        let value = serde_json::json!({"data": {"items": [{"id": 5}]}});
+
+        let error = validator.validate(&value).unwrap_err();
+        assert_eq!(
+            "Missing value at JSON pointer '/data/items/1/id'",
+            error.to_string()
+        );
+        assert!(std::ptr::eq(&value["data"]["items"], error.location()));
+    }
+
+    #[test]
+    fn same_as() {
+        let validator = super::document(super::at("/a", super::same_as("/b")));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"a": 1, "b": 1}))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected same value as '/b' (2) but got 1."
+            )),
+            validator
+                .validate(&serde_json::json!({"a": 1, "b": 2}))
+                .map_err(|err| err.to_string())
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!({"a": 1})),
+            Err(Error::MissingPointer(_, _))
+        ));
+    }
+
+    #[test]
+    fn same_as_without_document() {
+        let validator = super::same_as("/b");
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!(1)),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn same_as_other_threads() {
+        let validator = super::same_as("/b");
+        let document = serde_json::json!({"b": 1});
+
+        super::with_document(&document, || {
+            assert_eq!(Ok(()), validator.validate(&serde_json::json!(1)));
+            // The document is only set for the thread validating it.
+            std::thread::scope(|scope| {
+                scope.spawn(|| {
+                    assert!(super::same_as("/b")
+                        .validate(&serde_json::json!(1))
+                        .is_err());
+                });
+            });
+        });
+        assert!(validator.validate(&serde_json::json!(1)).is_err());
+    }
+
+    #[test]
+    fn pointer_of() {
+        let value = serde_json::json!({"a/b": [1, {"c~": true}]});
+
+        assert_eq!(Some(String::new()), super::pointer_of(&value, &value));
+        assert_eq!(
+            Some(String::from("/a~1b/1/c~0")),
+            super::pointer_of(&value, &value["a/b"][1]["c~"])
+        );
+        assert_eq!(None, super::pointer_of(&value, &serde_json::json!(true)));
+    }
+}
@@ -7,6 +7,7 @@ where
 {
     PrimitiveValidator {
         typename: String::from("string"),
+        description: String::from("a string matching a custom predicate"),
         extract: |val| val.as_str().map(String::from),
         predicate,
     }
@@ -16,6 +17,7 @@ where
 pub fn null() -> impl Validator {
     PrimitiveValidator {
         typename: String::from("null"),
+        description: String::from("null"),
         extract: |val| val.as_null(),
         predicate: |_| Ok(()),
     }
@@ -28,6 +30,7 @@ where
 {
     PrimitiveValidator {
         typename: String::from("bool"),
+        description: String::from("a bool matching a custom predicate"),
         extract: |val| val.as_bool(),
         predicate,
     }
@@ -40,6 +43,7 @@ where
 {
     PrimitiveValidator {
         typename: String::from("i64"),
+        description: String::from("an i64 matching a custom predicate"),
         extract: |val| val.as_i64(),
         predicate,
     }
@@ -52,6 +56,7 @@ where
 {
     PrimitiveValidator {
         typename: String::from("u64"),
+        description: String::from("a u64 matching a custom predicate"),
         extract: |val| val.as_u64(),
         predicate,
     }
@@ -64,17 +69,76 @@ where
 {
     PrimitiveValidator {
         typename: String::from("f64"),
+        description: String::from("an f64 matching a custom predicate"),
         extract: |val| val.as_f64(),
         predicate,
     }
 }
 
+/// Match if number match predicate.
+///
+/// Unlike [i64], [u64] and [f64], the number is not converted and
+/// can be of any representation.
+pub fn number<F>(predicate: F) -> impl Validator
+where
+    F: Fn(&serde_json::Number) -> Result<(), String> + 'static,
+{
+    PrimitiveValidator {
+        typename: String::from("number"),
+        description: String::from("a number matching a custom predicate"),
+        extract: |val| val.as_number().cloned(),
+        predicate,
+    }
+}
+
+/// Match any string.
+pub fn is_string() -> impl Validator {
+    is_type("string", "a string", |val| val.is_string())
+}
+
+/// Match any number.
+pub fn is_number() -> impl Validator {
+    is_type("number", "a number", |val| val.is_number())
+}
+
+/// Match any bool.
+pub fn is_bool() -> impl Validator {
+    is_type("bool", "a bool", |val| val.is_boolean())
+}
+
+/// Match if null.
+///
+/// Same as [null].
+pub fn is_null() -> impl Validator {
+    is_type("null", "null", |val| val.is_null())
+}
+
+/// Match any array, whatever its content.
+pub fn is_array() -> impl Validator {
+    is_type("array", "an array", |val| val.is_array())
+}
+
+/// Match any object, whatever its content.
+pub fn is_object() -> impl Validator {
+    is_type("object", "an object", |val| val.is_object())
+}
+
+fn is_type(typename: &str, description: &str, check: fn(&Value) -> bool) -> impl Validator {
+    PrimitiveValidator {
+        typename: String::from(typename),
+        description: String::from(description),
+        extract: move |val: &Value| check(val).then_some(()),
+        predicate: |_| Ok(()),
+    }
+}
+
 struct PrimitiveValidator<T, F, G>
 where
     F: Fn(&T) -> Result<(), String>,
     G: Fn(&Value) -> Option<T>,
 {
     typename: String,
+    description: String,
     extract: G,
     predicate: F,
 }
@@ -90,6 +154,20 @@ where
 
         (self.predicate)(&val).map_err(|msg| Error::InvalidValue(value, msg))
     }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+
+    fn to_json_schema(&self) -> Value {
+        match self.typename.as_str() {
+            "bool" => serde_json::json!({ "type": "boolean" }),
+            "i64" => serde_json::json!({ "type": "integer" }),
+            "u64" => serde_json::json!({ "type": "integer", "minimum": 0 }),
+            "f64" => serde_json::json!({ "type": "number" }),
+            typename => serde_json::json!({ "type": typename }),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -136,4 +214,37 @@ mod tests {
 
         assert_eq!(Ok(()), validator.validate(&serde_json::json!(4)));
     }
+
+    #[test]
+    fn is_type() {
+        let values = [
+            serde_json::json!("str"),
+            serde_json::json!(4.2),
+            serde_json::json!(false),
+            serde_json::json!(null),
+            serde_json::json!([]),
+            serde_json::json!({}),
+        ];
+        let validators: [Box<dyn Validator>; 6] = [
+            Box::new(super::is_string()),
+            Box::new(super::is_number()),
+            Box::new(super::is_bool()),
+            Box::new(super::is_null()),
+            Box::new(super::is_array()),
+            Box::new(super::is_object()),
+        ];
+
+        for (validator_index, validator) in validators.iter().enumerate() {
+            for (value_index, value) in values.iter().enumerate() {
+                if validator_index == value_index {
+                    assert_eq!(Ok(()), validator.validate(value));
+                } else {
+                    assert!(matches!(
+                        validator.validate(value),
+                        Err(Error::InvalidType(_, _))
+                    ));
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,87 @@
+use crate::validators;
+use crate::Validator;
+use semver::{Version, VersionReq};
+
+/// Match if string is a semantic version, like `1.2.3-beta.1`.
+pub fn is_semver() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "pattern": SEMVER_PATTERN }),
+        validators::described(
+            String::from("a semantic version"),
+            validators::string(|val| {
+                Version::parse(val)
+                    .map(|_| ())
+                    .map_err(|err| format!("semantic version ({})", err))
+            }),
+        ),
+    )
+}
+
+/// The syntax of semantic versions, numeric pre-release identifiers with
+/// leading zeros aside.
+const SEMVER_PATTERN: &str = r"^(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)(-[0-9A-Za-z-]+(\.[0-9A-Za-z-]+)*)?(\+[0-9A-Za-z-]+(\.[0-9A-Za-z-]+)*)?$";
+
+/// Match if string is a semantic version satisfying the requirement.
+///
+/// Panics if the requirement is not valid.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#"{"version": "1.4.0"}"#, {
+///     "version": validators::semver_req(">=1.2, <2"),
+/// });
+/// ```
+pub fn semver_req(requirement: &str) -> impl Validator {
+    let requirement = VersionReq::parse(requirement).expect("invalid version requirement");
+    validators::described(
+        format!("a version matching {}", requirement),
+        validators::string(move |val| {
+            let version =
+                Version::parse(val).map_err(|err| format!("semantic version ({})", err))?;
+            if requirement.matches(&version) {
+                Ok(())
+            } else {
+                Err(format!("version matching '{}'", requirement))
+            }
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+
+    #[test]
+    fn is_semver() {
+        let validator = super::is_semver();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("1.2.3-beta.1"))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("1.2")),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert_eq!(Some(serde_json::json!("0.0.0")), validator.generate());
+        for seed in 0..16 {
+            assert!(validator.generate_seeded(seed).is_some());
+        }
+    }
+
+    #[test]
+    fn semver_req() {
+        let validator = super::semver_req(">=1.2, <2");
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("1.4.0")));
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected version matching '>=1.2, <2' but got \"2.0.0\"."
+            )),
+            validator
+                .validate(&serde_json::json!("2.0.0"))
+                .map_err(|err| err.to_string())
+        );
+    }
+}
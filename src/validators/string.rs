@@ -0,0 +1,323 @@
+use crate::validators;
+use crate::{Error, Validator, Value};
+
+/// Match if string contains the expected substring.
+pub fn contains(expected: &str) -> impl Validator {
+    let expected = String::from(expected);
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "pattern": escape_regex(&expected) }),
+        validators::described(
+            format!("a string containing {:?}", expected),
+            validators::string(move |val| {
+                if val.contains(&expected) {
+                    Ok(())
+                } else {
+                    Err(format!("string containing {:?}", expected))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string starts with the expected prefix.
+pub fn starts_with(expected: &str) -> impl Validator {
+    let expected = String::from(expected);
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "pattern": format!("^{}", escape_regex(&expected)) }),
+        validators::described(
+            format!("a string starting with {:?}", expected),
+            validators::string(move |val| {
+                if val.starts_with(&expected) {
+                    Ok(())
+                } else {
+                    Err(format!("string starting with {:?}", expected))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string ends with the expected suffix.
+pub fn ends_with(expected: &str) -> impl Validator {
+    let expected = String::from(expected);
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "pattern": format!("{}$", escape_regex(&expected)) }),
+        validators::described(
+            format!("a string ending with {:?}", expected),
+            validators::string(move |val| {
+                if val.ends_with(&expected) {
+                    Ok(())
+                } else {
+                    Err(format!("string ending with {:?}", expected))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string is equal to the expected value, ignoring case.
+pub fn eq_ignore_case(expected: &str) -> impl Validator {
+    let expected = String::from(expected);
+    let lowercase_expected = expected.to_lowercase();
+    validators::described(
+        format!("{:?} (ignoring case)", expected),
+        validators::string(move |val| {
+            if val.to_lowercase() == lowercase_expected {
+                Ok(())
+            } else {
+                Err(format!("{:?} (ignoring case)", expected))
+            }
+        }),
+    )
+}
+
+/// Match if string has exactly the expected length.
+///
+/// The length is counted in characters, not bytes.
+pub fn str_len(expected: usize) -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "minLength": expected, "maxLength": expected }),
+        validators::described(
+            format!("a string of length {}", expected),
+            str_length(move |len| {
+                if len == expected {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "string of length {} (actual length {})",
+                        expected, len
+                    ))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string has at least the expected length.
+///
+/// The length is counted in characters, not bytes.
+pub fn str_min_len(min: usize) -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "minLength": min }),
+        validators::described(
+            format!("a string of length >= {}", min),
+            str_length(move |len| {
+                if len >= min {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "string of length >= {} (actual length {})",
+                        min, len
+                    ))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string has at most the expected length.
+///
+/// The length is counted in characters, not bytes.
+pub fn str_max_len(max: usize) -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "maxLength": max }),
+        validators::described(
+            format!("a string of length <= {}", max),
+            str_length(move |len| {
+                if len <= max {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "string of length <= {} (actual length {})",
+                        max, len
+                    ))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if the length of the string, in characters, satisfies the predicate.
+fn str_length<F>(predicate: F) -> impl Validator
+where
+    F: Fn(usize) -> Result<(), String> + 'static,
+{
+    StrLengthValidator { predicate }
+}
+
+struct StrLengthValidator<F>
+where
+    F: Fn(usize) -> Result<(), String>,
+{
+    predicate: F,
+}
+
+impl<F> Validator for StrLengthValidator<F>
+where
+    F: Fn(usize) -> Result<(), String>,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let string_value = value
+            .as_str()
+            .ok_or_else(|| Error::InvalidType(value, String::from("string")))?;
+
+        (self.predicate)(string_value.chars().count())
+            .map_err(|msg| Error::InvalidLength(value, msg))
+    }
+}
+
+/// Match if string match the regular expression.
+///
+/// Panics if the pattern is not a valid regular expression.
+#[cfg(feature = "regex")]
+pub fn regex(pattern: &str) -> impl Validator {
+    let re = regex::Regex::new(pattern).expect("invalid regular expression");
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "pattern": pattern }),
+        validators::described(
+            format!("a string matching {}", pattern),
+            validators::string(move |val| {
+                if re.is_match(val) {
+                    Ok(())
+                } else {
+                    Err(format!("string matching '{}'", re.as_str()))
+                }
+            }),
+        ),
+    )
+}
+
+/// Escape the characters of `text` which are special in regular expressions.
+pub(crate) fn escape_regex(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if "\\^$.*+?()[]{}|".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+
+    #[test]
+    fn contains() {
+        let validator = super::contains("world");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("hello world!"))
+        );
+    }
+
+    #[test]
+    fn contains_invalid_value() {
+        let validator = super::contains("world");
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!("hello")),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn starts_with() {
+        let validator = super::starts_with("hello");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("hello world"))
+        );
+    }
+
+    #[test]
+    fn ends_with_invalid_type() {
+        let validator = super::ends_with("world");
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!(5)),
+            Err(Error::InvalidType(_, _))
+        ));
+    }
+
+    #[test]
+    fn eq_ignore_case() {
+        let validator = super::eq_ignore_case("Content-Type");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("content-type"))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("content-length")),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn str_len() {
+        let validator = super::str_len(4);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("héhé")));
+    }
+
+    #[test]
+    fn str_len_invalid_value() {
+        let validator = super::str_len(4);
+
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected string of length 4 (actual length 3) but got \"abc\"."
+            )),
+            validator
+                .validate(&serde_json::json!("abc"))
+                .map_err(|err| err.to_string())
+        );
+    }
+
+    #[test]
+    fn str_min_max_len() {
+        let value = serde_json::json!("abc");
+
+        assert_eq!(Ok(()), super::str_min_len(3).validate(&value));
+        assert_eq!(Ok(()), super::str_max_len(3).validate(&value));
+        assert!(super::str_min_len(4).validate(&value).is_err());
+        assert!(super::str_max_len(2).validate(&value).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex() {
+        let validator = super::regex(r"^ord-\d+$");
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("ord-42")));
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex_invalid_value() {
+        let validator = super::regex(r"^ord-\d+$");
+
+        assert!(matches!(
+            validator.validate(&serde_json::json!("ord-")),
+            Err(Error::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            "a string starting with \"ord-\"",
+            super::starts_with("ord-").describe()
+        );
+        assert_eq!("a string of length <= 3", super::str_max_len(3).describe());
+        #[cfg(feature = "regex")]
+        assert_eq!(
+            "a string matching ^ord-\\d+$",
+            super::regex("^ord-\\d+$").describe()
+        );
+    }
+}
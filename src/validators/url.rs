@@ -0,0 +1,141 @@
+use crate::validators;
+use crate::Validator;
+use url::Url;
+
+/// Match if string is an absolute URL with a host, like `https://example.com/path`.
+pub fn is_url() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "uri" }),
+        validators::described(
+            String::from("a URL"),
+            validators::string(|val| {
+                let url = Url::parse(val).map_err(|err| format!("URL ({})", err))?;
+                if url.has_host() {
+                    Ok(())
+                } else {
+                    Err(String::from("URL with a host"))
+                }
+            }),
+        ),
+    )
+}
+
+/// Match if string is an absolute URI, like `urn:isbn:0451450523` or
+/// `mailto:user@example.com`.
+pub fn is_uri() -> impl Validator {
+    validators::with_schema(
+        serde_json::json!({ "type": "string", "format": "uri" }),
+        validators::described(
+            String::from("a URI"),
+            validators::string(|val| {
+                Url::parse(val)
+                    .map(|_| ())
+                    .map_err(|err| format!("URI ({})", err))
+            }),
+        ),
+    )
+}
+
+/// Match if string is an URL with the expected host.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#"{"next_page": "https://api.example.com/users?page=2"}"#, {
+///     "next_page": validators::url_with_host("api.example.com"),
+/// });
+/// ```
+pub fn url_with_host(host: &str) -> impl Validator {
+    let host = String::from(host);
+    // Hosts of special schemes like `http` are compared in lowercase, and their
+    // slashes may be missing or backslashes.
+    let host_pattern: String = host
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphabetic() => {
+                format!("[{}{}]", c.to_ascii_lowercase(), c.to_ascii_uppercase())
+            }
+            c => super::string::escape_regex(&c.to_string()),
+        })
+        .collect();
+    validators::with_schema(
+        serde_json::json!({
+            "type": "string",
+            "format": "uri",
+            "pattern": format!(
+                r"^([hH][tT][tT][pP][sS]?|[A-Za-z][A-Za-z0-9+.-]*):(//|[/\\]*)([^/?#@]*@)?{}([/?#:\\]|$)",
+                host_pattern
+            ),
+        }),
+        validators::described(
+            format!("a URL with host {:?}", host),
+            validators::string(move |val| {
+                let url = Url::parse(val).map_err(|err| format!("URL ({})", err))?;
+                if url.host_str() == Some(host.as_str()) {
+                    Ok(())
+                } else {
+                    Err(format!("URL with host {:?}", host))
+                }
+            }),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Validator};
+
+    #[test]
+    fn is_url() {
+        let validator = super::is_url();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("https://example.com/path?q=1"))
+        );
+        assert!(matches!(
+            validator.validate(&serde_json::json!("/relative/path")),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert!(validator
+            .validate(&serde_json::json!("mailto:user@example.com"))
+            .is_err());
+    }
+
+    #[test]
+    fn is_uri() {
+        let validator = super::is_uri();
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("urn:isbn:0451450523"))
+        );
+        assert!(validator.validate(&serde_json::json!("not a uri")).is_err());
+    }
+
+    #[test]
+    fn url_with_host() {
+        let validator = super::url_with_host("api.example.com");
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!("https://api.example.com/users"))
+        );
+        assert_eq!(
+            Err(String::from(
+                "Invalid value. Expected URL with host \"api.example.com\" \
+                but got \"https://example.com/users\"."
+            )),
+            validator
+                .validate(&serde_json::json!("https://example.com/users"))
+                .map_err(|err| err.to_string())
+        );
+        assert_eq!(
+            Some(serde_json::json!("http://api.example.com/")),
+            validator.generate()
+        );
+        for seed in 0..16 {
+            assert!(validator.generate_seeded(seed).is_some());
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use assert_json::assert_json;
+use assert_json::assert_json_eq;
+use assert_json::assert_json_response;
+use assert_json::refute_json;
 use assert_json::validators;
 use indoc::indoc;
 
@@ -71,3 +74,152 @@ fn test_readme_example() {
         )
     );
 }
+
+#[test]
+fn nested_error_pointer() {
+    let expected_output = indoc! {r#"
+        error: Invalid JSON at /items/1/name
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{"items": [{"name": "a"}, {"name": 5}]}"#, {
+            "items": [{"name": "a"}, {"name": "b"}],
+        })
+    )
+}
+
+#[test]
+fn all_errors_reported() {
+    let expected_output = indoc! {r#"
+        error: Invalid JSON at /a
+    "#};
+    let json = r#"{"a": 0, "b": 0}"#;
+
+    assert_panic_output!(expected_output, assert_json!(json, { "a": 1, "b": 2 }));
+
+    let expected_output = indoc! {r#"
+        error: Invalid JSON at /b
+    "#};
+
+    assert_panic_output!(expected_output, assert_json!(json, { "a": 1, "b": 2 }));
+}
+
+#[test]
+fn structural_diff() {
+    let expected_output = [
+        "  = --- expected",
+        "    +++ actual",
+        "    @@ -1,4 +1,4 @@",
+        "     {",
+        "    -  \"id\": 1,",
+        "    +  \"id\": 2,",
+        "       \"tags\": []",
+        "     }",
+    ]
+    .join("\n");
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(
+            r#"{"user": {"id": 2, "tags": []}}"#,
+            { "user": validators::eq(serde_json::json!({"id": 1, "tags": []})) }
+        )
+    )
+}
+
+#[test]
+fn source_line_column() {
+    let expected_output = indoc! {r#"
+        error: Invalid JSON at /result/name (line 6, column 25)
+    "#};
+    let json = r#"
+        {
+            "status": "success",
+            "result": {
+                "id": 5,
+                "name": "incorrect name"
+            }
+        }
+    "#;
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(json, {
+            "status": "success",
+            "result": {
+                "id": 5,
+                "name": "charlesvdv",
+            }
+        })
+    )
+}
+
+#[test]
+fn response_context() {
+    let expected_output = indoc! {r#"
+        3 │ │ }
+          │ ╰─^ Missing key 'id' in object
+
+        Response status: 404
+        Content-Type: application/json
+        X-Request-Id: 42
+    "#};
+    let response = assert_json::HttpResponse::new(404, r#"{"error": "not found"}"#)
+        .header("Content-Type", "application/json")
+        .header("X-Request-Id", "42");
+
+    assert_panic_output!(
+        expected_output,
+        assert_json_response!(&response, { "id": 5 })
+    )
+}
+
+#[test]
+#[should_panic(expected = "Missing key 'userId' in object. Did you mean 'user_id'?")]
+fn missing_key_suggestion() {
+    assert_json!(r#"{"user_id": 5}"#, { "userId": 5 });
+}
+
+#[test]
+fn json_eq_diff() {
+    let expected_output = [
+        "  = --- expected",
+        "    +++ actual",
+        "    @@ -1,6 +1,6 @@",
+        "     {",
+        "       \"id\": 5,",
+        "       \"tags\": [",
+        "    -    \"b\"",
+        "    +    \"a\"",
+        "       ]",
+        "     }",
+    ]
+    .join("\n");
+
+    assert_panic_output!(
+        expected_output,
+        assert_json_eq!(
+            r#"{"id": 5, "tags": ["a"]}"#,
+            serde_json::json!({"id": 5, "tags": ["b"]})
+        )
+    )
+}
+
+#[test]
+fn refutation() {
+    let expected_output = indoc! {r#"
+        error: Invalid JSON (line 1, column 1)
+          ┌─ :1:1
+          │
+        1 │ "secret"
+          │ ^^^^^^^^ Unexpected match. Expected value to not match but "secret" matched.
+
+        Matched expectation: a string
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        refute_json!(r#""secret""#, validators::is_string())
+    )
+}